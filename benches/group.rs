@@ -0,0 +1,72 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use shipyard::{Component, EntityId, IntoIter, Remove, View, ViewMut, World};
+
+#[derive(Component)]
+struct Position(f32);
+
+#[derive(Component)]
+struct Velocity(f32);
+
+fn world(is_grouped: bool) -> World {
+    let mut world = World::new();
+
+    if is_grouped {
+        world.add_group::<(Position, Velocity)>().unwrap();
+    }
+
+    for i in 0..10_000 {
+        match i % 3 {
+            0 => world.add_entity((Position(i as f32),)),
+            1 => world.add_entity((Velocity(i as f32),)),
+            _ => world.add_entity((Position(i as f32), Velocity(1.0))),
+        };
+    }
+
+    world
+}
+
+fn iteration(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iteration");
+
+    for (name, is_grouped) in [("mixed", false), ("group", true)] {
+        let world = world(is_grouped);
+
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                world.run(
+                    |mut positions: ViewMut<Position>, velocities: View<Velocity>| {
+                        for (mut position, velocity) in (&mut positions, &velocities).iter() {
+                            position.0 += black_box(velocity.0);
+                        }
+                    },
+                )
+            })
+        });
+    }
+
+    group.finish();
+}
+
+/// A component removed through a view is added back with `World`, the group catches up with the view's change.
+fn view_remove(c: &mut Criterion) {
+    let mut group = c.benchmark_group("view_remove");
+
+    for (name, is_grouped) in [("mixed", false), ("group", true)] {
+        let mut world = world(is_grouped);
+        let entity = EntityId::new_from_index_and_gen(2, 0);
+
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                world.run(|mut velocities: ViewMut<Velocity>| {
+                    velocities.remove(black_box(entity));
+                });
+                world.add_component(entity, (Velocity(1.0),));
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, iteration, view_remove);
+criterion_main!(benches);
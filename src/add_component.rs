@@ -39,6 +39,7 @@ impl<T: Component, TRACK> AddComponent<T> for ViewMut<'_, T, TRACK> {
     #[track_caller]
     fn add_component_unchecked(&mut self, entity: EntityId, component: T) {
         self.sparse_set.insert(entity, component, self.current);
+        self.sparse_set.invalidate_group(entity);
     }
 }
 
@@ -47,6 +48,7 @@ impl<T: Component, TRACK> AddComponent<T> for &mut ViewMut<'_, T, TRACK> {
     #[track_caller]
     fn add_component_unchecked(&mut self, entity: EntityId, component: T) {
         self.sparse_set.insert(entity, component, self.current);
+        self.sparse_set.invalidate_group(entity);
     }
 }
 
@@ -56,6 +58,7 @@ impl<T: Component, TRACK> AddComponent<Option<T>> for ViewMut<'_, T, TRACK> {
     fn add_component_unchecked(&mut self, entity: EntityId, component: Option<T>) {
         if let Some(component) = component {
             self.sparse_set.insert(entity, component, self.current);
+            self.sparse_set.invalidate_group(entity);
         }
     }
 }
@@ -66,6 +69,7 @@ impl<T: Component, TRACK> AddComponent<Option<T>> for &mut ViewMut<'_, T, TRACK>
     fn add_component_unchecked(&mut self, entity: EntityId, component: Option<T>) {
         if let Some(component) = component {
             self.sparse_set.insert(entity, component, self.current);
+            self.sparse_set.invalidate_group(entity);
         }
     }
 }
@@ -36,6 +36,7 @@ impl<T: Component, TRACK> AddEntity for &mut ViewMut<'_, T, TRACK> {
         storage
             .sparse_set
             .insert(entity, component, storage.current);
+        storage.sparse_set.invalidate_group(entity);
     }
 }
 
@@ -19,7 +19,7 @@ impl<T: 'static + Storage + CustomDeleteAny> TupleDeleteAny for T {
     fn delete_any(all_storages: &mut AllStorages) {
         let mut ids = HashSet::new();
 
-        let current = all_storages.get_current();
+        let current = all_storages.get_current_mut();
         let storages = all_storages.storages.get_mut();
 
         if let Some(storage) = storages.get_mut(&StorageId::of::<T>()) {
@@ -43,7 +43,7 @@ macro_rules! impl_delete_any {
             fn delete_any(all_storages: &mut AllStorages) {
                 let mut ids = HashSet::default();
 
-                let current = all_storages.get_current();
+                let current = all_storages.get_current_mut();
                 let storages = all_storages.storages.get_mut();
 
                 $(
@@ -0,0 +1,387 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::atomic_refcell::{ARef, ARefMut};
+use crate::entities::{Entities, EntitiesIter};
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::storage::{Storage, StorageId};
+use alloc::vec::Vec;
+use core::iter::Copied;
+use core::slice::Iter;
+
+/// Query over storages only known at runtime, created with [`AllStorages::dynamic_query`].
+///
+/// Matches entities with a component in all required storages and none in the excluded ones.
+pub struct DynamicQuery<'a> {
+    entities: ARef<'a, &'a Entities>,
+    required: Vec<ARef<'a, &'a dyn Storage>>,
+    optional: Vec<Option<ARef<'a, &'a dyn Storage>>>,
+    excluded: Vec<ARef<'a, &'a dyn Storage>>,
+    is_empty: bool,
+}
+
+/// Mutable query over storages only known at runtime, created with [`AllStorages::dynamic_query_mut`].
+///
+/// Matches entities with a component in all required storages and none in the excluded ones.
+pub struct DynamicQueryMut<'a> {
+    entities: ARef<'a, &'a Entities>,
+    required: Vec<ARefMut<'a, &'a mut (dyn Storage + 'static)>>,
+    optional: Vec<Option<ARefMut<'a, &'a mut (dyn Storage + 'static)>>>,
+    excluded: Vec<ARef<'a, &'a dyn Storage>>,
+    is_empty: bool,
+    current: u32,
+}
+
+/// Component given to [`DynamicQueryMut::for_each`].
+///
+/// The component is only flagged as modified if [`DynamicComponentMut::as_mut_ptr`] is called.
+pub struct DynamicComponentMut {
+    ptr: *mut u8,
+    index: usize,
+    is_modified: bool,
+}
+
+impl DynamicComponentMut {
+    /// Returns a pointer valid for reads of the storage's component type.
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr
+    }
+    /// Returns a pointer valid for reads and writes of the storage's component type.\
+    /// Flags the component as modified.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.is_modified = true;
+
+        self.ptr
+    }
+}
+
+impl<'a> DynamicQuery<'a> {
+    pub(super) fn new(
+        all_storages: &'a AllStorages,
+        required: &[StorageId],
+        optional: &[StorageId],
+        excluded: &[StorageId],
+    ) -> Result<DynamicQuery<'a>, error::DynamicQuery> {
+        let entities = all_storages.custom_storage::<Entities>()?;
+        let mut is_empty = false;
+
+        let mut required_storages = Vec::with_capacity(required.len());
+        for &storage_id in required {
+            match borrow(all_storages, storage_id)? {
+                Some(storage) => required_storages.push(storage),
+                None => is_empty = true,
+            }
+        }
+
+        let optional = optional
+            .iter()
+            .map(|&storage_id| borrow(all_storages, storage_id))
+            .collect::<Result<_, _>>()?;
+
+        let mut excluded_storages = Vec::with_capacity(excluded.len());
+        for &storage_id in excluded {
+            if let Some(storage) = borrow(all_storages, storage_id)? {
+                excluded_storages.push(storage);
+            }
+        }
+
+        Ok(DynamicQuery {
+            entities,
+            required: required_storages,
+            optional,
+            excluded: excluded_storages,
+            is_empty,
+        })
+    }
+    /// Calls `f` for each matching entity.\
+    /// `required` contains a pointer to each required component, in the order the storages were given.\
+    /// `optional` contains a pointer to each optional component present, in the order the storages were given.
+    ///
+    /// The pointers are valid for reads of the storage's component type until `f` returns.
+    pub fn for_each<F: FnMut(EntityId, &[*const u8], &[Option<*const u8>])>(&self, mut f: F) {
+        if self.is_empty {
+            return;
+        }
+
+        let mut required = Vec::with_capacity(self.required.len());
+        let mut optional = Vec::with_capacity(self.optional.len());
+
+        for entity in driver(
+            self.required
+                .iter()
+                .map(|storage| -> &dyn Storage { &**storage }),
+            &self.entities,
+        ) {
+            required.clear();
+            optional.clear();
+
+            if self
+                .excluded
+                .iter()
+                .any(|storage| storage.sparse_array().unwrap().contains(entity))
+            {
+                continue;
+            }
+
+            let has_all = self
+                .required
+                .iter()
+                .all(|storage| match index_of(&**storage, entity) {
+                    Some(index) => {
+                        required.push(storage.component_ptr(index).unwrap());
+                        true
+                    }
+                    None => false,
+                });
+
+            if !has_all {
+                continue;
+            }
+
+            optional.extend(self.optional.iter().map(|storage| {
+                let storage = &**storage.as_ref()?;
+
+                storage.component_ptr(index_of(storage, entity)?)
+            }));
+
+            f(entity, &required, &optional);
+        }
+    }
+    /// Returns the matching entities.
+    pub fn ids(&self) -> Vec<EntityId> {
+        let mut ids = Vec::new();
+
+        self.for_each(|entity, _, _| ids.push(entity));
+
+        ids
+    }
+}
+
+impl<'a> DynamicQueryMut<'a> {
+    pub(super) fn new(
+        all_storages: &'a AllStorages,
+        required: &[StorageId],
+        optional: &[StorageId],
+        excluded: &[StorageId],
+    ) -> Result<DynamicQueryMut<'a>, error::DynamicQuery> {
+        let entities = all_storages.custom_storage::<Entities>()?;
+        let mut is_empty = false;
+
+        let mut required_storages = Vec::with_capacity(required.len());
+        for &storage_id in required {
+            match borrow_mut(all_storages, storage_id)? {
+                Some(storage) => required_storages.push(storage),
+                None => is_empty = true,
+            }
+        }
+
+        let optional = optional
+            .iter()
+            .map(|&storage_id| borrow_mut(all_storages, storage_id))
+            .collect::<Result<_, _>>()?;
+
+        let mut excluded_storages = Vec::with_capacity(excluded.len());
+        for &storage_id in excluded {
+            if let Some(storage) = borrow(all_storages, storage_id)? {
+                excluded_storages.push(storage);
+            }
+        }
+
+        Ok(DynamicQueryMut {
+            entities,
+            required: required_storages,
+            optional,
+            excluded: excluded_storages,
+            is_empty,
+            current: all_storages.get_current(),
+        })
+    }
+    /// Calls `f` for each matching entity.\
+    /// `required` contains each required component, in the order the storages were given.\
+    /// `optional` contains each optional component present, in the order the storages were given.
+    ///
+    /// The pointers are valid until `f` returns.\
+    /// Only the components accessed with [`DynamicComponentMut::as_mut_ptr`] are flagged as modified.
+    pub fn for_each<F>(&mut self, mut f: F)
+    where
+        F: FnMut(EntityId, &mut [DynamicComponentMut], &mut [Option<DynamicComponentMut>]),
+    {
+        if self.is_empty {
+            return;
+        }
+
+        let entities = driver(
+            self.required
+                .iter()
+                .map(|storage| -> &dyn Storage { &**storage }),
+            &self.entities,
+        )
+        .collect::<Vec<_>>();
+        let mut required = Vec::with_capacity(self.required.len());
+        let mut optional = Vec::with_capacity(self.optional.len());
+        let current = self.current;
+
+        for entity in entities {
+            required.clear();
+            optional.clear();
+
+            if self
+                .excluded
+                .iter()
+                .any(|storage| storage.sparse_array().unwrap().contains(entity))
+            {
+                continue;
+            }
+
+            let has_all =
+                self.required
+                    .iter_mut()
+                    .all(|storage| match index_of(&**storage, entity) {
+                        Some(index) => {
+                            required.push(component(&mut **storage, index));
+                            true
+                        }
+                        None => false,
+                    });
+
+            if !has_all {
+                continue;
+            }
+
+            optional.extend(self.optional.iter_mut().map(|storage| {
+                let storage = &mut **storage.as_mut()?;
+                let index = index_of(storage, entity)?;
+
+                Some(component(storage, index))
+            }));
+
+            f(entity, &mut required, &mut optional);
+
+            for (storage, component) in self.required.iter_mut().zip(&required) {
+                if component.is_modified {
+                    storage.flag_modified(component.index, current);
+                }
+            }
+
+            for (storage, component) in self.optional.iter_mut().zip(&optional) {
+                if let (Some(storage), Some(component)) = (storage, component) {
+                    if component.is_modified {
+                        storage.flag_modified(component.index, current);
+                    }
+                }
+            }
+        }
+    }
+    /// Returns the matching entities.
+    pub fn ids(&self) -> Vec<EntityId> {
+        if self.is_empty {
+            return Vec::new();
+        }
+
+        driver(
+            self.required
+                .iter()
+                .map(|storage| -> &dyn Storage { &**storage }),
+            &self.entities,
+        )
+        .filter(|&entity| {
+            self.required
+                .iter()
+                .all(|storage| index_of(&**storage, entity).is_some())
+                && !self
+                    .excluded
+                    .iter()
+                    .any(|storage| storage.sparse_array().unwrap().contains(entity))
+        })
+        .collect()
+    }
+}
+
+fn borrow(
+    all_storages: &AllStorages,
+    storage_id: StorageId,
+) -> Result<Option<ARef<'_, &'_ dyn Storage>>, error::DynamicQuery> {
+    match all_storages.custom_storage_by_id(storage_id) {
+        Ok(storage) if storage.sparse_array().is_some() => Ok(Some(storage)),
+        Ok(storage) => Err(error::DynamicQuery::NotSparseSet(storage.name())),
+        Err(error::GetStorage::MissingStorage { .. }) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn borrow_mut(
+    all_storages: &AllStorages,
+    storage_id: StorageId,
+) -> Result<Option<ARefMut<'_, &'_ mut (dyn Storage + 'static)>>, error::DynamicQuery> {
+    match all_storages.custom_storage_mut_by_id(storage_id) {
+        Ok(storage) if storage.sparse_array().is_some() => Ok(Some(storage)),
+        Ok(storage) => Err(error::DynamicQuery::NotSparseSet(storage.name())),
+        Err(error::GetStorage::MissingStorage { .. }) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Returns the component at `index`, it isn't flagged as modified yet.
+fn component(storage: &mut dyn Storage, index: usize) -> DynamicComponentMut {
+    DynamicComponentMut {
+        ptr: storage.component_ptr_mut(index).unwrap(),
+        index,
+        is_modified: false,
+    }
+}
+
+/// Returns the index of `entity`'s component in `storage`.
+fn index_of(storage: &dyn Storage, entity: EntityId) -> Option<usize> {
+    let sparse_entity = storage.sparse_array()?.get(entity)?;
+
+    if sparse_entity.gen() == entity.gen() {
+        Some(sparse_entity.uindex())
+    } else {
+        None
+    }
+}
+
+/// Returns the entities to check, the smallest required storage or all alive entities.
+fn driver<'s>(
+    mut required: impl Iterator<Item = &'s dyn Storage>,
+    entities: &'s Entities,
+) -> Driver<'s> {
+    let first = required
+        .next()
+        .map(|storage| storage.dense_entities().unwrap());
+
+    match first {
+        Some(first) => Driver::Dense(
+            required
+                .map(|storage| storage.dense_entities().unwrap())
+                .fold(first, |smallest, dense| {
+                    if dense.len() < smallest.len() {
+                        dense
+                    } else {
+                        smallest
+                    }
+                })
+                .iter()
+                .copied(),
+        ),
+        None => Driver::Alive(entities.iter()),
+    }
+}
+
+enum Driver<'a> {
+    Dense(Copied<Iter<'a, EntityId>>),
+    Alive(EntitiesIter<'a>),
+}
+
+impl Iterator for Driver<'_> {
+    type Item = EntityId;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Driver::Dense(iter) => iter.next(),
+            Driver::Alive(iter) => iter.next(),
+        }
+    }
+}
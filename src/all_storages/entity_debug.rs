@@ -0,0 +1,50 @@
+use crate::atomic_refcell::{ARef, SharedBorrow};
+use crate::entity_id::EntityId;
+use crate::reflect::DebugFn;
+use crate::storage::Storage;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+
+pub(crate) type DebugComponent<'a> = (
+    Cow<'static, str>,
+    ARef<'a, &'a dyn Storage>,
+    Option<DebugFn>,
+);
+
+/// Prints all components of an entity, created with [`AllStorages::debug_entity`].
+///
+/// Components not registered with [`ComponentRegistrationBuilder::with_debug`] are printed as `..`.
+///
+/// [`AllStorages::debug_entity`]: crate::AllStorages::debug_entity()
+/// [`ComponentRegistrationBuilder::with_debug`]: crate::ComponentRegistrationBuilder::with_debug()
+pub struct EntityDebug<'a> {
+    pub(crate) entity: EntityId,
+    pub(crate) components: Vec<DebugComponent<'a>>,
+    // has to be dropped after the storages
+    pub(crate) all_borrow: Option<SharedBorrow<'a>>,
+}
+
+struct NotDebug;
+
+impl Debug for NotDebug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("..")
+    }
+}
+
+impl Debug for EntityDebug<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!("{:?} ", self.entity))?;
+
+        let mut map = f.debug_map();
+        for (name, storage, debug) in &self.components {
+            match debug.and_then(|debug| debug(&**storage, self.entity)) {
+                Some(component) => map.entry(name, component),
+                None => map.entry(name, &NotDebug),
+            };
+        }
+
+        map.finish()
+    }
+}
@@ -1,31 +1,43 @@
 mod custom_storage;
 mod delete_any;
+mod dynamic_query;
+mod entity_debug;
 mod retain;
 
 pub use custom_storage::CustomStorageAccess;
 pub use delete_any::{CustomDeleteAny, TupleDeleteAny};
+pub use dynamic_query::{DynamicComponentMut, DynamicQuery, DynamicQueryMut};
+pub use entity_debug::EntityDebug;
 pub use retain::TupleRetain;
 
-use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
+use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell, HolderScope};
 use crate::borrow::Borrow;
 use crate::type_id::TypeId;
-use crate::component::{Unique, Local};
+use crate::component::{Indexed, Unique, Local};
 use crate::entities::Entities;
 use crate::entity_id::EntityId;
 use crate::get_component::GetComponent;
 use crate::iter_component::{IntoIterRef, IterComponent};
-use crate::memory_usage::AllStoragesMemoryUsage;
+use crate::map_entities::EntityMap;
+use crate::memory_usage::{AllStoragesMemoryUsage, MemoryReport};
 use crate::public_transport::RwLock;
 use crate::public_transport::ShipyardRwLock;
+use crate::reflect::ComponentRegistry;
 use crate::reserve::BulkEntityIter;
-use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TupleRemove};
+use crate::sparse_set::{
+    pack, unpack, BulkAddEntity, GroupMember, SparseSet, TupleAddComponent, TupleDelete,
+    TupleGroup, TupleRemove,
+};
 use crate::storage::{SBox, Storage, StorageId};
 use crate::system::AllSystem;
-use crate::tracking::{TrackingTimestamp, TupleTrack};
+use crate::template::Template;
+use crate::tracking::{is_rebase_due, TrackingTimestamp, TupleTrack};
 use crate::views::EntitiesViewMut;
 use crate::{error, UniqueStorage, LocalStorage};
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::any::type_name;
 use core::sync::atomic::AtomicU32;
 use hashbrown::hash_map::{Entry, HashMap};
@@ -43,6 +55,9 @@ pub struct AllStorages {
     #[cfg(feature = "thread_local")]
     thread_id: std::thread::ThreadId,
     counter: Arc<AtomicU32>,
+    /// Value of `counter` the last time tracking was rebased, shared with `World`
+    last_rebase: Arc<AtomicU32>,
+    pub(crate) groups: Vec<Arc<[GroupMember]>>,
 }
 
 #[cfg(not(feature = "thread_local"))]
@@ -52,7 +67,7 @@ unsafe impl Sync for AllStorages {}
 
 impl AllStorages {
     #[cfg(feature = "std")]
-    pub(crate) fn new(counter: Arc<AtomicU32>) -> Self {
+    pub(crate) fn new(counter: Arc<AtomicU32>, last_rebase: Arc<AtomicU32>) -> Self {
         let mut storages = HashMap::new();
 
         storages.insert(StorageId::of::<Entities>(), SBox::new(Entities::new()));
@@ -62,9 +77,14 @@ impl AllStorages {
             #[cfg(feature = "thread_local")]
             thread_id: std::thread::current().id(),
             counter,
+            last_rebase,
+            groups: Vec::new(),
         }
     }
-    pub(crate) fn new_with_lock<L: ShipyardRwLock + Send + Sync>(counter: Arc<AtomicU32>) -> Self {
+    pub(crate) fn new_with_lock<L: ShipyardRwLock + Send + Sync>(
+        counter: Arc<AtomicU32>,
+        last_rebase: Arc<AtomicU32>,
+    ) -> Self {
         let mut storages = HashMap::new();
 
         storages.insert(StorageId::of::<Entities>(), SBox::new(Entities::new()));
@@ -74,6 +94,8 @@ impl AllStorages {
             #[cfg(feature = "thread_local")]
             thread_id: std::thread::current().id(),
             counter,
+            last_rebase,
+            groups: Vec::new(),
         }
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
@@ -297,7 +319,9 @@ impl AllStorages {
     /// ```
     #[track_caller]
     pub fn strip(&mut self, entity: EntityId) {
-        let current = self.get_current();
+        let current = self.get_current_mut();
+
+        self.unpack_entity(entity, &[]);
 
         for storage in self.storages.get_mut().values_mut() {
             unsafe { &mut *storage.0 }.get_mut().delete(entity, current);
@@ -333,7 +357,9 @@ impl AllStorages {
     /// You should only use this method if you use a custom storage with a runtime id.
     #[track_caller]
     pub fn retain_storage(&mut self, entity: EntityId, excluded_storage: &[StorageId]) {
-        let current = self.get_current();
+        let current = self.get_current_mut();
+
+        self.unpack_entity(entity, excluded_storage);
 
         for (storage_id, storage) in self.storages.get_mut().iter_mut() {
             if !excluded_storage.contains(storage_id) {
@@ -341,6 +367,136 @@ impl AllStorages {
             }
         }
     }
+    /// Returns the id and name of all storages `entity` has a component in, sorted by name.\
+    /// Only storages with a sparse array, like `SparseSet`, are checked.
+    ///
+    /// ### Borrows
+    ///
+    /// - All storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((U32(0),));
+    ///
+    /// let components = all_storages.components_of(entity).unwrap();
+    /// assert_eq!(components.len(), 1);
+    /// assert_eq!(components[0].0, StorageId::of::<SparseSet<U32>>());
+    /// ```
+    pub fn components_of(
+        &self,
+        entity: EntityId,
+    ) -> Result<Vec<(StorageId, Cow<'static, str>)>, error::GetStorage> {
+        let mut components = Vec::new();
+
+        let storages = self.storages.read();
+        for (&storage_id, storage) in storages.iter() {
+            let storage = unsafe { &*storage.0 }.borrow().map_err(|err| {
+                error::GetStorage::StorageBorrow {
+                    name: None,
+                    id: storage_id,
+                    borrow: err,
+                }
+            })?;
+
+            if storage
+                .sparse_array()
+                .is_some_and(|sparse| sparse.contains(entity))
+            {
+                components.push((storage_id, storage.name()));
+            }
+        }
+        drop(storages);
+
+        components.sort_unstable_by(|(_, name1), (_, name2)| name1.cmp(name2));
+
+        Ok(components)
+    }
+    /// Returns a value printing all of `entity`'s components with `Debug`.\
+    /// Components are named after their registration in `registry`, or their storage if they're not registered.\
+    /// Components not registered with [`ComponentRegistrationBuilder::with_debug`] are printed as `..`.
+    ///
+    /// ### Borrows
+    ///
+    /// - All storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, ComponentRegistry, World};
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct U32(u32);
+    ///
+    /// let mut registry = ComponentRegistry::new();
+    /// registry.register::<U32>("U32").with_debug();
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((U32(0),));
+    ///
+    /// println!("{:?}", all_storages.debug_entity(entity, &registry).unwrap());
+    /// ```
+    ///
+    /// [`ComponentRegistrationBuilder::with_debug`]: crate::ComponentRegistrationBuilder::with_debug()
+    pub fn debug_entity(
+        &self,
+        entity: EntityId,
+        registry: &ComponentRegistry,
+    ) -> Result<EntityDebug<'_>, error::GetStorage> {
+        let mut components = Vec::new();
+
+        let storages = self.storages.read();
+        for (&storage_id, storage) in storages.iter() {
+            let storage = unsafe { &*storage.0 }.borrow().map_err(|err| {
+                error::GetStorage::StorageBorrow {
+                    name: None,
+                    id: storage_id,
+                    borrow: err,
+                }
+            })?;
+
+            if storage
+                .sparse_array()
+                .is_some_and(|sparse| sparse.contains(entity))
+            {
+                match registry.get_by_storage_id(storage_id) {
+                    Some(registration) => components.push((
+                        Cow::Owned(registration.name().into()),
+                        storage,
+                        registration.debug_fn(),
+                    )),
+                    None => components.push((storage.name(), storage, None)),
+                }
+            }
+        }
+        drop(storages);
+
+        components.sort_unstable_by(|(name1, ..), (name2, ..)| name1.cmp(name2));
+
+        Ok(EntityDebug {
+            entity,
+            components,
+            all_borrow: None,
+        })
+    }
     /// Deletes all entities and components in the `World`.
     ///
     /// ### Example
@@ -355,12 +511,53 @@ impl AllStorages {
     /// ```
     #[track_caller]
     pub fn clear(&mut self) {
-        let current = self.get_current();
+        let current = self.get_current_mut();
 
         for storage in self.storages.get_mut().values_mut() {
             unsafe { &mut *storage.0 }.get_mut().clear(current);
         }
     }
+    /// Moves tracking timestamps older than `u32::MAX / 2` ticks forward.\
+    /// This keeps them from looking new once the tracking counter wraps around.
+    ///
+    /// It's done automatically every `u32::MAX / 4` ticks, when a workload starts running
+    /// or when `AllStorages` is modified through `&mut`, for example to add an entity.\
+    /// [`TrackingTimestamp`]s are not rebased, they shouldn't be kept for this long.
+    pub fn rebase_tracking(&mut self) {
+        let current = self.get_current();
+
+        for storage in self.storages.get_mut().values_mut() {
+            unsafe { &mut *storage.0 }
+                .get_mut()
+                .rebase_tracking(current);
+        }
+
+        self.last_rebase
+            .store(current, core::sync::atomic::Ordering::Release);
+    }
+    /// Shrinks the capacity of all storages as much as possible.\
+    /// Returns the number of bytes reclaimed.
+    pub fn shrink_to_fit(&mut self) -> usize {
+        let mut reclaimed = 0;
+
+        for storage in self.storages.get_mut().values_mut() {
+            let storage = unsafe { &mut *storage.0 }.get_mut();
+
+            let before = storage
+                .memory_usage()
+                .map_or(0, |usage| usage.allocated_memory_bytes);
+            storage.shrink_to_fit();
+            let after = storage
+                .memory_usage()
+                .map_or(0, |usage| usage.allocated_memory_bytes);
+
+            reclaimed += before.saturating_sub(after);
+        }
+
+        self.storages.get_mut().shrink_to_fit();
+
+        reclaimed
+    }
     /// Clear all deletion and removal tracking data.
     #[track_caller]
     pub fn clear_all_removed_and_deleted(&mut self) {
@@ -404,7 +601,7 @@ impl AllStorages {
     /// ```
     #[inline]
     pub fn add_entity<T: TupleAddComponent>(&mut self, component: T) -> EntityId {
-        let current = self.get_current();
+        let current = self.get_current_mut();
 
         let entity = self.exclusive_storage_mut::<Entities>().unwrap().generate();
         component.add_component(self, entity, current);
@@ -434,6 +631,143 @@ impl AllStorages {
     pub fn bulk_add_entity<T: BulkAddEntity>(&mut self, source: T) -> BulkEntityIter<'_> {
         source.bulk_add_entity(self)
     }
+    /// Creates a new entity with a clone of all of `entity`'s components and returns its `EntityId`.\
+    /// Only components registered in `registry` with [`ComponentRegistrationBuilder::with_clone`] are cloned.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is not alive.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, ComponentRegistry, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut registry = ComponentRegistry::new();
+    /// registry.register::<U32>("U32").with_clone();
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((U32(0),));
+    /// let clone = all_storages.clone_entity(entity, &registry);
+    ///
+    /// assert_eq!(all_storages.get::<&U32>(clone).as_deref(), Ok(&&U32(0)));
+    /// ```
+    ///
+    /// [`ComponentRegistrationBuilder::with_clone`]: crate::ComponentRegistrationBuilder::with_clone()
+    #[track_caller]
+    pub fn clone_entity(&mut self, entity: EntityId, registry: &ComponentRegistry) -> EntityId {
+        let current = self.get_current_mut();
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        if !entities.is_alive(entity) {
+            panic!("{:?}", error::CloneEntity::EntityIsNotAlive);
+        }
+        let clone = entities.generate();
+
+        let storages = self.storages.get_mut();
+        for registration in registry {
+            if let Some(storage) = storages.get_mut(&registration.storage_id()) {
+                registration.clone_component(
+                    unsafe { &mut *storage.0 }.get_mut(),
+                    entity,
+                    clone,
+                    current,
+                );
+            }
+        }
+
+        self.pack_entity(clone);
+
+        clone
+    }
+    /// Creates a new entity with a clone of all of `template`'s components and returns its `EntityId`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Template, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let template = Template::new().with(U32(0));
+    /// let entity = all_storages.spawn_template(&template);
+    ///
+    /// assert_eq!(all_storages.get::<&U32>(entity).as_deref(), Ok(&&U32(0)));
+    /// ```
+    pub fn spawn_template(&mut self, template: &Template) -> EntityId {
+        let current = self.get_current_mut();
+
+        let entity = self.exclusive_storage_mut::<Entities>().unwrap().generate();
+        template.add_to(self, &[entity], current);
+
+        self.pack_entity(entity);
+
+        entity
+    }
+    /// Creates one entity per item of `overrides` and returns an iterator yielding the new `EntityId`s.\
+    /// Each entity receives a clone of all of `template`'s components, then its overrides, replacing the template's components.\
+    /// `overrides` must always yield a tuple, even for a single component, `()` spawns the template as is.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Template, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct Position(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let template = Template::new().with(Health(100)).with(Position(0));
+    /// let entities = all_storages
+    ///     .bulk_spawn_template(&template, (0..10).map(|i| (Position(i),)))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(all_storages.get::<&Health>(entities[9]).as_deref(), Ok(&&Health(100)));
+    /// assert_eq!(all_storages.get::<&Position>(entities[9]).as_deref(), Ok(&&Position(9)));
+    /// ```
+    pub fn bulk_spawn_template<T: TupleAddComponent, I: IntoIterator<Item = T>>(
+        &mut self,
+        template: &Template,
+        overrides: I,
+    ) -> BulkEntityIter<'_> {
+        let current = self.get_current_mut();
+        let overrides = overrides.into_iter().collect::<Vec<_>>();
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let entities_len = entities.data.len();
+        let new_entities = entities.bulk_generate(overrides.len()).to_vec();
+
+        template.add_to(self, &new_entities, current);
+
+        for (&entity, component) in new_entities.iter().zip(overrides) {
+            component.add_component(self, entity, current);
+        }
+
+        for &entity in &new_entities {
+            self.pack_entity(entity);
+        }
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+
+        BulkEntityIter {
+            iter: entities.data[entities_len..].iter().copied(),
+            slice: &entities.data[entities_len..],
+        }
+    }
     /// Adds components to an existing entity.  
     /// If the entity already owned a component it will be replaced.  
     /// `component` must always be a tuple, even for a single component.  
@@ -466,7 +800,7 @@ impl AllStorages {
     #[track_caller]
     #[inline]
     pub fn add_component<T: TupleAddComponent>(&mut self, entity: EntityId, component: T) {
-        let current = self.get_current();
+        let current = self.get_current_mut();
 
         if self
             .exclusive_storage_mut::<Entities>()
@@ -630,7 +964,14 @@ let (entities, mut usizes) = all_storages
     #[cfg_attr(feature = "thread_local", doc = "[NonSend]: crate::NonSend")]
     #[cfg_attr(feature = "thread_local", doc = "[NonSync]: crate::NonSync")]
     #[cfg_attr(feature = "thread_local", doc = "[NonSendSync]: crate::NonSendSync")]
+    #[track_caller]
     pub fn borrow<V: Borrow>(&self) -> Result<V::View<'_>, error::GetStorage> {
+        #[cfg(feature = "tracing")]
+        let borrow_span = tracing::trace_span!("borrow", view = type_name::<V>());
+        #[cfg(feature = "tracing")]
+        let _borrow_span = borrow_span.enter();
+
+        let _holder = HolderScope::caller();
         let current = self.get_current();
 
         V::borrow(self, None, None, None, current)
@@ -728,6 +1069,7 @@ You can use:
         let system_span = tracing::info_span!("system", name = ?type_name::<S>());
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
+        let _holder = HolderScope::caller();
 
         system
             .run((data,), self)
@@ -849,6 +1191,7 @@ let i = all_storages.run(sys1);
         let system_span = tracing::info_span!("system", name = ?type_name::<S>());
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
+        let _holder = HolderScope::caller();
 
         system
             .run((), self)
@@ -916,6 +1259,27 @@ let i = all_storages.run(sys1);
             Err(err) => Err(error::GetStorage::Entities(err)),
         }
     }
+    /// Moves `entity` in the packed part of all groups it's part of.
+    pub(crate) fn pack_entity(&mut self, entity: EntityId) {
+        for i in 0..self.groups.len() {
+            let members = self.groups[i].clone();
+
+            pack(self, &members, entity);
+        }
+    }
+    /// Moves `entity` out of the packed part of all groups owning at least one storage not in `excluded_storage`.
+    pub(crate) fn unpack_entity(&mut self, entity: EntityId, excluded_storage: &[StorageId]) {
+        for i in 0..self.groups.len() {
+            let members = self.groups[i].clone();
+
+            if members
+                .iter()
+                .any(|member| !excluded_storage.contains(&member.storage_id))
+            {
+                unpack(self, &members, entity);
+            }
+        }
+    }
     pub(crate) fn exclusive_storage_mut<T: 'static>(
         &mut self,
     ) -> Result<&mut T, error::GetStorage> {
@@ -1046,6 +1410,31 @@ let i = all_storages.run(sys1);
     pub fn memory_usage(&self) -> AllStoragesMemoryUsage<'_> {
         AllStoragesMemoryUsage(self)
     }
+    /// Returns the memory usage of all storages, split by allocation.\
+    /// Storages that can't be borrowed are counted in [`MemoryReport::borrowed_storages`].
+    pub fn memory_report(&self) -> MemoryReport {
+        let mut report = MemoryReport::default();
+
+        let storages = self.storages.read();
+        for storage in storages.values() {
+            match unsafe { &*(storage.0) }.borrow() {
+                Ok(storage) => report.storages.extend(storage.memory_report()),
+                Err(_) => report.borrowed_storages += 1,
+            }
+        }
+        drop(storages);
+
+        report
+            .storages
+            .sort_unstable_by(|report, other| report.storage_name.cmp(&other.storage_name));
+
+        for storage in &report.storages {
+            report.used_memory_bytes += storage.used_memory_bytes;
+            report.allocated_memory_bytes += storage.allocated_memory_bytes;
+        }
+
+        report
+    }
 
     #[inline]
     pub(crate) fn get_current(&self) -> u32 {
@@ -1053,6 +1442,20 @@ let i = all_storages.run(sys1);
             .fetch_add(1, core::sync::atomic::Ordering::Acquire)
     }
 
+    /// Same as `get_current` but rebases tracking timestamps when it's due.\
+    /// Requires `&mut self` since no storage can be borrowed while rebasing.
+    #[inline]
+    pub(crate) fn get_current_mut(&mut self) -> u32 {
+        if is_rebase_due(
+            self.counter.load(core::sync::atomic::Ordering::Acquire),
+            &self.last_rebase,
+        ) {
+            self.rebase_tracking();
+        }
+
+        self.get_current()
+    }
+
     /// Returns a timestamp used to clear tracking information.
     pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
         TrackingTimestamp(self.counter.load(core::sync::atomic::Ordering::Acquire))
@@ -1087,6 +1490,133 @@ let i = all_storages.run(sys1);
         T::track_all(self);
     }
 
+    /// Makes the storages of `G` an owning group.\
+    /// Entities with a component in all of these storages are packed at the start of each of them.
+    /// Iterating all storages of the group together is then as fast as iterating a single storage.
+    ///
+    /// Adding and removing components with `World` or `AllStorages` keeps the group packed.
+    /// Doing it with views flags the group and iteration falls back to the regular strategy
+    /// until the next `World` or `AllStorages` operation touching the group.
+    ///
+    /// A storage can only be part of a single group.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_group::<(Position, Velocity)>().unwrap();
+    ///
+    /// all_storages.add_entity((Position(0.0),));
+    /// all_storages.add_entity((Position(1.0), Velocity(1.0)));
+    ///
+    /// all_storages.run(|positions: View<Position>, velocities: View<Velocity>| {
+    ///     assert_eq!((&positions, &velocities).iter().count(), 1);
+    /// });
+    /// ```
+    pub fn add_group<G: TupleGroup>(&mut self) -> Result<(), error::AddGroup> {
+        G::add_group(self)
+    }
+
+    /// Indexes `T`'s storage, entities can then be found using their component's key with [`SparseSet::find`].\
+    /// Multiple entities can share the same key.
+    ///
+    /// Components modified through a mutable borrow are reindexed when the borrow ends or with [`SparseSet::reindex`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Indexed, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Team(u32);
+    ///
+    /// impl Indexed for Team {
+    ///     type Key = u32;
+    ///
+    ///     fn key(&self) -> u32 {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_index::<Team>();
+    ///
+    /// all_storages.add_entity((Team(0),));
+    /// all_storages.add_entity((Team(0),));
+    ///
+    /// all_storages.run(|teams: View<Team>| {
+    ///     assert_eq!(teams.find(&0).len(), 2);
+    /// });
+    /// ```
+    ///
+    /// [`SparseSet::find`]: crate::SparseSet::find()
+    /// [`SparseSet::reindex`]: crate::SparseSet::reindex()
+    pub fn add_index<T: Send + Sync + Indexed>(&mut self) {
+        // a non unique index can't fail
+        let _ = self
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .index(false);
+    }
+
+    /// Indexes `T`'s storage, entities can then be found using their component's key with [`SparseSet::find`].\
+    /// A key can only be associated with a single entity.\
+    /// Adding a component with a key already in use panics, [`ViewMut::try_add_component_unchecked`] returns an error instead.
+    ///
+    /// Components modified through a mutable borrow are reindexed when the borrow ends, a duplicate key then panics.\
+    /// [`SparseSet::reindex`] reindexes them sooner and returns an error instead.
+    ///
+    /// ### Errors
+    ///
+    /// - Two components already in the storage have the same key, the storage is not indexed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Indexed, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct NetworkId(u64);
+    ///
+    /// impl Indexed for NetworkId {
+    ///     type Key = u64;
+    ///
+    ///     fn key(&self) -> u64 {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_unique_index::<NetworkId>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((NetworkId(42),));
+    ///
+    /// all_storages.run(|network_ids: View<NetworkId>| {
+    ///     assert_eq!(network_ids.find(&42), &[entity]);
+    /// });
+    /// ```
+    ///
+    /// [`SparseSet::find`]: crate::SparseSet::find()
+    /// [`SparseSet::reindex`]: crate::SparseSet::reindex()
+    /// [`ViewMut::try_add_component_unchecked`]: crate::ViewMut::try_add_component_unchecked()
+    pub fn add_unique_index<T: Send + Sync + Indexed>(
+        &mut self,
+    ) -> Result<(), error::DuplicateKey> {
+        self.exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .index(true)
+    }
+
     #[doc = "Retrieve components of `entity`.
 
 Multiple components can be queried at the same time using a tuple.
@@ -1285,8 +1815,8 @@ for (i, j) in &mut iter {
     /// - `entity` is not alive
     #[track_caller]
     pub fn move_entity(&mut self, other: &mut AllStorages, entity: EntityId) {
-        let current = self.get_current();
-        let other_current = other.get_current();
+        let current = self.get_current_mut();
+        let other_current = other.get_current_mut();
 
         if !self
             .exclusive_storage_mut::<Entities>()
@@ -1308,6 +1838,8 @@ for (i, j) in &mut iter {
             entity
         );
 
+        self.unpack_entity(entity, &[]);
+
         for storage in self.storages.get_mut().values_mut() {
             unsafe { &mut *storage.0 }.get_mut().move_component_from(
                 other,
@@ -1317,6 +1849,202 @@ for (i, j) in &mut iter {
                 other_current,
             );
         }
+
+        other.pack_entity(entity);
+    }
+
+    /// Moves all entities of `other` to this `World`, they're given new ids.\
+    /// Returns the old to new id mapping.
+    ///
+    /// The [`EntityId`]s stored inside components are rewritten if they're registered in `registry`
+    /// with [`ComponentRegistrationBuilder::with_map_entities`].
+    ///
+    /// ```
+    /// use shipyard::{
+    ///     AllStoragesViewMut, Component, ComponentRegistry, EntityId, EntityMap, MapEntities, World,
+    /// };
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Parent(EntityId);
+    ///
+    /// impl MapEntities for Parent {
+    ///     fn map_entities(&mut self, entity_map: &EntityMap) {
+    ///         self.0 = entity_map.map(self.0);
+    ///     }
+    /// }
+    ///
+    /// let mut registry = ComponentRegistry::new();
+    /// registry.register::<Parent>("Parent").with_map_entities();
+    ///
+    /// let world1 = World::new();
+    /// let world2 = World::new();
+    ///
+    /// let mut all_storages1 = world1.borrow::<AllStoragesViewMut>().unwrap();
+    /// let mut all_storages2 = world2.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages1.add_entity(());
+    /// let parent = all_storages2.add_entity(());
+    /// let child = all_storages2.add_entity(Parent(parent));
+    ///
+    /// let entity_map = all_storages1.merge_from(&mut all_storages2, &registry);
+    ///
+    /// assert!(!all_storages2.is_entity_alive(child));
+    /// assert_eq!(
+    ///     all_storages1.get::<&Parent>(entity_map.map(child)).as_deref(),
+    ///     Ok(&&Parent(entity_map.map(parent)))
+    /// );
+    /// ```
+    ///
+    /// [`ComponentRegistrationBuilder::with_map_entities`]: crate::ComponentRegistrationBuilder::with_map_entities()
+    pub fn merge_from(
+        &mut self,
+        other: &mut AllStorages,
+        registry: &ComponentRegistry,
+    ) -> EntityMap {
+        let current = self.get_current_mut();
+        let other_current = other.get_current_mut();
+
+        let other_entities = other.exclusive_storage_mut::<Entities>().unwrap();
+        let olds = other_entities.iter().collect::<Vec<_>>();
+        for &old in &olds {
+            other_entities.delete_unchecked(old);
+        }
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let mut entity_map = EntityMap::new();
+        for &old in &olds {
+            entity_map.insert(old, entities.generate());
+        }
+
+        let other_storages = other.storages.get_mut();
+        for registration in registry {
+            if let Some(storage) = other_storages.get_mut(&registration.storage_id()) {
+                registration.map_entities(unsafe { &mut *storage.0 }.get_mut(), &entity_map);
+            }
+        }
+
+        for old in olds {
+            let new = entity_map.map(old);
+
+            other.unpack_entity(old, &[]);
+
+            for storage in other.storages.get_mut().values_mut() {
+                unsafe { &mut *storage.0 }.get_mut().move_component_from(
+                    self,
+                    old,
+                    new,
+                    other_current,
+                    current,
+                );
+            }
+
+            self.pack_entity(new);
+        }
+
+        entity_map
+    }
+
+    /// Borrows the storages needed to query components only known at runtime.\
+    /// Matching entities have a component in all `required` storages and none in the `excluded` ones,
+    /// `optional` components are given when present.
+    ///
+    /// Missing `required` storages match no entity, missing `optional` and `excluded` storages are ignored.\
+    /// When `required` is empty all alive entities are checked.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`Entities`] (shared)
+    /// - all given storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    /// - A storage isn't a [`SparseSet`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Dead;
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let alive = all_storages.add_entity((Health(10),));
+    /// all_storages.add_entity((Health(0), Dead));
+    ///
+    /// let query = all_storages
+    ///     .dynamic_query(
+    ///         &[StorageId::of::<SparseSet<Health>>()],
+    ///         &[],
+    ///         &[StorageId::of::<SparseSet<Dead>>()],
+    ///     )
+    ///     .unwrap();
+    ///
+    /// query.for_each(|entity, required, _| {
+    ///     assert_eq!(entity, alive);
+    ///     assert_eq!(unsafe { &*required[0].cast::<Health>() }, &Health(10));
+    /// });
+    /// ```
+    pub fn dynamic_query(
+        &self,
+        required: &[StorageId],
+        optional: &[StorageId],
+        excluded: &[StorageId],
+    ) -> Result<DynamicQuery<'_>, error::DynamicQuery> {
+        DynamicQuery::new(self, required, optional, excluded)
+    }
+
+    /// Borrows the storages needed to modify components only known at runtime.\
+    /// Matching entities have a component in all `required` storages and none in the `excluded` ones,
+    /// `optional` components are given when present.
+    ///
+    /// Missing `required` storages match no entity, missing `optional` and `excluded` storages are ignored.\
+    /// When `required` is empty all alive entities are checked.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`Entities`] (shared)
+    /// - `required` and `optional` storages (exclusive)
+    /// - `excluded` storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    /// - A storage isn't a [`SparseSet`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Health(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((Health(10),));
+    ///
+    /// all_storages
+    ///     .dynamic_query_mut(&[StorageId::of::<SparseSet<Health>>()], &[], &[])
+    ///     .unwrap()
+    ///     .for_each(|_, required, _| unsafe {
+    ///         (*required[0].as_mut_ptr().cast::<Health>()).0 += 1;
+    ///     });
+    ///
+    /// assert_eq!(all_storages.get::<&Health>(entity).as_deref(), Ok(&&Health(11)));
+    /// ```
+    pub fn dynamic_query_mut(
+        &self,
+        required: &[StorageId],
+        optional: &[StorageId],
+        excluded: &[StorageId],
+    ) -> Result<DynamicQueryMut<'_>, error::DynamicQuery> {
+        DynamicQueryMut::new(self, required, optional, excluded)
     }
 
     /// Moves all components from an entity to another in another `World`.
@@ -1347,8 +2075,8 @@ for (i, j) in &mut iter {
     /// - `to` is not alive
     #[track_caller]
     pub fn move_components(&mut self, other: &mut AllStorages, from: EntityId, to: EntityId) {
-        let current = self.get_current();
-        let other_current = other.get_current();
+        let current = self.get_current_mut();
+        let other_current = other.get_current_mut();
 
         if !self
             .exclusive_storage_mut::<Entities>()
@@ -1372,6 +2100,8 @@ for (i, j) in &mut iter {
             );
         };
 
+        self.unpack_entity(from, &[]);
+
         for storage in self.storages.get_mut().values_mut() {
             unsafe { &mut *storage.0 }.get_mut().move_component_from(
                 other,
@@ -1381,6 +2111,8 @@ for (i, j) in &mut iter {
                 other_current,
             );
         }
+
+        other.pack_entity(to);
     }
 }
 
@@ -1,6 +1,8 @@
 mod borrow_state;
+mod holder;
 
 pub use borrow_state::{ExclusiveBorrow, SharedBorrow};
+pub(crate) use holder::HolderScope;
 
 use crate::error;
 use borrow_state::BorrowState;
@@ -309,7 +311,10 @@ fn shared() {
     let first_borrow = refcell.borrow().unwrap();
 
     assert!(refcell.borrow().is_ok());
-    assert_eq!(refcell.borrow_mut().err(), Some(error::Borrow::Shared));
+    assert_eq!(
+        refcell.borrow_mut().err(),
+        Some(error::Borrow::Shared(None))
+    );
 
     drop(first_borrow);
 
@@ -321,14 +326,39 @@ fn exclusive() {
     let refcell = AtomicRefCell::new(0);
     let first_borrow = refcell.borrow_mut().unwrap();
 
-    assert_eq!(refcell.borrow().err(), Some(error::Borrow::Unique));
-    assert_eq!(refcell.borrow_mut().err(), Some(error::Borrow::Unique));
+    assert_eq!(refcell.borrow().err(), Some(error::Borrow::Unique(None)));
+    assert_eq!(
+        refcell.borrow_mut().err(),
+        Some(error::Borrow::Unique(None))
+    );
 
     drop(first_borrow);
 
     assert!(refcell.borrow_mut().is_ok());
 }
 
+#[cfg(all(feature = "std", debug_assertions))]
+#[test]
+fn holder() {
+    let refcell = AtomicRefCell::new(0);
+
+    let scope = HolderScope::system("system");
+    let first_borrow = refcell.borrow().unwrap();
+    drop(scope);
+
+    assert_eq!(
+        refcell.borrow_mut().err(),
+        Some(error::Borrow::Shared(Some(error::BorrowHolder::System(
+            "system"
+        ))))
+    );
+
+    drop(first_borrow);
+    let _first_borrow = refcell.borrow_mut().unwrap();
+
+    assert_eq!(refcell.borrow().err(), Some(error::Borrow::Unique(None)));
+}
+
 #[cfg(all(feature = "std", not(feature = "thread_local")))]
 #[test]
 fn shared_thread() {
@@ -342,7 +372,7 @@ fn shared_thread() {
         refcell_clone.borrow().unwrap();
         assert_eq!(
             refcell_clone.borrow_mut().err(),
-            Some(error::Borrow::Shared)
+            Some(error::Borrow::Shared(None))
         );
     })
     .join()
@@ -365,7 +395,7 @@ fn exclusive_thread() {
         let _first_borrow = refcell_clone.borrow_mut();
         assert_eq!(
             refcell_clone.borrow_mut().err(),
-            Some(error::Borrow::Unique)
+            Some(error::Borrow::Unique(None))
         );
     })
     .join()
@@ -1,17 +1,35 @@
-use crate::error;
+#[cfg(all(feature = "std", debug_assertions))]
+use super::holder::current_holder;
+use crate::error::{self, BorrowHolder};
+#[cfg(all(feature = "std", debug_assertions))]
+use alloc::vec::Vec;
 use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(all(feature = "std", debug_assertions))]
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 const HIGH_BIT: usize = !(usize::MAX >> 1);
 const MAX_FAILED_BORROWS: usize = HIGH_BIT + (HIGH_BIT >> 1);
 
-pub(super) struct BorrowState(AtomicUsize);
+pub(super) struct BorrowState(
+    AtomicUsize,
+    /// Holders of the active borrows, oldest first.\
+    /// The oldest one is reported when a borrow fails.
+    #[cfg(all(feature = "std", debug_assertions))]
+    Mutex<Vec<BorrowHolder>>,
+);
 
 /// Unlocks a shared borrow on drop.
-pub struct SharedBorrow<'a>(&'a BorrowState);
+pub struct SharedBorrow<'a>(
+    &'a BorrowState,
+    #[cfg(all(feature = "std", debug_assertions))] Option<BorrowHolder>,
+);
 
 impl Drop for SharedBorrow<'_> {
     #[inline]
     fn drop(&mut self) {
+        #[cfg(all(feature = "std", debug_assertions))]
+        self.0.remove_holder(self.1);
+
         (self.0).0.fetch_sub(1, Ordering::Release);
     }
 }
@@ -29,6 +47,8 @@ pub struct ExclusiveBorrow<'a>(&'a BorrowState);
 impl Drop for ExclusiveBorrow<'_> {
     #[inline]
     fn drop(&mut self) {
+        // an exclusive borrow is the only active borrow
+        self.0.clear_holders();
         (self.0).0.store(0, Ordering::Release);
     }
 }
@@ -36,7 +56,11 @@ impl Drop for ExclusiveBorrow<'_> {
 impl BorrowState {
     #[inline]
     pub(super) fn new() -> Self {
-        BorrowState(AtomicUsize::new(0))
+        BorrowState(
+            AtomicUsize::new(0),
+            #[cfg(all(feature = "std", debug_assertions))]
+            Mutex::new(Vec::new()),
+        )
     }
     #[inline]
     pub(super) fn read(&self) -> Result<SharedBorrow<'_>, error::Borrow> {
@@ -44,9 +68,9 @@ impl BorrowState {
         if new & HIGH_BIT != 0 {
             self.check_overflow(new);
 
-            Err(error::Borrow::Unique)
+            Err(error::Borrow::Unique(self.holder()))
         } else {
-            Ok(SharedBorrow(self))
+            Ok(self.shared_borrow())
         }
     }
 
@@ -62,11 +86,11 @@ impl BorrowState {
         };
 
         if old == 0 {
-            Ok(SharedBorrow(self))
+            Ok(self.shared_borrow())
         } else if old & HIGH_BIT == 0 {
-            Err(error::Borrow::Shared)
+            Err(error::Borrow::Shared(self.holder()))
         } else {
-            Err(error::Borrow::Unique)
+            Err(error::Borrow::Unique(self.holder()))
         }
     }
 
@@ -81,11 +105,68 @@ impl BorrowState {
         };
 
         if old == 0 {
+            #[cfg(all(feature = "std", debug_assertions))]
+            if let Some(holder) = current_holder() {
+                self.lock_holders().push(holder);
+            }
+
             Ok(ExclusiveBorrow(self))
         } else if old & HIGH_BIT == 0 {
-            Err(error::Borrow::Shared)
+            Err(error::Borrow::Shared(self.holder()))
         } else {
-            Err(error::Borrow::Unique)
+            Err(error::Borrow::Unique(self.holder()))
+        }
+    }
+
+    /// Records this thread's current holder as the holder of the new shared borrow.\
+    /// Borrows made outside of any `HolderScope` aren't recorded.
+    #[inline]
+    fn shared_borrow(&self) -> SharedBorrow<'_> {
+        #[cfg(all(feature = "std", debug_assertions))]
+        {
+            let holder = current_holder();
+            if let Some(holder) = holder {
+                self.lock_holders().push(holder);
+            }
+
+            SharedBorrow(self, holder)
+        }
+        #[cfg(not(all(feature = "std", debug_assertions)))]
+        {
+            SharedBorrow(self)
+        }
+    }
+    #[cfg(all(feature = "std", debug_assertions))]
+    fn lock_holders(&self) -> MutexGuard<'_, Vec<BorrowHolder>> {
+        self.1.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    /// Removes the holder of a shared borrow that is being dropped.
+    #[cfg(all(feature = "std", debug_assertions))]
+    #[inline]
+    fn remove_holder(&self, holder: Option<BorrowHolder>) {
+        if let Some(holder) = holder {
+            let mut holders = self.lock_holders();
+            if let Some(index) = holders.iter().position(|&other| other == holder) {
+                holders.remove(index);
+            }
+        }
+    }
+    #[inline]
+    fn clear_holders(&self) {
+        #[cfg(all(feature = "std", debug_assertions))]
+        self.lock_holders().clear();
+    }
+    /// Returns the holder of the oldest active borrow.\
+    /// Always returns `None` in release builds.
+    #[cold]
+    fn holder(&self) -> Option<BorrowHolder> {
+        #[cfg(all(feature = "std", debug_assertions))]
+        {
+            self.lock_holders().first().copied()
+        }
+        #[cfg(not(all(feature = "std", debug_assertions)))]
+        {
+            None
         }
     }
 
@@ -0,0 +1,54 @@
+use crate::error::BorrowHolder;
+#[cfg(all(feature = "std", debug_assertions))]
+use core::cell::Cell;
+use core::panic::Location;
+
+// Holders are only recorded in debug builds, they're reported by `error::Borrow`.
+#[cfg(all(feature = "std", debug_assertions))]
+std::thread_local! {
+    static CURRENT_HOLDER: Cell<Option<BorrowHolder>> = const { Cell::new(None) };
+}
+
+/// Makes `holder` the holder of all borrows made by this thread until dropped.\
+/// Restores the previous holder on drop.
+pub(crate) struct HolderScope {
+    #[cfg(all(feature = "std", debug_assertions))]
+    previous: Option<BorrowHolder>,
+}
+
+impl HolderScope {
+    #[inline]
+    #[allow(unused_variables)]
+    fn new(holder: BorrowHolder) -> HolderScope {
+        HolderScope {
+            #[cfg(all(feature = "std", debug_assertions))]
+            previous: CURRENT_HOLDER.with(|current| current.replace(Some(holder))),
+        }
+    }
+    /// Borrows made in this scope will be attributed to the caller.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn caller() -> HolderScope {
+        HolderScope::new(BorrowHolder::Caller(Location::caller()))
+    }
+    /// Borrows made in this scope will be attributed to the system `name`.
+    #[inline]
+    pub(crate) fn system(name: &'static str) -> HolderScope {
+        HolderScope::new(BorrowHolder::System(name))
+    }
+}
+
+#[cfg(all(feature = "std", debug_assertions))]
+impl Drop for HolderScope {
+    #[inline]
+    fn drop(&mut self) {
+        CURRENT_HOLDER.with(|current| current.set(self.previous));
+    }
+}
+
+/// Returns the holder of borrows made by this thread.
+#[cfg(all(feature = "std", debug_assertions))]
+#[inline]
+pub(super) fn current_holder() -> Option<BorrowHolder> {
+    CURRENT_HOLDER.with(Cell::get)
+}
@@ -0,0 +1,227 @@
+use crate::all_storages::AllStorages;
+use crate::entities::Entities;
+use crate::reflect::ComponentRegistry;
+use crate::world::World;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+/// Hash of a `World`'s state, returned by [`World::checksum`].
+///
+/// Two worlds with the same entities and the same components in their hashed storages
+/// have the same checksum, regardless of the order components were added and deleted in.
+///
+/// [`World::checksum`]: crate::World::checksum()
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checksum {
+    /// Hash of [`Entities`] and all storages.
+    ///
+    /// [`Entities`]: crate::Entities
+    pub hash: u64,
+    /// Hash of [`Entities`].
+    ///
+    /// [`Entities`]: crate::Entities
+    pub entities: u64,
+    /// Hash of each storage registered with [`ComponentRegistrationBuilder::with_hash`],
+    /// sorted by registered name.
+    ///
+    /// [`ComponentRegistrationBuilder::with_hash`]: crate::ComponentRegistrationBuilder::with_hash()
+    pub storages: Vec<(Cow<'static, str>, u64)>,
+}
+
+impl Checksum {
+    /// Returns the registered name of the storages with a different hash in `other`,
+    /// storages only present in one of the checksums included.
+    pub fn diverging_storages(&self, other: &Checksum) -> Vec<Cow<'static, str>> {
+        let mut diverging = Vec::new();
+        let mut storages = self.storages.iter().peekable();
+        let mut other_storages = other.storages.iter().peekable();
+
+        loop {
+            match (storages.peek(), other_storages.peek()) {
+                (Some((name, hash)), Some((other_name, other_hash))) => {
+                    if name < other_name {
+                        diverging.push(name.clone());
+                        storages.next();
+                    } else if other_name < name {
+                        diverging.push(other_name.clone());
+                        other_storages.next();
+                    } else {
+                        if hash != other_hash {
+                            diverging.push(name.clone());
+                        }
+
+                        storages.next();
+                        other_storages.next();
+                    }
+                }
+                (Some((name, _)), None) => {
+                    diverging.push(name.clone());
+                    storages.next();
+                }
+                (None, Some((other_name, _))) => {
+                    diverging.push(other_name.clone());
+                    other_storages.next();
+                }
+                (None, None) => return diverging,
+            }
+        }
+    }
+}
+
+impl AllStorages {
+    /// Hashes [`Entities`] and all storages registered with [`ComponentRegistrationBuilder::with_hash`] in `registry`.\
+    /// Components are hashed in [`EntityId`] order, the result doesn't depend on the order they were added in.\
+    /// Storages are identified by their registered name, a storage missing from the `AllStorages` hashes like an empty one.
+    ///
+    /// The hash is the same on all platforms as long as `T`'s [`Hash`] implementation is.
+    ///
+    /// [`Entities`]: crate::Entities
+    /// [`EntityId`]: crate::EntityId
+    /// [`ComponentRegistrationBuilder::with_hash`]: crate::ComponentRegistrationBuilder::with_hash()
+    pub fn checksum(&mut self, registry: &ComponentRegistry) -> Checksum {
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap().checksum();
+
+        let all_storages = self.storages.get_mut();
+        let mut storages: Vec<(Cow<'static, str>, u64)> = Vec::new();
+
+        for registration in registry {
+            let storage = all_storages
+                .get_mut(&registration.storage_id())
+                .map(|storage| &*unsafe { &mut *storage.0 }.get_mut());
+
+            if let Some(hash) = registration.checksum(storage) {
+                storages.push((registration.name().to_string().into(), hash));
+            }
+        }
+
+        storages.sort_unstable_by(|(name, _), (other_name, _)| name.cmp(other_name));
+
+        let mut hasher = ChecksumHasher::new();
+        hasher.write_u64(entities);
+        for (name, hash) in &storages {
+            name.hash(&mut hasher);
+            hasher.write_u64(*hash);
+        }
+
+        Checksum {
+            hash: hasher.finish(),
+            entities,
+            storages,
+        }
+    }
+}
+
+impl World {
+    /// Hashes [`Entities`] and all storages registered with [`ComponentRegistrationBuilder::with_hash`] in `registry`.\
+    /// Components are hashed in [`EntityId`] order, the result doesn't depend on the order they were added in.\
+    /// Storages are identified by their registered name, a storage missing from the `World` hashes like an empty one.
+    ///
+    /// The hash is the same on all platforms as long as `T`'s [`Hash`] implementation is.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ComponentRegistry, World};
+    ///
+    /// #[derive(Component, Hash)]
+    /// struct Position(i32, i32);
+    ///
+    /// let mut registry = ComponentRegistry::new();
+    /// registry.register::<Position>("Position").with_hash();
+    ///
+    /// let mut world1 = World::new();
+    /// let entity0 = world1.add_entity((Position(0, 0),));
+    /// let entity1 = world1.add_entity((Position(1, 1),));
+    ///
+    /// let mut world2 = World::new();
+    /// world2.add_entity(());
+    /// world2.add_entity((Position(1, 1),));
+    /// world2.add_component(entity0, (Position(0, 0),));
+    ///
+    /// assert_eq!(world1.checksum(&registry), world2.checksum(&registry));
+    ///
+    /// world2.add_component(entity1, (Position(2, 2),));
+    ///
+    /// let checksum2 = world2.checksum(&registry);
+    /// assert_ne!(world1.checksum(&registry).hash, checksum2.hash);
+    /// assert_eq!(
+    ///     world1.checksum(&registry).diverging_storages(&checksum2),
+    ///     vec!["Position"]
+    /// );
+    /// ```
+    ///
+    /// [`Entities`]: crate::Entities
+    /// [`EntityId`]: crate::EntityId
+    /// [`ComponentRegistrationBuilder::with_hash`]: crate::ComponentRegistrationBuilder::with_hash()
+    #[inline]
+    pub fn checksum(&mut self, registry: &ComponentRegistry) -> Checksum {
+        self.all_storages.get_mut().checksum(registry)
+    }
+}
+
+/// 64 bits FNV-1a hasher.\
+/// Unlike `std`'s default hasher its output is stable, integers are hashed as little endian
+/// and `usize`/`isize` as 64 bits to get the same hash on all platforms.
+pub(crate) struct ChecksumHasher(u64);
+
+impl ChecksumHasher {
+    #[inline]
+    pub(crate) fn new() -> ChecksumHasher {
+        ChecksumHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for ChecksumHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
@@ -1,6 +1,22 @@
 /// Indicates that a `struct` or `enum` can be store in the `World`.
 pub trait Component: Sized + 'static {}
 
+/// Indicates that a [`Component`] can be looked up by a key.
+///
+/// The storage has to be indexed with [`World::add_index`] or [`World::add_unique_index`],
+/// entities can then be found with [`SparseSet::find`].
+///
+/// [`World::add_index`]: crate::World::add_index()
+/// [`World::add_unique_index`]: crate::World::add_unique_index()
+/// [`SparseSet::find`]: crate::SparseSet::find()
+pub trait Indexed: Component {
+    /// Value used to look up entities.
+    type Key: core::hash::Hash + Eq + Clone + Send + Sync + 'static;
+
+    /// Returns the key of this component.
+    fn key(&self) -> Self::Key;
+}
+
 /// Indicates that a `struct` or `enum` can be store a single time in the `World`.
 pub trait Unique: Sized + 'static {}
 
@@ -5,14 +5,20 @@ pub use iterator::EntitiesIter;
 use crate::add_component::AddComponent;
 use crate::add_distinct_component::AddDistinctComponent;
 use crate::add_entity::AddEntity;
+use crate::checksum::ChecksumHasher;
 use crate::entity_id::EntityId;
 use crate::error;
-use crate::memory_usage::StorageMemoryUsage;
+use crate::memory_usage::{
+    MemorySection, StorageKind, StorageMemoryBreakdown, StorageMemoryReport, StorageMemoryUsage,
+};
 use crate::reserve::{BulkEntityIter, BulkReserve};
 use crate::storage::Storage;
 use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
+use core::hash::Hasher;
 use core::iter::repeat_with;
+use core::mem::size_of;
 
 /// Entities holds the EntityIds to all entities: living, removed and dead.
 ///
@@ -34,7 +40,7 @@ use core::iter::repeat_with;
 // Dead entities are simply never added to the linked list.
 pub struct Entities {
     pub(crate) data: Vec<EntityId>,
-    list: Option<(usize, usize)>,
+    pub(crate) list: Option<(usize, usize)>,
     on_deletion: Option<Box<dyn FnMut(EntityId) + Send + Sync>>,
 }
 
@@ -378,6 +384,32 @@ impl Entities {
     pub fn on_deletion(&mut self, f: impl FnMut(EntityId) + Send + Sync + 'static) {
         self.on_deletion = Some(Box::new(f));
     }
+    /// Shrinks the capacity of `Entities` as much as possible.\
+    /// Deleted entities are kept to preserve their generation, only the spare capacity is released.
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+    /// Hashes the entities and the state deciding which ids are given to the next entities.
+    pub(crate) fn checksum(&self) -> u64 {
+        let mut hasher = ChecksumHasher::new();
+
+        hasher.write_usize(self.data.len());
+        for entity in &self.data {
+            hasher.write_u64(entity.inner());
+        }
+
+        // the list decides which ids are given to the next entities
+        match self.list {
+            Some((new, old)) => {
+                hasher.write_u8(1);
+                hasher.write_usize(new);
+                hasher.write_usize(old);
+            }
+            None => hasher.write_u8(0),
+        }
+
+        hasher.finish()
+    }
 }
 
 impl Storage for Entities {
@@ -422,12 +454,28 @@ impl Storage for Entities {
                 + core::mem::size_of::<Entities>(),
             used_memory_bytes: (self.data.len() * core::mem::size_of::<EntityId>())
                 + core::mem::size_of::<Entities>(),
+            breakdown: StorageMemoryBreakdown {
+                component_memory_bytes: self.data.capacity() * size_of::<EntityId>(),
+                ..StorageMemoryBreakdown::default()
+            },
             component_count: self.data.len(),
         })
     }
+    fn memory_report(&self) -> Option<StorageMemoryReport> {
+        let memory_usage = self.memory_usage()?;
+
+        Some(StorageMemoryReport {
+            kind: StorageKind::Entities,
+            sections: vec![MemorySection::vec("data", &self.data)],
+            ..StorageMemoryReport::from_memory_usage(memory_usage)
+        })
+    }
     fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
+    fn shrink_to_fit(&mut self) {
+        Entities::shrink_to_fit(self);
+    }
     fn move_component_from(
         &mut self,
         _other_all_storages: &mut crate::AllStorages,
@@ -87,7 +87,7 @@ impl<'de> Deserialize<'de> for EntityId {
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(1, &self))?;
 
-                Ok(EntityId::new_from_parts(index, generation))
+                from_parts(index, generation)
             }
 
             fn visit_map<V>(self, mut map: V) -> Result<EntityId, V::Error>
@@ -117,7 +117,7 @@ impl<'de> Deserialize<'de> for EntityId {
                 let index = index.ok_or_else(|| de::Error::missing_field("index"))?;
                 let generation = generation.ok_or_else(|| de::Error::missing_field("gen"))?;
 
-                Ok(EntityId::new_from_parts(index, generation))
+                from_parts(index, generation)
             }
         }
 
@@ -131,6 +131,22 @@ impl<'de> Deserialize<'de> for EntityId {
     }
 }
 
+/// Builds an `EntityId` from deserialized parts, the dead `EntityId` included.
+fn from_parts<E: de::Error>(index: u64, gen: u16) -> Result<EntityId, E> {
+    let dead = EntityId::dead();
+
+    if index == dead.index() && gen == dead.gen() {
+        Ok(dead)
+    } else if index < EntityId::INDEX_MASK && gen <= EntityId::max_gen() {
+        Ok(EntityId::new_from_parts(index, gen))
+    } else {
+        Err(de::Error::custom(format_args!(
+            "invalid EntityId {{ index: {}, gen: {} }}",
+            index, gen
+        )))
+    }
+}
+
 #[test]
 fn serde_json() {
     let string = serde_json::to_string(&EntityId::new_from_index_and_gen(10, 2)).unwrap();
@@ -148,3 +164,13 @@ fn bincode() {
     let entity = bincode::deserialize::<EntityId>(&bytes).unwrap();
     assert_eq!(entity, EntityId::new_from_parts(10, 2));
 }
+
+#[test]
+fn dead() {
+    let string = serde_json::to_string(&EntityId::dead()).unwrap();
+
+    let entity = serde_json::de::from_str::<EntityId>(&string).unwrap();
+    assert_eq!(entity, EntityId::dead());
+
+    assert!(serde_json::de::from_str::<EntityId>(r#"{"index":10,"gen":65535}"#).is_err());
+}
@@ -1,23 +1,28 @@
 //! All error types.
 
-use crate::info::TypeInfo;
+use crate::info::{Conflict, TypeInfo};
 use crate::scheduler::Label;
 use crate::storage::StorageId;
 use crate::{entity_id::EntityId, tracking::tracking_fmt};
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+#[cfg(feature = "serde1")]
+use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt::{Debug, Display, Formatter};
+use core::panic::Location;
 #[cfg(feature = "std")]
 use std::error::Error;
 
 /// AtomicRefCell's borrow error.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Borrow {
-    /// The Storage was borrowed when an exclusive borrow occurred.
-    Unique,
-    /// The Storage was borrowed exclusively when a shared borrow occurred.
-    Shared,
+    /// The Storage was borrowed when an exclusive borrow occurred.\
+    /// Contains the holder of the conflicting borrow, see [`BorrowHolder`].
+    Unique(Option<BorrowHolder>),
+    /// The Storage was borrowed exclusively when a shared borrow occurred.\
+    /// Contains the holder of the conflicting borrow, see [`BorrowHolder`].
+    Shared(Option<BorrowHolder>),
     /// The Storage of a `!Send` component was accessed from an other thread.
     WrongThread,
     /// The Storage of a `!Sync` component was accessed from multiple threads at the same time.
@@ -30,10 +35,14 @@ impl Error for Borrow {}
 impl Debug for Borrow {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
-            Borrow::Unique => f.write_str("Cannot mutably borrow while already borrowed."),
-            Borrow::Shared => {
-                f.write_str("Cannot immutably borrow while already mutably borrowed.")
-            }
+            Borrow::Unique(holder) => f.write_fmt(format_args!(
+                "Cannot mutably borrow while already borrowed{}.",
+                HeldBy(holder)
+            )),
+            Borrow::Shared(holder) => f.write_fmt(format_args!(
+                "Cannot immutably borrow while already mutably borrowed{}.",
+                HeldBy(holder)
+            )),
             Borrow::WrongThread => {
                 f.write_str("Can't access from another thread because it's !Send and !Sync.")
             }
@@ -50,6 +59,45 @@ impl Display for Borrow {
     }
 }
 
+/// Holder of a storage borrow, reported by [`Borrow`].
+///
+/// Holders are only recorded in debug builds with the `std` feature.\
+/// When multiple shared borrows are active, the oldest one is reported.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BorrowHolder {
+    /// A workload system, identified by its type name.
+    System(&'static str),
+    /// A call to [`World::borrow`](crate::World::borrow), [`World::run`](crate::World::run) or their [`AllStorages`](crate::AllStorages) equivalent.
+    Caller(&'static Location<'static>),
+}
+
+impl Debug for BorrowHolder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            BorrowHolder::System(name) => f.write_fmt(format_args!("system {}", name)),
+            BorrowHolder::Caller(location) => f.write_fmt(format_args!("{}", location)),
+        }
+    }
+}
+
+impl Display for BorrowHolder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Formats the holder of a conflicting borrow, if it was recorded.
+struct HeldBy<'a>(&'a Option<BorrowHolder>);
+
+impl Display for HeldBy<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self.0 {
+            Some(holder) => f.write_fmt(format_args!(" by {:?}", holder)),
+            None => Ok(()),
+        }
+    }
+}
+
 /// Error related to acquiring a storage.
 pub enum GetStorage {
     #[allow(missing_docs)]
@@ -149,35 +197,35 @@ impl Debug for GetStorage {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             GetStorage::AllStoragesBorrow(borrow) => match borrow {
-                Borrow::Unique => f.write_str("Cannot mutably borrow AllStorages while it's already borrowed (AllStorages is borrowed to access any storage)."),
-                Borrow::Shared => {
-                    f.write_str("Cannot immutably borrow AllStorages while it's already mutably borrowed.")
+                Borrow::Unique(holder) => f.write_fmt(format_args!("Cannot mutably borrow AllStorages while it's already borrowed (AllStorages is borrowed to access any storage){}.", HeldBy(holder))),
+                Borrow::Shared(holder) => {
+                    f.write_fmt(format_args!("Cannot immutably borrow AllStorages while it's already mutably borrowed{}.", HeldBy(holder)))
                 },
                 _ => unreachable!(),
             },
             GetStorage::StorageBorrow {name, id, borrow} => if let Some(name) = name {
                 match borrow {
-                    Borrow::Unique => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed.", name)),
-                    Borrow::Shared => {
-                        f.write_fmt(format_args!("Cannot immutably borrow {} storage while it's already mutably borrowed.", name))
+                    Borrow::Unique(holder) => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed{}.", name, HeldBy(holder))),
+                    Borrow::Shared(holder) => {
+                        f.write_fmt(format_args!("Cannot immutably borrow {} storage while it's already mutably borrowed{}.", name, HeldBy(holder)))
                     },
                     Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {} storage from multiple thread at the same time because it's !Sync.", name)),
                     Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
                 }
             } else {
                 match borrow {
-                    Borrow::Unique => f.write_fmt(format_args!("Cannot mutably borrow {:?} storage while it's already borrowed.", id)),
-                    Borrow::Shared => {
-                        f.write_fmt(format_args!("Cannot immutably borrow {:?} storage while it's already mutably borrowed.", id))
+                    Borrow::Unique(holder) => f.write_fmt(format_args!("Cannot mutably borrow {:?} storage while it's already borrowed{}.", id, HeldBy(holder))),
+                    Borrow::Shared(holder) => {
+                        f.write_fmt(format_args!("Cannot immutably borrow {:?} storage while it's already mutably borrowed{}.", id, HeldBy(holder)))
                     },
                     Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {:?} storage from multiple thread at the same time because it's !Sync.", id)),
                     Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {:?} storage from other thread than the one it was created in because it's !Send and !Sync.", id)),
                 }
             }
             GetStorage::Entities(borrow) => match borrow {
-                Borrow::Unique => f.write_str("Cannot mutably borrow Entities storage while it's already borrowed."),
-                Borrow::Shared => {
-                    f.write_str("Cannot immutably borrow Entities storage while it's already mutably borrowed.")
+                Borrow::Unique(holder) => f.write_fmt(format_args!("Cannot mutably borrow Entities storage while it's already borrowed{}.", HeldBy(holder))),
+                Borrow::Shared(holder) => {
+                    f.write_fmt(format_args!("Cannot immutably borrow Entities storage while it's already mutably borrowed{}.", HeldBy(holder)))
                 },
                 _ => unreachable!(),
             },
@@ -223,14 +271,14 @@ impl Debug for NewEntity {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             NewEntity::AllStoragesBorrow(borrow) => match borrow {
-                Borrow::Unique => f.write_str("Cannot mutably borrow all storages while it's already borrowed (this include component storage)."),
-                Borrow::Shared => {
-                    f.write_str("Cannot immutably borrow all storages while it's already mutably borrowed.")
+                Borrow::Unique(holder) => f.write_fmt(format_args!("Cannot mutably borrow all storages while it's already borrowed (this include component storage){}.", HeldBy(holder))),
+                Borrow::Shared(holder) => {
+                    f.write_fmt(format_args!("Cannot immutably borrow all storages while it's already mutably borrowed{}.", HeldBy(holder)))
                 },
                 _ => unreachable!(),
             },
             NewEntity::Entities(borrow) => match borrow {
-                Borrow::Unique => f.write_str("Cannot mutably borrow entities while it's already borrowed."),
+                Borrow::Unique(holder) => f.write_fmt(format_args!("Cannot mutably borrow entities while it's already borrowed{}.", HeldBy(holder))),
                 _ => unreachable!(),
             },
         }
@@ -272,6 +320,63 @@ impl Display for AddComponent {
     }
 }
 
+/// Returned by [`AllStorages::clone_entity`] and [`World::clone_entity`] when trying to clone an entity that is not alive.
+///
+/// [`AllStorages::clone_entity`]: crate::all_storages::AllStorages::clone_entity()
+/// [`World::clone_entity`]: crate::world::World::clone_entity()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CloneEntity {
+    #[allow(missing_docs)]
+    EntityIsNotAlive,
+}
+
+#[cfg(feature = "std")]
+impl Error for CloneEntity {}
+
+impl Debug for CloneEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            CloneEntity::EntityIsNotAlive => f.write_str("Entity has to be alive to be cloned."),
+        }
+    }
+}
+
+impl Display for CloneEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Returned by [`AllStorages::rollback`] and [`World::rollback`] when there are not enough snapshots.
+///
+/// [`AllStorages::rollback`]: crate::all_storages::AllStorages::rollback()
+/// [`World::rollback`]: crate::world::World::rollback()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Rollback {
+    /// No snapshot was saved this many snapshots ago.
+    MissingSnapshot(usize),
+}
+
+#[cfg(feature = "std")]
+impl Error for Rollback {}
+
+impl Debug for Rollback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Rollback::MissingSnapshot(age) => f.write_fmt(format_args!(
+                "No snapshot was saved {} snapshot(s) before the last one.",
+                age
+            )),
+        }
+    }
+}
+
+impl Display for Rollback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error type returned by [`Workload::add_to_world`].
 ///
 /// [`Workload::add_to_world`]: crate::Workload::add_to_world()
@@ -363,18 +468,18 @@ impl Debug for AddWorkload {
             AddWorkload::TrackingStorageBorrow { name, id, borrow } => {
                 if let Some(name) = name {
                     match borrow {
-                        Borrow::Unique => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed.", name)),
-                        Borrow::Shared => {
-                            f.write_fmt(format_args!("Cannot immutably borrow {} storage while it's already mutably borrowed.", name))
+                        Borrow::Unique(holder) => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed{}.", name, HeldBy(holder))),
+                        Borrow::Shared(holder) => {
+                            f.write_fmt(format_args!("Cannot immutably borrow {} storage while it's already mutably borrowed{}.", name, HeldBy(holder)))
                         },
                         Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {} storage from multiple thread at the same time because it's !Sync.", name)),
                         Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
                     }
                 } else {
                     match borrow {
-                        Borrow::Unique => f.write_fmt(format_args!("Cannot mutably borrow {:?} storage while it's already borrowed.", id)),
-                        Borrow::Shared => {
-                            f.write_fmt(format_args!("Cannot immutably borrow {:?} storage while it's already mutably borrowed.", id))
+                        Borrow::Unique(holder) => f.write_fmt(format_args!("Cannot mutably borrow {:?} storage while it's already borrowed{}.", id, HeldBy(holder))),
+                        Borrow::Shared(holder) => {
+                            f.write_fmt(format_args!("Cannot immutably borrow {:?} storage while it's already mutably borrowed{}.", id, HeldBy(holder)))
                         },
                         Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {:?} storage from multiple thread at the same time because it's !Sync.", id)),
                         Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {:?} storage from other thread than the one it was created in because it's !Send and !Sync.", id)),
@@ -391,6 +496,59 @@ impl Display for AddWorkload {
     }
 }
 
+/// Error returned by [`World::remove_system`], [`World::replace_system`] and [`World::insert_system`].
+///
+/// [`World::remove_system`]: crate::World::remove_system()
+/// [`World::replace_system`]: crate::World::replace_system()
+/// [`World::insert_system`]: crate::World::insert_system()
+pub enum EditWorkload {
+    /// The `Scheduler` is already borrowed.
+    Borrow,
+    /// No workload with this name exists.
+    MissingWorkload,
+    /// The workload doesn't contain this system.
+    MissingSystem(Box<dyn Label>),
+    /// The new system can't run in parallel with the other systems of the batch it replaces a system in.
+    Conflict(Box<dyn Label>, Box<Conflict>),
+    /// Systems added to an existing workload can't have `before_all`, `after_all` or `require_*` constraints.
+    Requirements(Box<dyn Label>),
+    /// Enabling the new system's tracking failed.
+    Tracking(GetStorage),
+}
+
+#[cfg(feature = "std")]
+impl Error for EditWorkload {}
+
+impl Debug for EditWorkload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            EditWorkload::Borrow => {
+                f.write_str("Cannot mutably borrow the scheduler while it's already borrowed.")
+            }
+            EditWorkload::MissingWorkload => f.write_str("No workload with this name exists."),
+            EditWorkload::MissingSystem(system) => f.write_fmt(format_args!(
+                "The workload doesn't contain {:?}.",
+                system
+            )),
+            EditWorkload::Conflict(system, conflict) => f.write_fmt(format_args!(
+                "System({:?}) can't take the place of the system it replaces: {:?}",
+                system, conflict
+            )),
+            EditWorkload::Requirements(system) => f.write_fmt(format_args!(
+                "System({:?}) can't have before or after requirements when added to an existing workload.",
+                system
+            )),
+            EditWorkload::Tracking(err) => Debug::fmt(err, f),
+        }
+    }
+}
+
+impl Display for EditWorkload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Trying to set the default workload to a non existent one will result in this error.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum SetDefaultWorkload {
@@ -478,6 +636,39 @@ impl Display for RunWorkload {
     }
 }
 
+/// Error returned by [`World::run_workload_in_worlds`] and [`ScheduledWorkload::run_with_worlds`].
+///
+/// [`World::run_workload_in_worlds`]: crate::World::run_workload_in_worlds()
+/// [`ScheduledWorkload::run_with_worlds`]: crate::ScheduledWorkload::run_with_worlds()
+pub struct RunWorlds {
+    /// Index of each [`World`](crate::World) that failed and its error, in the same order as the worlds.
+    pub errors: Vec<(usize, RunWorkload)>,
+}
+
+#[cfg(feature = "std")]
+impl Error for RunWorlds {}
+
+impl Debug for RunWorlds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_fmt(format_args!(
+            "{} world(s) failed to run the workload:",
+            self.errors.len()
+        ))?;
+
+        for (index, err) in &self.errors {
+            f.write_fmt(format_args!("\nWorld {}: {:?}", index, err))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for RunWorlds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error returned by [`World::run`] and [`AllStorages::run`].
 /// Can refer to an invalid storage borrow or a custom error.
 ///
@@ -606,6 +797,77 @@ impl Display for InvalidSystem {
     }
 }
 
+/// Error returned by [`World::add_group`] and [`AllStorages::add_group`].
+///
+/// [`World::add_group`]: crate::World::add_group()
+/// [`AllStorages::add_group`]: crate::AllStorages::add_group()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AddGroup {
+    /// The same storage is present multiple times in the group.
+    DuplicateStorage(&'static str),
+    /// The storage is already part of another group.
+    AlreadyGrouped(&'static str),
+}
+
+#[cfg(feature = "std")]
+impl Error for AddGroup {}
+
+impl Debug for AddGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AddGroup::DuplicateStorage(name) => f.write_fmt(format_args!(
+                "{} is present multiple times in the group.",
+                name
+            )),
+            AddGroup::AlreadyGrouped(name) => f.write_fmt(format_args!(
+                "{} is already part of a group, a storage can only be part of a single group.",
+                name
+            )),
+        }
+    }
+}
+
+impl Display for AddGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned when a unique index would associate a key with multiple entities.\
+/// Returned by [`World::add_unique_index`], [`AllStorages::add_unique_index`], [`SparseSet::reindex`] and [`ViewMut::try_add_component_unchecked`].
+///
+/// [`World::add_unique_index`]: crate::World::add_unique_index()
+/// [`AllStorages::add_unique_index`]: crate::AllStorages::add_unique_index()
+/// [`SparseSet::reindex`]: crate::SparseSet::reindex()
+/// [`ViewMut::try_add_component_unchecked`]: crate::ViewMut::try_add_component_unchecked()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateKey {
+    /// Entity that could not be indexed.
+    pub entity: EntityId,
+    /// Entity already associated with the key.
+    pub other: EntityId,
+    /// Name of the component.
+    pub name: &'static str,
+}
+
+#[cfg(feature = "std")]
+impl Error for DuplicateKey {}
+
+impl Debug for DuplicateKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_fmt(format_args!(
+            "{:?}'s {} component has the same key as {:?}'s, a unique index can only associate a key with a single entity.",
+            self.entity, self.name, self.other
+        ))
+    }
+}
+
+impl Display for DuplicateKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error returned by [`World::remove_unique`] and [`AllStorages::remove_unique`].
 ///
 /// [`World::remove_unique`]: crate::World::remove_unique()
@@ -629,7 +891,7 @@ impl Debug for UniqueRemove {
             UniqueRemove::AllStorages => f.write_str("Cannot borrow AllStorages while it's already exclusively borrowed."),
             UniqueRemove::MissingUnique(name) => f.write_fmt(format_args!("No unique storage exists for {}.\n", name)),
             UniqueRemove::StorageBorrow((name, borrow)) => match borrow {
-                Borrow::Unique => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed.", name)),
+                Borrow::Unique(holder) => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed{}.", name, HeldBy(holder))),
                 Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
                 _ => unreachable!()
             }
@@ -862,3 +1124,190 @@ impl Display for GetComponent {
         Debug::fmt(self, f)
     }
 }
+
+/// Error returned by [`AllStorages::dynamic_query`] and [`AllStorages::dynamic_query_mut`].
+///
+/// [`AllStorages::dynamic_query`]: crate::AllStorages::dynamic_query
+/// [`AllStorages::dynamic_query_mut`]: crate::AllStorages::dynamic_query_mut
+#[derive(PartialEq)]
+pub enum DynamicQuery {
+    #[allow(missing_docs)]
+    StorageBorrow(GetStorage),
+    /// The storage isn't a `SparseSet`, it can't be queried.
+    NotSparseSet(Cow<'static, str>),
+}
+
+impl From<GetStorage> for DynamicQuery {
+    fn from(get_storage: GetStorage) -> DynamicQuery {
+        DynamicQuery::StorageBorrow(get_storage)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DynamicQuery {}
+
+impl Debug for DynamicQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            DynamicQuery::StorageBorrow(err) => f.write_fmt(format_args!("{:?}", err)),
+            DynamicQuery::NotSparseSet(name) => f.write_fmt(format_args!(
+                "{} isn't a SparseSet, only SparseSets can be queried.",
+                name
+            )),
+        }
+    }
+}
+
+impl Display for DynamicQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`ComponentRegistration`]'s (de)serialization functions.
+///
+/// [`ComponentRegistration`]: crate::ComponentRegistration
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub enum Reflect {
+    #[allow(missing_docs)]
+    GetComponent(GetComponent),
+    #[allow(missing_docs)]
+    EntityIsNotAlive,
+    /// The component was registered without (de)serialization support.
+    NotSerde(&'static str),
+    /// The component failed to (de)serialize.
+    Serde(serde_json::Error),
+}
+
+#[cfg(feature = "serde1")]
+impl From<GetComponent> for Reflect {
+    fn from(get_component: GetComponent) -> Reflect {
+        Reflect::GetComponent(get_component)
+    }
+}
+
+#[cfg(all(feature = "serde1", feature = "std"))]
+impl Error for Reflect {}
+
+#[cfg(feature = "serde1")]
+impl Debug for Reflect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Reflect::GetComponent(err) => f.write_fmt(format_args!("{:?}", err)),
+            Reflect::EntityIsNotAlive => f.write_str("Entity has to be alive to add a component to it."),
+            Reflect::NotSerde(name) => f.write_fmt(format_args!("{} was registered without (de)serialization support, use ComponentRegistry::register_serde.", name)),
+            Reflect::Serde(err) => f.write_fmt(format_args!("Component (de)serialization failed: {}", err)),
+        }
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl Display for Reflect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`World::spawn_scene`] and [`World::export_scene`].
+///
+/// [`World::spawn_scene`]: crate::World::spawn_scene
+/// [`World::export_scene`]: crate::World::export_scene
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub enum Scene {
+    #[allow(missing_docs)]
+    Reflect(Reflect),
+    /// No component is registered under this name.
+    UnknownComponent(String),
+    /// Multiple scene entities have this id.
+    DuplicateEntity(EntityId),
+}
+
+#[cfg(feature = "serde1")]
+impl From<Reflect> for Scene {
+    fn from(reflect: Reflect) -> Scene {
+        Scene::Reflect(reflect)
+    }
+}
+
+#[cfg(all(feature = "serde1", feature = "std"))]
+impl Error for Scene {}
+
+#[cfg(feature = "serde1")]
+impl Debug for Scene {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Scene::Reflect(err) => f.write_fmt(format_args!("{:?}", err)),
+            Scene::UnknownComponent(name) => {
+                f.write_fmt(format_args!("No component is registered as {}.", name))
+            }
+            Scene::DuplicateEntity(entity) => f.write_fmt(format_args!(
+                "Multiple scene entities have the id {:?}.",
+                entity
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl Display for Scene {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`World::change_set`] and [`World::apply_change_set`].
+///
+/// [`World::change_set`]: crate::World::change_set
+/// [`World::apply_change_set`]: crate::World::apply_change_set
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub enum Replication {
+    #[allow(missing_docs)]
+    Reflect(Reflect),
+    /// The storage doesn't track insertion, modification, removal and deletion.
+    Untracked(&'static str),
+    /// No component is registered under this name.
+    UnknownComponent(String),
+    /// A component failed to (de)serialize with the [`ReplicationFormat`].
+    ///
+    /// [`ReplicationFormat`]: crate::ReplicationFormat
+    Format(Box<dyn Display + Send + Sync>),
+}
+
+#[cfg(feature = "serde1")]
+impl From<Reflect> for Replication {
+    fn from(reflect: Reflect) -> Replication {
+        Replication::Reflect(reflect)
+    }
+}
+
+#[cfg(all(feature = "serde1", feature = "std"))]
+impl Error for Replication {}
+
+#[cfg(feature = "serde1")]
+impl Debug for Replication {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Replication::Reflect(err) => f.write_fmt(format_args!("{:?}", err)),
+            Replication::Untracked(name) => f.write_fmt(format_args!(
+                "{} has to track all changes to be replicated, use `track::All`.",
+                name
+            )),
+            Replication::UnknownComponent(name) => {
+                f.write_fmt(format_args!("No component is registered as {}.", name))
+            }
+            Replication::Format(err) => {
+                f.write_fmt(format_args!("Component (de)serialization failed: {}", err))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl Display for Replication {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
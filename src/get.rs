@@ -74,6 +74,8 @@ impl<'a, 'b, T: Component, TRACK> Get for &'b mut ViewMut<'a, T, TRACK> {
                 name: type_name::<T>(),
             })?;
 
+        self.sparse_set.mark_index_dirty(entity);
+
         let SparseSet {
             data,
             modification_data,
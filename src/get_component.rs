@@ -6,7 +6,7 @@ use crate::borrow::{NonSend, NonSendSync, NonSync};
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
-use crate::sparse_set::SparseSet;
+use crate::sparse_set::{ReindexOnDrop, SparseSet};
 use core::any::type_name;
 use core::ops::{Deref, DerefMut};
 
@@ -52,6 +52,7 @@ pub struct RefMut<'a, T> {
     inner: T,
     flag: Option<&'a mut u32>,
     current: u32,
+    reindex: Option<ReindexOnDrop<'a>>,
     all_borrow: Option<SharedBorrow<'a>>,
     borrow: ExclusiveBorrow<'a>,
 }
@@ -66,6 +67,7 @@ impl<'a, T> RefMut<'a, T> {
             inner: f(orig.inner),
             flag: orig.flag,
             current: orig.current,
+            reindex: orig.reindex,
             all_borrow: orig.all_borrow,
             borrow: orig.borrow,
         }
@@ -259,6 +261,9 @@ impl<T: Component + Send + Sync> GetComponent for &'_ mut T {
                 name: type_name::<T>(),
             })?;
 
+        sparse_set.mark_index_dirty(entity);
+        let reindex = unsafe { ReindexOnDrop::new(sparse_set) };
+
         let SparseSet {
             data,
             modification_data,
@@ -271,6 +276,7 @@ impl<T: Component + Send + Sync> GetComponent for &'_ mut T {
             flag: is_tracking_modification
                 .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
             current,
+            reindex,
             all_borrow,
             borrow,
         })
@@ -300,6 +306,9 @@ impl<T: Component + Sync> GetComponent for NonSend<&'_ mut T> {
                 name: type_name::<T>(),
             })?;
 
+        sparse_set.0.mark_index_dirty(entity);
+        let reindex = unsafe { ReindexOnDrop::new(&mut sparse_set.0) };
+
         let NonSend(SparseSet {
             data,
             modification_data,
@@ -312,6 +321,7 @@ impl<T: Component + Sync> GetComponent for NonSend<&'_ mut T> {
             flag: is_tracking_modification
                 .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
             current,
+            reindex,
             all_borrow,
             borrow,
         })
@@ -341,6 +351,9 @@ impl<T: Component + Send> GetComponent for NonSync<&'_ mut T> {
                 name: type_name::<T>(),
             })?;
 
+        sparse_set.0.mark_index_dirty(entity);
+        let reindex = unsafe { ReindexOnDrop::new(&mut sparse_set.0) };
+
         let NonSync(SparseSet {
             data,
             modification_data,
@@ -353,6 +366,7 @@ impl<T: Component + Send> GetComponent for NonSync<&'_ mut T> {
             flag: is_tracking_modification
                 .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
             current,
+            reindex,
             all_borrow,
             borrow,
         })
@@ -382,6 +396,9 @@ impl<T: Component> GetComponent for NonSendSync<&'_ mut T> {
                 name: type_name::<T>(),
             })?;
 
+        sparse_set.0.mark_index_dirty(entity);
+        let reindex = unsafe { ReindexOnDrop::new(&mut sparse_set.0) };
+
         let NonSendSync(SparseSet {
             data,
             modification_data,
@@ -394,6 +411,7 @@ impl<T: Component> GetComponent for NonSendSync<&'_ mut T> {
             flag: is_tracking_modification
                 .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
             current,
+            reindex,
             all_borrow,
             borrow,
         })
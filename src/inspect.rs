@@ -0,0 +1,171 @@
+use crate::all_storages::AllStorages;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::memory_usage::StorageMemoryUsage;
+use crate::reflect::ComponentRegistry;
+use crate::world::World;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// Serializable dump of a `World`'s entities, components and storages' memory usage,
+/// returned by [`World::inspect`].
+///
+/// ### Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use shipyard::{Component, ComponentRegistry, World};
+///
+/// #[derive(Component, Serialize, Deserialize)]
+/// struct Health(u32);
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// let mut registry = ComponentRegistry::new();
+/// registry.register_serde::<Health>("Health");
+/// registry.register::<Player>("Player");
+///
+/// let mut world = World::new();
+/// world.add_entity((Health(10),));
+/// let player = world.add_entity((Health(20), Player));
+///
+/// let inspection = world.inspect(&registry).unwrap();
+///
+/// assert_eq!(inspection.entities.len(), 2);
+/// let players = inspection.with_component("Player").collect::<Vec<_>>();
+/// assert_eq!(players.len(), 1);
+/// assert_eq!(players[0].id, player);
+/// assert_eq!(players[0].components["Health"], Some(serde_json::json!(20)));
+/// assert_eq!(players[0].components["Player"], None);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Inspection {
+    /// All alive entities, in index order.
+    pub entities: Vec<InspectedEntity>,
+    /// Memory usage of all storages, sorted by name.
+    pub storages: Vec<StorageMemoryUsage>,
+}
+
+/// Entity of an [`Inspection`].
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InspectedEntity {
+    #[allow(missing_docs)]
+    pub id: EntityId,
+    /// The entity's components registered in the [`ComponentRegistry`] the inspection was made with,
+    /// indexed by name.\
+    /// The value is `None` for components registered without (de)serialization support.
+    pub components: BTreeMap<String, Option<serde_json::Value>>,
+}
+
+impl Inspection {
+    /// Returns the entities with the component registered under `name`.
+    pub fn with_component<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a InspectedEntity> + 'a {
+        self.entities
+            .iter()
+            .filter(move |entity| entity.components.contains_key(name))
+    }
+}
+
+impl AllStorages {
+    /// Lists all alive entities with their components registered in `registry`
+    /// and the memory usage of all storages.\
+    /// Storages exclusively borrowed are not part of the memory usage list.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`Entities`] (shared)
+    /// - storages of the registered components (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    /// - A component failed to serialize.
+    ///
+    /// [`Entities`]: crate::Entities
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn inspect(&self, registry: &ComponentRegistry) -> Result<Inspection, error::Reflect> {
+        let entities = self
+            .entities()
+            .map_err(|err| error::Reflect::GetComponent(err.into()))?;
+
+        let mut inspection = Inspection::default();
+        let mut positions = HashMap::new();
+        for entity in entities.iter() {
+            positions.insert(entity, inspection.entities.len());
+            inspection.entities.push(InspectedEntity {
+                id: entity,
+                components: BTreeMap::new(),
+            });
+        }
+        drop(entities);
+
+        for registration in registry {
+            let is_serde = registration.is_serde();
+
+            for entity in registration
+                .list(self)
+                .map_err(|err| error::Reflect::GetComponent(err.into()))?
+            {
+                let value = if is_serde {
+                    Some(registration.get_value(self, entity)?)
+                } else {
+                    None
+                };
+
+                if let Some(&position) = positions.get(&entity) {
+                    inspection.entities[position]
+                        .components
+                        .insert(registration.name().to_string(), value);
+                }
+            }
+        }
+
+        let storages = self.storages.read();
+        inspection.storages = storages
+            .values()
+            .filter_map(|storage| unsafe { &*(storage.0) }.borrow().ok()?.memory_usage())
+            .collect();
+        inspection
+            .storages
+            .sort_unstable_by(|usage, other| usage.storage_name.cmp(&other.storage_name));
+
+        Ok(inspection)
+    }
+}
+
+impl World {
+    /// Lists all alive entities with their components registered in `registry`
+    /// and the memory usage of all storages.\
+    /// Storages exclusively borrowed are not part of the memory usage list.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - [`Entities`] (shared)
+    /// - storages of the registered components (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    /// - A component failed to serialize.
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    /// [`Entities`]: crate::Entities
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn inspect(&self, registry: &ComponentRegistry) -> Result<Inspection, error::Reflect> {
+        let all_storages = self.all_storages.borrow().map_err(|err| {
+            error::Reflect::GetComponent(error::GetStorage::AllStoragesBorrow(err).into())
+        })?;
+
+        all_storages.inspect(registry)
+    }
+}
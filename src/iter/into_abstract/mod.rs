@@ -7,7 +7,7 @@ mod or;
 use super::abstract_mut::AbstractMut;
 use crate::component::Component;
 use crate::entity_id::EntityId;
-use crate::sparse_set::{FullRawWindow, FullRawWindowMut, SparseSet};
+use crate::sparse_set::{FullRawWindow, FullRawWindowMut, GroupState, SparseSet};
 use crate::sparse_set::{SparseArray, BUCKET_SIZE};
 use crate::type_id::TypeId;
 use crate::views::{View, ViewMut};
@@ -51,6 +51,10 @@ pub trait IntoAbstract {
     fn other_dense(&self) -> Vec<core::slice::Iter<'static, EntityId>> {
         Vec::new()
     }
+    #[doc(hidden)]
+    fn group(&self) -> Option<GroupState> {
+        None
+    }
 }
 
 impl<'a, T: Component, TRACK> IntoAbstract for &'a View<'a, T, TRACK> {
@@ -76,6 +80,10 @@ impl<'a, T: Component, TRACK> IntoAbstract for &'a View<'a, T, TRACK> {
     fn dense(&self) -> *const EntityId {
         self.dense.as_ptr()
     }
+    #[inline]
+    fn group(&self) -> Option<GroupState> {
+        self.group_state()
+    }
 }
 
 impl<'a: 'b, 'b, T: Component, TRACK> IntoAbstract for &'b ViewMut<'a, T, TRACK> {
@@ -101,6 +109,10 @@ impl<'a: 'b, 'b, T: Component, TRACK> IntoAbstract for &'b ViewMut<'a, T, TRACK>
     fn dense(&self) -> *const EntityId {
         self.dense.as_ptr()
     }
+    #[inline]
+    fn group(&self) -> Option<GroupState> {
+        self.group_state()
+    }
 }
 
 impl<'a: 'b, 'b, T: Component, TRACK> IntoAbstract for &'b mut ViewMut<'a, T, TRACK> {
@@ -126,4 +138,8 @@ impl<'a: 'b, 'b, T: Component, TRACK> IntoAbstract for &'b mut ViewMut<'a, T, TR
     fn dense(&self) -> *const EntityId {
         self.dense.as_ptr()
     }
+    #[inline]
+    fn group(&self) -> Option<GroupState> {
+        self.group_state()
+    }
 }
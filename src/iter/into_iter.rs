@@ -6,6 +6,7 @@ use super::mixed::Mixed;
 use super::par_iter::ParIter;
 use super::tight::Tight;
 use crate::entity_id::EntityId;
+use crate::sparse_set::group_iteration;
 use crate::type_id::TypeId;
 use alloc::vec::Vec;
 use core::ptr;
@@ -181,6 +182,31 @@ macro_rules! impl_into_iter {
 
             #[allow(clippy::drop_copy)]
             fn iter(self) -> Self::IntoIter {
+                let groups = [self.$index1.group(), $(self.$index.group()),+];
+
+                // all storages of a packed group are present, the group's entities are at the start of their storages
+                if let Some((mask, group_index, len)) = group_iteration(&groups) {
+                    if mask.count_ones() as usize == groups.len() {
+                        return Iter::Tight(Tight {
+                            current: 0,
+                            end: len,
+                            storage: (self.$index1.into_abstract(), $(self.$index.into_abstract(),)+),
+                        });
+                    }
+
+                    let dense = [self.$index1.dense(), $(self.$index.dense()),+][group_index];
+                    let slice = unsafe { core::slice::from_raw_parts(dense, len) };
+
+                    return Iter::Mixed(Mixed {
+                        count: 0,
+                        mask,
+                        indices: slice.iter(),
+                        last_id: EntityId::dead(),
+                        storage: (self.$index1.into_abstract(), $(self.$index.into_abstract(),)+),
+                        rev_next_storage: Vec::new(),
+                    });
+                }
+
                 let type_ids = [self.$index1.type_id(), $(self.$index.type_id()),+];
                 let mut smallest = core::usize::MAX;
                 let mut smallest_dense = ptr::null();
@@ -11,8 +11,8 @@ use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::iter::{AbstractMut, Iter, Mixed, Tight};
-use crate::sparse_set::SparseSet;
 use crate::sparse_set::{FullRawWindow, FullRawWindowMut};
+use crate::sparse_set::{ReindexOnDrop, SparseSet};
 use crate::views::{View, ViewMut};
 use alloc::vec::Vec;
 use core::any::TypeId;
@@ -277,7 +277,7 @@ impl<T: Component> IterComponent for NonSendSync<&'_ T> {
 
 impl<T: Component + Send + Sync> IterComponent for &'_ mut T {
     type Storage<'a> = FullRawWindowMut<'a, T>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<ReindexOnDrop<'a>>, ExclusiveBorrow<'a>);
 
     fn into_abtract_mut<'a>(
         all_storages: &'a AllStorages,
@@ -331,7 +331,7 @@ impl<T: Component + Send + Sync> IterComponent for &'_ mut T {
 #[cfg(feature = "thread_local")]
 impl<T: Component + Sync> IterComponent for NonSend<&'_ mut T> {
     type Storage<'a> = FullRawWindowMut<'a, T>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<ReindexOnDrop<'a>>, ExclusiveBorrow<'a>);
 
     fn into_abtract_mut<'a>(
         all_storages: &'a AllStorages,
@@ -385,7 +385,7 @@ impl<T: Component + Sync> IterComponent for NonSend<&'_ mut T> {
 #[cfg(feature = "thread_local")]
 impl<T: Component + Send> IterComponent for NonSync<&'_ mut T> {
     type Storage<'a> = FullRawWindowMut<'a, T>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<ReindexOnDrop<'a>>, ExclusiveBorrow<'a>);
 
     fn into_abtract_mut<'a>(
         all_storages: &'a AllStorages,
@@ -439,7 +439,7 @@ impl<T: Component + Send> IterComponent for NonSync<&'_ mut T> {
 #[cfg(feature = "thread_local")]
 impl<T: Component> IterComponent for NonSendSync<&'_ mut T> {
     type Storage<'a> = FullRawWindowMut<'a, T>;
-    type Borrow<'a> = ExclusiveBorrow<'a>;
+    type Borrow<'a> = (Option<ReindexOnDrop<'a>>, ExclusiveBorrow<'a>);
 
     fn into_abtract_mut<'a>(
         all_storages: &'a AllStorages,
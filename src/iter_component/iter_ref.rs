@@ -9,8 +9,9 @@ use core::marker::PhantomData;
 #[allow(missing_docs)]
 pub struct IterRef<'a, T: IterComponent> {
     pub(crate) iter: Iter<T::Storage<'a>>,
-    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
+    // dropped before `_all_borrow`, the storage might still be accessed when its borrow ends
     pub(crate) _borrow: T::Borrow<'a>,
+    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
 }
 
 impl<'a, T: IterComponent> Iterator for IterRef<'a, T> {
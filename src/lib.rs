@@ -9,7 +9,7 @@
 //! - **serde1** &mdash; adds (de)serialization support with [serde](https://github.com/serde-rs/serde)
 //! - **std** *(default)* &mdash; lets Shipyard use the standard library
 //! - **thread_local** &mdash; adds methods and types required to work with `!Send` and `!Sync` components
-//! - **tracing** &mdash; reports workload and system execution
+//! - **tracing** &mdash; reports workload and system execution, including each system's batch, borrows and `run_if` skips, as well as borrows and tracking updates
 
 #![warn(elided_lifetimes_in_paths)]
 #![warn(trivial_casts)]
@@ -39,6 +39,7 @@ mod all_storages;
 mod atomic_refcell;
 /// Allows access to helper types needed to implement `Borrow`.
 pub mod borrow;
+mod checksum;
 mod component;
 mod contains;
 mod delete;
@@ -47,20 +48,30 @@ mod entity_id;
 pub mod error;
 mod get;
 mod get_component;
+#[cfg(feature = "serde1")]
+mod inspect;
 pub mod iter;
 mod iter_component;
+mod map_entities;
 mod memory_usage;
 mod r#mut;
 mod not;
 mod or;
 mod public_transport;
+mod reflect;
 mod remove;
+#[cfg(feature = "serde1")]
+mod replication;
 mod reserve;
+#[cfg(feature = "serde1")]
+mod scene;
 mod scheduler;
 mod seal;
+mod snapshot;
 mod sparse_set;
 mod storage;
 mod system;
+mod template;
 /// module related to storage tracking, like insertion or modification.
 pub mod track;
 mod tracking;
@@ -83,40 +94,60 @@ pub use crate::borrow::NonSync;
 pub use add_component::AddComponent;
 pub use add_distinct_component::AddDistinctComponent;
 pub use add_entity::AddEntity;
-pub use all_storages::{AllStorages, CustomStorageAccess, TupleDeleteAny, TupleRetain};
+pub use all_storages::{
+    AllStorages, CustomStorageAccess, DynamicComponentMut, DynamicQuery, DynamicQueryMut,
+    EntityDebug, TupleDeleteAny, TupleRetain,
+};
 pub use atomic_refcell::{ARef, ARefMut};
 #[doc(hidden)]
 pub use atomic_refcell::{ExclusiveBorrow, SharedBorrow};
 #[doc(inline)]
 pub use borrow::{Borrow, BorrowInfo, Mutability, WorldBorrow};
-pub use component::{Component, Unique, Local};
+pub use checksum::Checksum;
+pub use component::{Component, Indexed, Unique, Local};
 pub use contains::Contains;
 pub use delete::Delete;
 pub use entities::Entities;
 pub use entity_id::EntityId;
 pub use get::Get;
 pub use get_component::{GetComponent, Ref, RefMut};
+#[cfg(feature = "serde1")]
+pub use inspect::{InspectedEntity, Inspection};
 pub use iter::{IntoIter, IntoWithId};
 pub use iter_component::{IntoIterRef, IterComponent, IterRef};
-pub use memory_usage::StorageMemoryUsage;
+pub use map_entities::{EntityMap, EntityMapIter, MapEntities};
+pub use memory_usage::{
+    MemoryReport, MemorySection, SchedulerMemoryUsage, StorageKind, StorageMemoryBreakdown,
+    StorageMemoryReport, StorageMemoryUsage,
+};
 pub use not::Not;
 pub use or::{OneOfTwo, Or};
 pub use r#mut::Mut;
+pub use reflect::{ComponentRegistration, ComponentRegistrationBuilder, ComponentRegistry};
 pub use remove::Remove;
+#[cfg(feature = "serde1")]
+pub use replication::{ChangeSet, ComponentChanges, Json, Replication, ReplicationFormat};
 pub use reserve::{BulkEntityIter, BulkReserve};
+#[cfg(feature = "serde1")]
+pub use scene::{Scene, SceneEntity};
+#[cfg(all(feature = "std", feature = "serde1"))]
+pub use scheduler::Publisher;
 pub use scheduler::{
-    info, AsLabel, IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem, Label,
-    ScheduledWorkload, SystemModificator, Workload, WorkloadModificator, WorkloadSystem,
+    info, AsLabel, IntoPipedSystem, IntoSystemPipe, IntoWorkload, IntoWorkloadSystem,
+    IntoWorkloadTrySystem, Label, Pipe, ScheduledWorkload, SystemModificator, Workload,
+    WorkloadModificator, WorkloadSystem,
 };
 #[cfg(feature = "proc")]
 pub use shipyard_proc::{Borrow, BorrowInfo, Component, Unique, Local, WorldBorrow};
+pub use snapshot::Snapshots;
 pub use sparse_set::{
     BulkAddEntity, SparseArray, SparseSet, SparseSetDrain, TupleAddComponent, TupleDelete,
-    TupleRemove,
+    TupleGroup, TupleRemove,
 };
 pub use storage::{Storage, StorageId};
 #[doc(hidden)]
 pub use system::{AllSystem, Nothing, System};
+pub use template::Template;
 pub use tracking::{
     DeletionTracking, Inserted, InsertedOrModified, InsertionTracking, ModificationTracking,
     Modified, RemovalOrDeletionTracking, RemovalTracking, Track, Tracking, TrackingTimestamp,
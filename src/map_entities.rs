@@ -0,0 +1,109 @@
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use hashbrown::hash_map::{HashMap, Iter};
+
+/// Old to new [`EntityId`] mapping, returned by [`World::merge_from`].
+///
+/// [`World::merge_from`]: crate::World::merge_from()
+#[derive(Default, Clone, Debug)]
+pub struct EntityMap {
+    map: HashMap<EntityId, EntityId>,
+}
+
+impl EntityMap {
+    /// Creates an empty `EntityMap`.
+    #[inline]
+    pub fn new() -> EntityMap {
+        EntityMap::default()
+    }
+    /// Maps `old` to `new`.
+    #[inline]
+    pub fn insert(&mut self, old: EntityId, new: EntityId) {
+        self.map.insert(old, new);
+    }
+    /// Removes `old`'s mapping and returns its new id, if it was mapped.
+    #[inline]
+    pub fn remove(&mut self, old: EntityId) -> Option<EntityId> {
+        self.map.remove(&old)
+    }
+    /// Returns the new id of `old`, if it was mapped.
+    #[inline]
+    pub fn get(&self, old: EntityId) -> Option<EntityId> {
+        self.map.get(&old).copied()
+    }
+    /// Returns the new id of `old`.\
+    /// Returns [`EntityId::dead`] if `old` wasn't mapped, it doesn't exist in the new `World`.
+    #[inline]
+    pub fn map(&self, old: EntityId) -> EntityId {
+        self.get(old).unwrap_or_else(EntityId::dead)
+    }
+    /// Returns the number of mapped entities.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+    /// Returns `true` if no entity is mapped.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+    /// Returns an iterator over all `(old, new)` pairs, in no particular order.
+    #[inline]
+    pub fn iter(&self) -> EntityMapIter<'_> {
+        EntityMapIter(self.map.iter())
+    }
+}
+
+impl<'a> IntoIterator for &'a EntityMap {
+    type Item = (EntityId, EntityId);
+    type IntoIter = EntityMapIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the `(old, new)` pairs of an [`EntityMap`].
+pub struct EntityMapIter<'a>(Iter<'a, EntityId, EntityId>);
+
+impl Iterator for EntityMapIter<'_> {
+    type Item = (EntityId, EntityId);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(&old, &new)| (old, new))
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Indicates that a [`Component`] stores [`EntityId`]s that have to be rewritten when it's moved to another `World`.
+///
+/// The component has to be registered with [`ComponentRegistrationBuilder::with_map_entities`]
+/// in the [`ComponentRegistry`] given to [`World::merge_from`].
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, EntityId, EntityMap, MapEntities};
+///
+/// struct Parent(EntityId);
+///
+/// impl Component for Parent {}
+///
+/// impl MapEntities for Parent {
+///     fn map_entities(&mut self, entity_map: &EntityMap) {
+///         self.0 = entity_map.map(self.0);
+///     }
+/// }
+/// ```
+///
+/// [`ComponentRegistrationBuilder::with_map_entities`]: crate::ComponentRegistrationBuilder::with_map_entities()
+/// [`ComponentRegistry`]: crate::ComponentRegistry
+/// [`World::merge_from`]: crate::World::merge_from()
+pub trait MapEntities: Component {
+    /// Rewrites all [`EntityId`]s stored in this component using `entity_map`.
+    fn map_entities(&mut self, entity_map: &EntityMap);
+}
@@ -1,12 +1,16 @@
 use crate::all_storages::AllStorages;
 use crate::world::World;
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::mem::size_of;
 
 pub struct WorldMemoryUsage<'w>(pub(crate) &'w World);
 
 pub struct AllStoragesMemoryUsage<'a>(pub(crate) &'a AllStorages);
 
 /// A trait to query the amount of memory a storage uses.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 pub struct StorageMemoryUsage {
     #[allow(missing_docs)]
     pub storage_name: Cow<'static, str>,
@@ -14,6 +18,9 @@ pub struct StorageMemoryUsage {
     pub used_memory_bytes: usize,
     /// Amount of memory allocated by the storage in bytes (including reserved memory).
     pub allocated_memory_bytes: usize,
+    /// Split of `allocated_memory_bytes` between components, sparse array and tracking.
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub breakdown: StorageMemoryBreakdown,
     #[allow(missing_docs)]
     pub component_count: usize,
 }
@@ -21,11 +28,146 @@ pub struct StorageMemoryUsage {
 impl core::fmt::Debug for StorageMemoryUsage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!(
-            "{}: {} bytes used for {} components ({} bytes reserved in total)",
+            "{}: {} bytes used for {} components ({} bytes reserved in total: {} for components, {} for the sparse array and {} for tracking)",
             self.storage_name,
             self.used_memory_bytes,
             self.component_count,
-            self.allocated_memory_bytes
+            self.allocated_memory_bytes,
+            self.breakdown.component_memory_bytes,
+            self.breakdown.sparse_memory_bytes,
+            self.breakdown.tracking_memory_bytes
         ))
     }
 }
+
+/// Part of [`StorageMemoryUsage::allocated_memory_bytes`] used by each part of a storage.\
+/// Custom storages can start from `StorageMemoryBreakdown::default()` and set the parts they have.
+///
+/// [`StorageMemoryUsage::allocated_memory_bytes`]: StorageMemoryUsage#structfield.allocated_memory_bytes
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct StorageMemoryBreakdown {
+    /// Memory holding the components and their `EntityId`.
+    pub component_memory_bytes: usize,
+    /// Memory holding the sparse array.
+    pub sparse_memory_bytes: usize,
+    /// Memory holding insertion, modification, deletion and removal tracking data.
+    pub tracking_memory_bytes: usize,
+}
+
+/// Structured memory usage of all storages and the scheduler, returned by [`World::memory_report`].
+///
+/// [`World::memory_report`]: crate::World::memory_report()
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryReport {
+    /// Storages sorted by name.
+    pub storages: Vec<StorageMemoryReport>,
+    /// Number of storages that could not be borrowed and are not part of the report.
+    pub borrowed_storages: usize,
+    /// `None` when the report comes from [`AllStorages::memory_report`].
+    ///
+    /// [`AllStorages::memory_report`]: crate::AllStorages::memory_report()
+    pub scheduler: Option<SchedulerMemoryUsage>,
+    /// Sum of the storages and scheduler used memory in bytes.
+    pub used_memory_bytes: usize,
+    /// Sum of the storages and scheduler allocated memory in bytes (including reserved memory).
+    pub allocated_memory_bytes: usize,
+}
+
+/// Memory usage of a single storage, split by allocation.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct StorageMemoryReport {
+    #[allow(missing_docs)]
+    pub storage_name: Cow<'static, str>,
+    #[allow(missing_docs)]
+    pub kind: StorageKind,
+    #[allow(missing_docs)]
+    pub component_count: usize,
+    /// Amount of memory used by the storage in bytes, the storage itself included.
+    pub used_memory_bytes: usize,
+    /// Amount of memory allocated by the storage in bytes (including reserved memory), the storage itself included.
+    pub allocated_memory_bytes: usize,
+    /// Split of `allocated_memory_bytes` between components, sparse array and tracking.
+    pub breakdown: StorageMemoryBreakdown,
+    /// Heap allocations of the storage.\
+    /// Empty for custom storages.
+    pub sections: Vec<MemorySection>,
+}
+
+/// Kind of storage in a [`StorageMemoryReport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageKind {
+    #[allow(missing_docs)]
+    SparseSet,
+    #[allow(missing_docs)]
+    Entities,
+    #[allow(missing_docs)]
+    Unique,
+    #[allow(missing_docs)]
+    Local,
+    /// Storage implemented outside of shipyard.
+    Custom,
+}
+
+/// A single allocation of a storage.\
+/// For example the `SparseSet`'s `"dense"` entity list or `"insertion_data"` tracking vector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemorySection {
+    #[allow(missing_docs)]
+    pub name: Cow<'static, str>,
+    /// Number of elements, for the sparse array it's the number of allocated buckets.
+    pub len: usize,
+    #[allow(missing_docs)]
+    pub used_memory_bytes: usize,
+    #[allow(missing_docs)]
+    pub allocated_memory_bytes: usize,
+}
+
+impl MemorySection {
+    /// Memory used by a `Vec`.
+    pub(crate) fn vec<T>(name: &'static str, vec: &Vec<T>) -> MemorySection {
+        MemorySection {
+            name: name.into(),
+            len: vec.len(),
+            used_memory_bytes: vec.len() * size_of::<T>(),
+            allocated_memory_bytes: vec.capacity() * size_of::<T>(),
+        }
+    }
+}
+
+/// Approximate memory usage of the scheduler.\
+/// Systems' captured state is included but not memory they allocate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchedulerMemoryUsage {
+    #[allow(missing_docs)]
+    pub system_count: usize,
+    #[allow(missing_docs)]
+    pub workload_count: usize,
+    #[allow(missing_docs)]
+    pub used_memory_bytes: usize,
+    #[allow(missing_docs)]
+    pub allocated_memory_bytes: usize,
+}
+
+impl StorageMemoryReport {
+    /// Report of a storage only implementing [`Storage::memory_usage`].
+    ///
+    /// [`Storage::memory_usage`]: crate::Storage::memory_usage()
+    pub fn from_memory_usage(memory_usage: StorageMemoryUsage) -> StorageMemoryReport {
+        StorageMemoryReport {
+            storage_name: memory_usage.storage_name,
+            kind: StorageKind::Custom,
+            component_count: memory_usage.component_count,
+            used_memory_bytes: memory_usage.used_memory_bytes,
+            allocated_memory_bytes: memory_usage.allocated_memory_bytes,
+            breakdown: memory_usage.breakdown,
+            sections: Vec::new(),
+        }
+    }
+}
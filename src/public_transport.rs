@@ -11,6 +11,10 @@ pub(crate) enum RwLock<T> {
     Std { lock: std::sync::RwLock<T> },
 }
 
+// Same bounds as `std::sync::RwLock`, the custom lock guards `value`
+unsafe impl<T: Send> Send for RwLock<T> {}
+unsafe impl<T: Send + Sync> Sync for RwLock<T> {}
+
 pub(crate) enum ReadGuard<'a, T> {
     Custom {
         lock: &'a dyn ShipyardRwLock,
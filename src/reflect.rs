@@ -0,0 +1,627 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::component::Component;
+#[cfg(feature = "serde1")]
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::get_component::Ref;
+use crate::map_entities::{EntityMap, MapEntities};
+use crate::sparse_set::SparseSet;
+use crate::storage::{Storage, StorageId};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::{type_name, Any};
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::marker::PhantomData;
+use hashbrown::HashMap;
+
+type GetFn =
+    for<'a> fn(&'a AllStorages, EntityId) -> Result<Ref<'a, &'a dyn Any>, error::GetComponent>;
+type CloneFn = fn(&mut dyn Storage, EntityId, EntityId, u32);
+pub(crate) type DebugFn = for<'a> fn(&'a dyn Storage, EntityId) -> Option<&'a dyn Debug>;
+type HashFn = fn(Option<&dyn Storage>) -> u64;
+
+/// Type erased access to a component registered in a [`ComponentRegistry`].
+#[derive(Clone)]
+pub struct ComponentRegistration {
+    name: Cow<'static, str>,
+    type_name: &'static str,
+    storage_id: StorageId,
+    get: GetFn,
+    remove: fn(&mut AllStorages, EntityId) -> Option<Box<dyn Any>>,
+    list: fn(&AllStorages) -> Result<Vec<EntityId>, error::GetStorage>,
+    clone_component: Option<CloneFn>,
+    map_entities: Option<fn(&mut dyn Storage, &EntityMap)>,
+    debug: Option<DebugFn>,
+    hash: Option<HashFn>,
+    #[cfg(feature = "serde1")]
+    serde: Option<SerdeFns>,
+}
+
+#[cfg(feature = "serde1")]
+#[derive(Clone, Copy)]
+struct SerdeFns {
+    get_value: fn(&AllStorages, EntityId) -> Result<serde_json::Value, error::Reflect>,
+    insert_value: fn(&mut AllStorages, EntityId, serde_json::Value) -> Result<(), error::Reflect>,
+    map_value: Option<MapValueFn>,
+    deserialize: fn(serde_json::Value) -> Result<Box<dyn Any>, error::Reflect>,
+    insert_deserialized: fn(&mut AllStorages, EntityId, Box<dyn Any>, &EntityMap),
+}
+
+#[cfg(feature = "serde1")]
+type MapValueFn = fn(serde_json::Value, &EntityMap) -> Result<serde_json::Value, error::Reflect>;
+
+impl ComponentRegistration {
+    fn new<T: Send + Sync + Component>(name: Cow<'static, str>) -> ComponentRegistration {
+        ComponentRegistration {
+            name,
+            type_name: type_name::<T>(),
+            storage_id: StorageId::of::<SparseSet<T>>(),
+            get: get::<T>,
+            remove: remove::<T>,
+            list: list::<T>,
+            clone_component: None,
+            map_entities: None,
+            debug: None,
+            hash: None,
+            #[cfg(feature = "serde1")]
+            serde: None,
+        }
+    }
+    /// Returns the name the component was registered with.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Returns the component's type name.
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+    /// Returns the id of the component's storage.
+    #[inline]
+    pub fn storage_id(&self) -> StorageId {
+        self.storage_id
+    }
+    /// Returns `entity`'s component, it can be downcast to the component's type.
+    #[inline]
+    pub fn get<'a>(
+        &self,
+        all_storages: &'a AllStorages,
+        entity: EntityId,
+    ) -> Result<Ref<'a, &'a dyn Any>, error::GetComponent> {
+        (self.get)(all_storages, entity)
+    }
+    /// Removes `entity`'s component and returns it, it can be downcast to the component's type.
+    #[inline]
+    pub fn remove(&self, all_storages: &mut AllStorages, entity: EntityId) -> Option<Box<dyn Any>> {
+        (self.remove)(all_storages, entity)
+    }
+    /// Returns all entities with this component.
+    #[inline]
+    pub fn list(&self, all_storages: &AllStorages) -> Result<Vec<EntityId>, error::GetStorage> {
+        (self.list)(all_storages)
+    }
+    /// Returns `true` if the component was registered with [`ComponentRegistrationBuilder::with_clone`].
+    #[inline]
+    pub fn is_clone(&self) -> bool {
+        self.clone_component.is_some()
+    }
+    /// Clones `from`'s component to `to` in `storage`, if the component can be cloned.
+    #[inline]
+    pub(crate) fn clone_component(
+        &self,
+        storage: &mut dyn Storage,
+        from: EntityId,
+        to: EntityId,
+        current: u32,
+    ) {
+        if let Some(clone_component) = self.clone_component {
+            (clone_component)(storage, from, to, current);
+        }
+    }
+    /// Returns `true` if the component was registered with [`ComponentRegistrationBuilder::with_map_entities`]
+    /// or [`ComponentRegistry::register_serde_map_entities`].
+    #[inline]
+    pub fn is_map_entities(&self) -> bool {
+        self.map_entities.is_some()
+    }
+    /// Rewrites the [`EntityId`]s stored in `storage`'s components, if the component can be mapped.
+    #[inline]
+    pub(crate) fn map_entities(&self, storage: &mut dyn Storage, entity_map: &EntityMap) {
+        if let Some(map_entities) = self.map_entities {
+            (map_entities)(storage, entity_map);
+        }
+    }
+    /// Returns `true` if the component was registered with [`ComponentRegistrationBuilder::with_debug`].
+    #[inline]
+    pub fn is_debug(&self) -> bool {
+        self.debug.is_some()
+    }
+    /// Returns the function giving access to a component as `dyn Debug`, if the component can be printed.
+    #[inline]
+    pub(crate) fn debug_fn(&self) -> Option<DebugFn> {
+        self.debug
+    }
+    /// Returns `true` if the component was registered with [`ComponentRegistrationBuilder::with_hash`].
+    #[inline]
+    pub fn is_hash(&self) -> bool {
+        self.hash.is_some()
+    }
+    /// Hashes the components in `storage`, if the component can be hashed.\
+    /// A missing storage hashes like an empty one.
+    #[inline]
+    pub(crate) fn checksum(&self, storage: Option<&dyn Storage>) -> Option<u64> {
+        self.hash.map(|hash| (hash)(storage))
+    }
+    /// Returns `true` if the component was registered with [`ComponentRegistry::register_serde`].
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    #[inline]
+    pub fn is_serde(&self) -> bool {
+        self.serde.is_some()
+    }
+    /// Serializes `entity`'s component.
+    ///
+    /// ### Errors
+    ///
+    /// - The component wasn't registered with [`ComponentRegistry::register_serde`].
+    /// - Storage borrow failed.
+    /// - `entity` doesn't have the component.
+    /// - Serialization failed.
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn get_value(
+        &self,
+        all_storages: &AllStorages,
+        entity: EntityId,
+    ) -> Result<serde_json::Value, error::Reflect> {
+        let serde = self
+            .serde
+            .ok_or_else(|| error::Reflect::NotSerde(self.type_name))?;
+
+        (serde.get_value)(all_storages, entity)
+    }
+    /// Deserializes a component and adds it to `entity`, replacing the previous one if present.
+    ///
+    /// ### Errors
+    ///
+    /// - The component wasn't registered with [`ComponentRegistry::register_serde`].
+    /// - `entity` isn't alive.
+    /// - Deserialization failed.
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn insert_value(
+        &self,
+        all_storages: &mut AllStorages,
+        entity: EntityId,
+        value: serde_json::Value,
+    ) -> Result<(), error::Reflect> {
+        let serde = self
+            .serde
+            .ok_or_else(|| error::Reflect::NotSerde(self.type_name))?;
+
+        (serde.insert_value)(all_storages, entity, value)
+    }
+    /// Rewrites the [`EntityId`]s stored inside a serialized component using `entity_map`.\
+    /// Returns `value` unchanged if the component wasn't registered with [`ComponentRegistry::register_serde_map_entities`].
+    ///
+    /// ### Errors
+    ///
+    /// - The component wasn't registered with [`ComponentRegistry::register_serde`].
+    /// - (De)serialization failed.
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn map_value(
+        &self,
+        value: serde_json::Value,
+        entity_map: &EntityMap,
+    ) -> Result<serde_json::Value, error::Reflect> {
+        let serde = self
+            .serde
+            .ok_or_else(|| error::Reflect::NotSerde(self.type_name))?;
+
+        match serde.map_value {
+            Some(map_value) => (map_value)(value, entity_map),
+            None => Ok(value),
+        }
+    }
+    /// Deserializes a component without adding it to any entity.
+    #[cfg(feature = "serde1")]
+    pub(crate) fn deserialize(
+        &self,
+        value: serde_json::Value,
+    ) -> Result<Box<dyn Any>, error::Reflect> {
+        let serde = self
+            .serde
+            .ok_or_else(|| error::Reflect::NotSerde(self.type_name))?;
+
+        (serde.deserialize)(value)
+    }
+    /// Adds a component returned by [`ComponentRegistration::deserialize`] to `entity`.\
+    /// Its [`EntityId`]s are rewritten using `entity_map` if it was registered with [`ComponentRegistry::register_serde_map_entities`].
+    ///
+    /// `entity` has to be alive.
+    #[cfg(feature = "serde1")]
+    pub(crate) fn insert_deserialized(
+        &self,
+        all_storages: &mut AllStorages,
+        entity: EntityId,
+        component: Box<dyn Any>,
+        entity_map: &EntityMap,
+    ) {
+        (self.serde.unwrap().insert_deserialized)(all_storages, entity, component, entity_map)
+    }
+}
+
+impl Debug for ComponentRegistration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComponentRegistration")
+            .field("name", &self.name)
+            .field("type_name", &self.type_name)
+            .field("storage_id", &self.storage_id)
+            .finish()
+    }
+}
+
+fn get<T: Send + Sync + Component>(
+    all_storages: &AllStorages,
+    entity: EntityId,
+) -> Result<Ref<'_, &'_ dyn Any>, error::GetComponent> {
+    Ok(Ref::map(
+        all_storages.get::<&T>(entity)?,
+        |component| -> &dyn Any { component },
+    ))
+}
+
+fn remove<T: Send + Sync + Component>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+) -> Option<Box<dyn Any>> {
+    let (component,) = all_storages.remove::<(T,)>(entity);
+
+    component.map(|component| -> Box<dyn Any> { Box::new(component) })
+}
+
+fn list<T: Send + Sync + Component>(
+    all_storages: &AllStorages,
+) -> Result<Vec<EntityId>, error::GetStorage> {
+    let sparse_set = all_storages.custom_storage_or_insert(SparseSet::<T>::new)?;
+
+    Ok(sparse_set.dense.clone())
+}
+
+fn clone_component<T: Send + Sync + Component + Clone>(
+    storage: &mut dyn Storage,
+    from: EntityId,
+    to: EntityId,
+    current: u32,
+) {
+    let sparse_set = storage.any_mut().downcast_mut::<SparseSet<T>>().unwrap();
+
+    if let Some(component) = sparse_set.private_get(from).cloned() {
+        sparse_set.insert(to, component, current);
+    }
+}
+
+fn map_entities<T: Send + Sync + MapEntities>(storage: &mut dyn Storage, entity_map: &EntityMap) {
+    storage
+        .any_mut()
+        .downcast_mut::<SparseSet<T>>()
+        .unwrap()
+        .map_entities(entity_map);
+}
+
+fn debug<T: Send + Sync + Component + Debug>(
+    storage: &dyn Storage,
+    entity: EntityId,
+) -> Option<&dyn Debug> {
+    storage
+        .any()
+        .downcast_ref::<SparseSet<T>>()
+        .unwrap()
+        .private_get(entity)
+        .map(|component| -> &dyn Debug { component })
+}
+
+fn hash<T: Send + Sync + Component + Hash>(storage: Option<&dyn Storage>) -> u64 {
+    match storage {
+        Some(storage) => storage
+            .any()
+            .downcast_ref::<SparseSet<T>>()
+            .unwrap()
+            .checksum(),
+        None => SparseSet::<T>::new().checksum(),
+    }
+}
+
+#[cfg(feature = "serde1")]
+fn get_value<T: Send + Sync + Component + serde::Serialize>(
+    all_storages: &AllStorages,
+    entity: EntityId,
+) -> Result<serde_json::Value, error::Reflect> {
+    let component = all_storages.get::<&T>(entity)?;
+
+    serde_json::to_value(&**component).map_err(error::Reflect::Serde)
+}
+
+#[cfg(feature = "serde1")]
+fn insert_value<T: Send + Sync + Component + serde::de::DeserializeOwned>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+    value: serde_json::Value,
+) -> Result<(), error::Reflect> {
+    if !all_storages
+        .exclusive_storage_mut::<Entities>()
+        .unwrap()
+        .is_alive(entity)
+    {
+        return Err(error::Reflect::EntityIsNotAlive);
+    }
+
+    let component: T = serde_json::from_value(value).map_err(error::Reflect::Serde)?;
+    all_storages.add_component(entity, (component,));
+
+    Ok(())
+}
+
+#[cfg(feature = "serde1")]
+fn map_value<T: serde::Serialize + serde::de::DeserializeOwned + MapEntities>(
+    value: serde_json::Value,
+    entity_map: &EntityMap,
+) -> Result<serde_json::Value, error::Reflect> {
+    let mut component: T = serde_json::from_value(value).map_err(error::Reflect::Serde)?;
+    component.map_entities(entity_map);
+
+    serde_json::to_value(&component).map_err(error::Reflect::Serde)
+}
+
+#[cfg(feature = "serde1")]
+fn deserialize<T: Component + serde::de::DeserializeOwned>(
+    value: serde_json::Value,
+) -> Result<Box<dyn Any>, error::Reflect> {
+    let component: T = serde_json::from_value(value).map_err(error::Reflect::Serde)?;
+
+    Ok(Box::new(component))
+}
+
+#[cfg(feature = "serde1")]
+fn insert_deserialized<T: Send + Sync + Component>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+    component: Box<dyn Any>,
+    _: &EntityMap,
+) {
+    all_storages.add_component(entity, (*component.downcast::<T>().unwrap(),));
+}
+
+#[cfg(feature = "serde1")]
+fn insert_mapped<T: Send + Sync + MapEntities>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+    component: Box<dyn Any>,
+    entity_map: &EntityMap,
+) {
+    let mut component = *component.downcast::<T>().unwrap();
+    component.map_entities(entity_map);
+
+    all_storages.add_component(entity, (component,));
+}
+
+/// Adds optional capabilities to a registration, returned by [`ComponentRegistry::register`] and its variants.
+pub struct ComponentRegistrationBuilder<'a, T> {
+    registration: &'a mut ComponentRegistration,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Send + Sync + Component> ComponentRegistrationBuilder<'_, T> {
+    /// Makes [`World::clone_entity`] clone `T` components.
+    ///
+    /// [`World::clone_entity`]: crate::World::clone_entity()
+    pub fn with_clone(self) -> Self
+    where
+        T: Clone,
+    {
+        self.registration.clone_component = Some(clone_component::<T>);
+
+        self
+    }
+    /// Makes [`World::merge_from`] rewrite the [`EntityId`]s stored in `T` components.
+    ///
+    /// [`World::merge_from`]: crate::World::merge_from()
+    pub fn with_map_entities(self) -> Self
+    where
+        T: MapEntities,
+    {
+        self.registration.map_entities = Some(map_entities::<T>);
+
+        self
+    }
+    /// Makes [`World::debug_entity`] print `T` components.
+    ///
+    /// [`World::debug_entity`]: crate::World::debug_entity()
+    pub fn with_debug(self) -> Self
+    where
+        T: Debug,
+    {
+        self.registration.debug = Some(debug::<T>);
+
+        self
+    }
+    /// Makes [`World::checksum`] hash `T` components.
+    ///
+    /// [`World::checksum`]: crate::World::checksum()
+    pub fn with_hash(self) -> Self
+    where
+        T: Hash,
+    {
+        self.registration.hash = Some(hash::<T>);
+
+        self
+    }
+}
+
+/// Name to [`ComponentRegistration`] mapping, gives access to components without knowing their type.
+///
+/// Only `Send + Sync` components can be registered.
+///
+/// ### Example
+/// ```
+/// use shipyard::{AllStoragesViewMut, Component, ComponentRegistry, World};
+///
+/// #[derive(Component, Debug, PartialEq)]
+/// struct Health(u32);
+///
+/// let mut registry = ComponentRegistry::new();
+/// registry.register::<Health>("Health");
+///
+/// let world = World::new();
+/// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+/// let entity = all_storages.add_entity((Health(10),));
+///
+/// let health = registry.get("Health").unwrap();
+///
+/// assert_eq!(health.list(&all_storages).unwrap(), vec![entity]);
+/// assert_eq!(
+///     health.get(&all_storages, entity).unwrap().downcast_ref::<Health>(),
+///     Some(&Health(10))
+/// );
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct ComponentRegistry {
+    registrations: Vec<ComponentRegistration>,
+    by_name: HashMap<Cow<'static, str>, usize>,
+    by_storage_id: HashMap<StorageId, usize>,
+}
+
+impl ComponentRegistry {
+    /// Creates an empty `ComponentRegistry`.
+    #[inline]
+    pub fn new() -> ComponentRegistry {
+        ComponentRegistry::default()
+    }
+    /// Registers `T` under `name`.\
+    /// Registering a name a second time replaces the previous registration.
+    pub fn register<T: Send + Sync + Component>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> ComponentRegistrationBuilder<'_, T> {
+        self.insert(ComponentRegistration::new::<T>(name.into()))
+    }
+    /// Registers `T` under `name` with (de)serialization support.\
+    /// Registering a name a second time replaces the previous registration.
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn register_serde<
+        T: Send + Sync + Component + serde::Serialize + serde::de::DeserializeOwned,
+    >(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> ComponentRegistrationBuilder<'_, T> {
+        let mut registration = ComponentRegistration::new::<T>(name.into());
+        registration.serde = Some(SerdeFns {
+            get_value: get_value::<T>,
+            insert_value: insert_value::<T>,
+            map_value: None,
+            deserialize: deserialize::<T>,
+            insert_deserialized: insert_deserialized::<T>,
+        });
+
+        self.insert(registration)
+    }
+    /// Registers `T` under `name` with (de)serialization support.\
+    /// The [`EntityId`]s stored inside serialized components can be rewritten with [`ComponentRegistration::map_value`],
+    /// scenes use it to resolve references between entities.
+    /// Components are also rewritten by [`World::merge_from`], like with [`ComponentRegistrationBuilder::with_map_entities`].\
+    /// Registering a name a second time replaces the previous registration.
+    ///
+    /// [`World::merge_from`]: crate::World::merge_from()
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn register_serde_map_entities<
+        T: Send + Sync + MapEntities + serde::Serialize + serde::de::DeserializeOwned,
+    >(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> ComponentRegistrationBuilder<'_, T> {
+        let mut registration = ComponentRegistration::new::<T>(name.into());
+        registration.serde = Some(SerdeFns {
+            get_value: get_value::<T>,
+            insert_value: insert_value::<T>,
+            map_value: Some(map_value::<T>),
+            deserialize: deserialize::<T>,
+            insert_deserialized: insert_mapped::<T>,
+        });
+        registration.map_entities = Some(map_entities::<T>);
+
+        self.insert(registration)
+    }
+    fn insert<T>(
+        &mut self,
+        registration: ComponentRegistration,
+    ) -> ComponentRegistrationBuilder<'_, T> {
+        let index = if let Some(&index) = self.by_name.get(&registration.name) {
+            let old = core::mem::replace(&mut self.registrations[index], registration);
+            if self.by_storage_id.get(&old.storage_id) == Some(&index) {
+                self.by_storage_id.remove(&old.storage_id);
+            }
+            self.by_storage_id
+                .insert(self.registrations[index].storage_id, index);
+
+            index
+        } else {
+            let index = self.registrations.len();
+            self.by_name.insert(registration.name.clone(), index);
+            self.by_storage_id.insert(registration.storage_id, index);
+            self.registrations.push(registration);
+
+            index
+        };
+
+        ComponentRegistrationBuilder {
+            registration: &mut self.registrations[index],
+            _phantom: PhantomData,
+        }
+    }
+    /// Returns the registration with the given `name`.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&ComponentRegistration> {
+        self.by_name
+            .get(name)
+            .map(|&index| &self.registrations[index])
+    }
+    /// Returns the registration of the component stored in `storage_id`.
+    #[inline]
+    pub fn get_by_storage_id(&self, storage_id: StorageId) -> Option<&ComponentRegistration> {
+        self.by_storage_id
+            .get(&storage_id)
+            .map(|&index| &self.registrations[index])
+    }
+    /// Returns an iterator over all registrations, in registration order.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, ComponentRegistration> {
+        self.registrations.iter()
+    }
+    /// Returns the number of registered components.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.registrations.len()
+    }
+    /// Returns `true` if no component is registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.registrations.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a ComponentRegistry {
+    type Item = &'a ComponentRegistration;
+    type IntoIter = core::slice::Iter<'a, ComponentRegistration>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
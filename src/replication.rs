@@ -0,0 +1,575 @@
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::map_entities::{EntityMap, MapEntities};
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::tracking::{is_track_within_bounds, TrackingTimestamp};
+use crate::world::World;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::fmt::Display;
+use core::marker::PhantomData;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+type ChangesFn = fn(&mut AllStorages, u32, u32) -> Result<StorageChanges, error::Replication>;
+type InsertedBeforeFn = fn(&mut AllStorages, EntityId, u32, u32) -> bool;
+type InsertFn = fn(&mut AllStorages, EntityId, &[u8], &EntityMap) -> Result<(), error::Replication>;
+type RemoveFn = fn(&mut AllStorages, EntityId);
+
+/// Serializer used by [`Replication`] to turn components into the bytes stored in a [`ChangeSet`].
+///
+/// ### Example
+/// ```
+/// use serde::{de::DeserializeOwned, Serialize};
+/// use shipyard::ReplicationFormat;
+///
+/// struct Bincode;
+///
+/// impl ReplicationFormat for Bincode {
+///     type Error = bincode::Error;
+///
+///     fn serialize<T: Serialize>(component: &T) -> Result<Vec<u8>, bincode::Error> {
+///         bincode::serialize(component)
+///     }
+///     fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, bincode::Error> {
+///         bincode::deserialize(bytes)
+///     }
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub trait ReplicationFormat: 'static {
+    /// Error returned when a component fails to (de)serialize.
+    type Error: Display + Send + Sync + 'static;
+
+    /// Serializes `component`.
+    fn serialize<T: Serialize>(component: &T) -> Result<Vec<u8>, Self::Error>;
+    /// Deserializes a component serialized with [`ReplicationFormat::serialize`].
+    fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Self::Error>;
+}
+
+/// Default [`ReplicationFormat`], serializes components to JSON.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub struct Json;
+
+impl ReplicationFormat for Json {
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn serialize<T: Serialize>(component: &T) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(component)
+    }
+    #[inline]
+    fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+}
+
+/// List of the components replicated by [`World::change_set`] and [`World::apply_change_set`].\
+/// Components are stored in the [`ChangeSet`] as bytes serialized with `F`, see [`ReplicationFormat`].
+///
+/// Replicated storages have to track all changes in the source `World`, see [`World::track_all`].
+///
+/// ### Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use shipyard::{ChangeSet, Component, EntityMap, Replication, View, World};
+///
+/// #[derive(Component, Serialize, Deserialize, Debug, PartialEq)]
+/// struct Position(i32, i32);
+///
+/// let mut replication = Replication::new();
+/// replication.register::<Position>("Position");
+///
+/// let mut server = World::new();
+/// server.track_all::<Position>();
+/// let mut client = World::new();
+/// let mut entity_map = EntityMap::new();
+///
+/// let since = server.get_tracking_timestamp();
+/// let entity = server.add_entity((Position(0, 0),));
+///
+/// let change_set = server.change_set(&replication, since).unwrap();
+/// let change_set: ChangeSet = serde_json::from_str(&serde_json::to_string(&change_set).unwrap()).unwrap();
+/// client.apply_change_set(&change_set, &replication, &mut entity_map).unwrap();
+///
+/// let client_entity = entity_map.map(entity);
+/// client.run(|positions: View<Position>| {
+///     assert_eq!(positions[client_entity], Position(0, 0));
+/// });
+/// ```
+///
+/// [`World::change_set`]: crate::World::change_set()
+/// [`World::apply_change_set`]: crate::World::apply_change_set()
+/// [`World::track_all`]: crate::World::track_all()
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub struct Replication<F: ReplicationFormat = Json> {
+    components: Vec<ReplicatedComponent>,
+    format: PhantomData<F>,
+}
+
+#[derive(Clone)]
+struct ReplicatedComponent {
+    name: Cow<'static, str>,
+    changes: ChangesFn,
+    inserted_before: InsertedBeforeFn,
+    insert: InsertFn,
+    remove: RemoveFn,
+}
+
+impl<F: ReplicationFormat> Default for Replication<F> {
+    #[inline]
+    fn default() -> Self {
+        Replication {
+            components: Vec::new(),
+            format: PhantomData,
+        }
+    }
+}
+
+impl<F: ReplicationFormat> Clone for Replication<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Replication {
+            components: self.components.clone(),
+            format: PhantomData,
+        }
+    }
+}
+
+impl Replication {
+    /// Creates an empty `Replication` serializing components to JSON.
+    #[inline]
+    pub fn new() -> Replication {
+        Replication::default()
+    }
+}
+
+impl<F: ReplicationFormat> Replication<F> {
+    /// Creates an empty `Replication` serializing components with `F`.
+    #[inline]
+    pub fn with_format() -> Replication<F> {
+        Replication::default()
+    }
+    /// Replicates `T` under `name`.\
+    /// Registering a name a second time replaces the previous registration.
+    pub fn register<T: Send + Sync + Component + Serialize + DeserializeOwned>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) {
+        self.insert::<T>(name.into(), insert::<T, F>);
+    }
+    /// Replicates `T` under `name`.\
+    /// The [`EntityId`]s stored inside the components are rewritten to the receiving `World`'s ids.\
+    /// Registering a name a second time replaces the previous registration.
+    pub fn register_map_entities<T: Send + Sync + MapEntities + Serialize + DeserializeOwned>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) {
+        self.insert::<T>(name.into(), insert_map_entities::<T, F>);
+    }
+    fn insert<T: Send + Sync + Component + Serialize>(
+        &mut self,
+        name: Cow<'static, str>,
+        insert: InsertFn,
+    ) {
+        let component = ReplicatedComponent {
+            name,
+            changes: changes::<T, F>,
+            inserted_before: inserted_before::<T>,
+            insert,
+            remove: remove::<T>,
+        };
+
+        match self
+            .components
+            .iter_mut()
+            .find(|other| other.name == component.name)
+        {
+            Some(other) => *other = component,
+            None => self.components.push(component),
+        }
+    }
+    /// Returns the number of replicated components.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+    /// Returns `true` if no component is replicated.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+impl<F: ReplicationFormat> core::fmt::Debug for Replication<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.components.iter().map(|component| &component.name))
+            .finish()
+    }
+}
+
+/// Changes made to the replicated components of a `World` since a [`TrackingTimestamp`], created with [`World::change_set`].
+///
+/// All [`EntityId`]s are the ones of the `World` the changes were made in.
+///
+/// [`World::change_set`]: crate::World::change_set()
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChangeSet {
+    /// Entities that received their first replicated component.
+    pub new_entities: Vec<EntityId>,
+    /// Deleted entities.
+    pub deleted_entities: Vec<EntityId>,
+    /// Changes of each component, keyed by their registered name.
+    pub components: BTreeMap<String, ComponentChanges>,
+}
+
+/// Changes made to a single component, part of a [`ChangeSet`].
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ComponentChanges {
+    /// Inserted and modified components, serialized with the [`ReplicationFormat`] of the [`Replication`].
+    pub updated: Vec<(EntityId, Vec<u8>)>,
+    /// Entities that lost their component without being deleted.
+    pub removed: Vec<EntityId>,
+}
+
+impl ChangeSet {
+    /// Creates an empty `ChangeSet`.
+    #[inline]
+    pub fn new() -> ChangeSet {
+        ChangeSet::default()
+    }
+    /// Returns `true` if there is no change.
+    pub fn is_empty(&self) -> bool {
+        self.new_entities.is_empty()
+            && self.deleted_entities.is_empty()
+            && self.components.is_empty()
+    }
+}
+
+#[derive(Default)]
+struct StorageChanges {
+    inserted: Vec<EntityId>,
+    updated: Vec<(EntityId, Vec<u8>)>,
+    removed: Vec<EntityId>,
+}
+
+impl AllStorages {
+    /// Collects the changes made to the components replicated by `replication` since `since`.
+    ///
+    /// ### Errors
+    ///
+    /// - A replicated storage doesn't track all changes.
+    /// - A component failed to serialize.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn change_set<F: ReplicationFormat>(
+        &mut self,
+        replication: &Replication<F>,
+        since: TrackingTimestamp,
+    ) -> Result<ChangeSet, error::Replication> {
+        // changes made at `since` are included
+        let last = since.0.wrapping_sub(1);
+        let current = self.get_current_mut();
+
+        let mut storage_changes = Vec::with_capacity(replication.components.len());
+        for component in &replication.components {
+            storage_changes.push((component.changes)(self, last, current)?);
+        }
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let mut change_set = ChangeSet::new();
+        for (component, changes) in replication.components.iter().zip(&mut storage_changes) {
+            let (removed, deleted): (Vec<_>, Vec<_>) = changes
+                .removed
+                .drain(..)
+                .partition(|&entity| entities.is_alive(entity));
+
+            change_set.deleted_entities.extend(deleted);
+
+            if !changes.updated.is_empty() || !removed.is_empty() {
+                change_set.components.insert(
+                    component.name.to_string(),
+                    ComponentChanges {
+                        updated: core::mem::take(&mut changes.updated),
+                        removed,
+                    },
+                );
+            }
+        }
+
+        change_set.deleted_entities.sort_unstable();
+        change_set.deleted_entities.dedup();
+
+        let mut new_entities = storage_changes
+            .into_iter()
+            .flat_map(|changes| changes.inserted)
+            .collect::<Vec<_>>();
+        new_entities.sort_unstable();
+        new_entities.dedup();
+        new_entities.retain(|&entity| {
+            replication
+                .components
+                .iter()
+                .all(|component| !(component.inserted_before)(self, entity, last, current))
+        });
+        change_set.new_entities = new_entities;
+
+        Ok(change_set)
+    }
+    /// Applies changes made in another `World`.\
+    /// `entity_map` maps the other `World`'s entities to this one's, it's updated when entities are created or deleted.
+    /// Entities of the other `World` that aren't in `entity_map` are created.
+    ///
+    /// Changes are applied until an error occurs.
+    ///
+    /// ### Errors
+    ///
+    /// - A component isn't replicated by `replication`.
+    /// - A component failed to deserialize.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn apply_change_set<F: ReplicationFormat>(
+        &mut self,
+        change_set: &ChangeSet,
+        replication: &Replication<F>,
+        entity_map: &mut EntityMap,
+    ) -> Result<(), error::Replication> {
+        let mut components = Vec::with_capacity(change_set.components.len());
+        for name in change_set.components.keys() {
+            match replication
+                .components
+                .iter()
+                .find(|component| component.name == name.as_str())
+            {
+                Some(component) => components.push(component),
+                None => return Err(error::Replication::UnknownComponent(name.clone())),
+            }
+        }
+
+        for &entity in &change_set.deleted_entities {
+            if let Some(local_entity) = entity_map.remove(entity) {
+                self.delete_entity(local_entity);
+            }
+        }
+
+        // all entities are created before mapping the components referencing them
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let updated_entities = change_set
+            .components
+            .values()
+            .flat_map(|changes| changes.updated.iter().map(|(entity, _)| entity));
+        for &entity in change_set.new_entities.iter().chain(updated_entities) {
+            if entity_map.get(entity).is_none() {
+                entity_map.insert(entity, entities.generate());
+            }
+        }
+
+        for (component, changes) in components.into_iter().zip(change_set.components.values()) {
+            for &entity in &changes.removed {
+                if let Some(local_entity) = entity_map.get(entity) {
+                    (component.remove)(self, local_entity);
+                }
+            }
+
+            for (entity, bytes) in &changes.updated {
+                (component.insert)(self, entity_map.map(*entity), bytes, entity_map)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl World {
+    /// Collects the changes made to the components replicated by `replication` since `since`.\
+    /// Use [`World::get_tracking_timestamp`] before calling this method to get the next `since`.
+    ///
+    /// ### Errors
+    ///
+    /// - A replicated storage doesn't track all changes.
+    /// - A component failed to serialize.
+    ///
+    /// ### Example
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use shipyard::{Component, Replication, World};
+    ///
+    /// #[derive(Component, Serialize, Deserialize)]
+    /// struct Health(u32);
+    ///
+    /// let mut replication = Replication::new();
+    /// replication.register::<Health>("Health");
+    ///
+    /// let mut world = World::new();
+    /// world.track_all::<Health>();
+    /// let entity = world.add_entity((Health(10),));
+    ///
+    /// let since = world.get_tracking_timestamp();
+    /// let change_set = world.change_set(&replication, since).unwrap();
+    /// assert!(change_set.is_empty());
+    ///
+    /// world.delete_entity(entity);
+    ///
+    /// let change_set = world.change_set(&replication, since).unwrap();
+    /// assert_eq!(change_set.deleted_entities, vec![entity]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    #[inline]
+    pub fn change_set<F: ReplicationFormat>(
+        &mut self,
+        replication: &Replication<F>,
+        since: TrackingTimestamp,
+    ) -> Result<ChangeSet, error::Replication> {
+        self.all_storages.get_mut().change_set(replication, since)
+    }
+    /// Applies changes made in another `World`.\
+    /// `entity_map` maps the other `World`'s entities to this one's, it's updated when entities are created or deleted.
+    /// Entities of the other `World` that aren't in `entity_map` are created.
+    ///
+    /// Changes are applied until an error occurs.
+    ///
+    /// ### Errors
+    ///
+    /// - A component isn't replicated by `replication`.
+    /// - A component failed to deserialize.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    #[inline]
+    pub fn apply_change_set<F: ReplicationFormat>(
+        &mut self,
+        change_set: &ChangeSet,
+        replication: &Replication<F>,
+        entity_map: &mut EntityMap,
+    ) -> Result<(), error::Replication> {
+        self.all_storages
+            .get_mut()
+            .apply_change_set(change_set, replication, entity_map)
+    }
+}
+
+fn changes<T: Send + Sync + Component + Serialize, F: ReplicationFormat>(
+    all_storages: &mut AllStorages,
+    last: u32,
+    current: u32,
+) -> Result<StorageChanges, error::Replication> {
+    let sparse_set = all_storages
+        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+    if !(sparse_set.is_tracking_insertion
+        && sparse_set.is_tracking_modification
+        && sparse_set.is_tracking_removal
+        && sparse_set.is_tracking_deletion)
+    {
+        return Err(error::Replication::Untracked(type_name::<SparseSet<T>>()));
+    }
+
+    let mut changes = StorageChanges::default();
+
+    for (((&entity, component), &insertion), &modification) in sparse_set
+        .dense
+        .iter()
+        .zip(&sparse_set.data)
+        .zip(&sparse_set.insertion_data)
+        .zip(&sparse_set.modification_data)
+    {
+        let is_inserted = is_track_within_bounds(insertion, last, current);
+
+        if is_inserted || is_track_within_bounds(modification, last, current) {
+            let bytes =
+                F::serialize(component).map_err(|err| error::Replication::Format(Box::new(err)))?;
+            changes.updated.push((entity, bytes));
+
+            if is_inserted {
+                changes.inserted.push(entity);
+            }
+        }
+    }
+
+    changes.removed.extend(
+        sparse_set
+            .deletion_data
+            .iter()
+            .filter(|(_, timestamp, _)| is_track_within_bounds(*timestamp, last, current))
+            .map(|(entity, _, _)| *entity),
+    );
+    changes.removed.extend(
+        sparse_set
+            .removal_data
+            .iter()
+            .filter(|(_, timestamp)| is_track_within_bounds(*timestamp, last, current))
+            .map(|(entity, _)| *entity),
+    );
+
+    Ok(changes)
+}
+
+/// Returns `true` if `entity` has a `T` component inserted before `last`.
+fn inserted_before<T: Send + Sync + Component>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+    last: u32,
+    current: u32,
+) -> bool {
+    let sparse_set = all_storages
+        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+    match sparse_set.index_of(entity) {
+        Some(index) => !is_track_within_bounds(sparse_set.insertion_data[index], last, current),
+        None => false,
+    }
+}
+
+fn insert<T: Send + Sync + Component + DeserializeOwned, F: ReplicationFormat>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+    bytes: &[u8],
+    _: &EntityMap,
+) -> Result<(), error::Replication> {
+    let component: T =
+        F::deserialize(bytes).map_err(|err| error::Replication::Format(Box::new(err)))?;
+
+    add_component(all_storages, entity, component)
+}
+
+fn insert_map_entities<T: Send + Sync + MapEntities + DeserializeOwned, F: ReplicationFormat>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+    bytes: &[u8],
+    entity_map: &EntityMap,
+) -> Result<(), error::Replication> {
+    let mut component: T =
+        F::deserialize(bytes).map_err(|err| error::Replication::Format(Box::new(err)))?;
+    component.map_entities(entity_map);
+
+    add_component(all_storages, entity, component)
+}
+
+fn add_component<T: Send + Sync + Component>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+    component: T,
+) -> Result<(), error::Replication> {
+    if !all_storages
+        .exclusive_storage_mut::<Entities>()
+        .unwrap()
+        .is_alive(entity)
+    {
+        return Err(error::Reflect::EntityIsNotAlive.into());
+    }
+
+    all_storages.add_component(entity, (component,));
+
+    Ok(())
+}
+
+fn remove<T: Send + Sync + Component>(all_storages: &mut AllStorages, entity: EntityId) {
+    all_storages.remove::<(T,)>(entity);
+}
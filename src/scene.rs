@@ -0,0 +1,278 @@
+use crate::all_storages::AllStorages;
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::map_entities::EntityMap;
+use crate::reflect::ComponentRegistry;
+use crate::world::World;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::HashSet;
+use serde::{Deserialize, Serialize};
+
+/// List of entities with named, serialized components.
+///
+/// Component names are the ones given to a [`ComponentRegistry`].\
+/// Entity ids are local to the scene, references between entities are resolved when the scene is spawned
+/// for components registered with [`ComponentRegistry::register_serde_map_entities`].
+///
+/// ### Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use shipyard::{Component, ComponentRegistry, EntityId, EntityMap, MapEntities, Scene, World};
+///
+/// #[derive(Component, Serialize, Deserialize)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Serialize, Deserialize, Debug, PartialEq)]
+/// struct Parent(EntityId);
+///
+/// impl MapEntities for Parent {
+///     fn map_entities(&mut self, entity_map: &EntityMap) {
+///         self.0 = entity_map.map(self.0);
+///     }
+/// }
+///
+/// let mut registry = ComponentRegistry::new();
+/// registry.register_serde::<Health>("Health");
+/// registry.register_serde_map_entities::<Parent>("Parent");
+///
+/// let scene: Scene = serde_json::from_str(
+///     r#"{
+///         "entities": [
+///             { "id": { "index": 0, "gen": 0 }, "components": { "Health": 10 } },
+///             { "id": { "index": 1, "gen": 0 }, "components": { "Parent": { "index": 0, "gen": 0 } } }
+///         ]
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// let mut world = World::new();
+/// let entity_map = world.spawn_scene(&scene, &registry).unwrap();
+///
+/// let parent = entity_map.map(EntityId::new_from_index_and_gen(0, 0));
+/// let child = entity_map.map(EntityId::new_from_index_and_gen(1, 0));
+/// assert_eq!(world.get::<&Parent>(child).as_deref(), Ok(&&Parent(parent)));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Scene {
+    /// The scene's entities.
+    pub entities: Vec<SceneEntity>,
+}
+
+/// Entity of a [`Scene`].
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SceneEntity {
+    /// Scene local id of the entity.
+    pub id: EntityId,
+    /// Serialized components, keyed by their registered name.
+    pub components: BTreeMap<String, serde_json::Value>,
+}
+
+impl Scene {
+    /// Creates an empty `Scene`.
+    #[inline]
+    pub fn new() -> Scene {
+        Scene::default()
+    }
+    /// Returns the number of entities in the scene.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+    /// Returns `true` if the scene has no entity.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+impl AllStorages {
+    /// Creates all entities of `scene` and returns the scene local to new id mapping.\
+    /// References to entities that aren't part of the scene become [`EntityId::dead`].
+    ///
+    /// Nothing is added if an error occurs.
+    ///
+    /// ### Errors
+    ///
+    /// - Two scene entities have the same id.
+    /// - A component isn't registered in `registry` or was registered without (de)serialization support.
+    /// - A component failed to deserialize.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn spawn_scene(
+        &mut self,
+        scene: &Scene,
+        registry: &ComponentRegistry,
+    ) -> Result<EntityMap, error::Scene> {
+        let mut ids = HashSet::with_capacity(scene.len());
+        let mut components = Vec::new();
+        for scene_entity in &scene.entities {
+            if !ids.insert(scene_entity.id) {
+                return Err(error::Scene::DuplicateEntity(scene_entity.id));
+            }
+
+            for (name, value) in &scene_entity.components {
+                let registration = registry
+                    .get(name)
+                    .ok_or_else(|| error::Scene::UnknownComponent(name.clone()))?;
+
+                components.push((
+                    scene_entity.id,
+                    registration,
+                    registration.deserialize(value.clone())?,
+                ));
+            }
+        }
+
+        // all components deserialized, nothing can fail from here
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let mut entity_map = EntityMap::new();
+        for scene_entity in &scene.entities {
+            entity_map.insert(scene_entity.id, entities.generate());
+        }
+
+        for (id, registration, component) in components {
+            registration.insert_deserialized(self, entity_map.map(id), component, &entity_map);
+        }
+
+        Ok(entity_map)
+    }
+    /// Serializes `entities`' components registered with (de)serialization support in `registry`.\
+    /// Entity ids are rewritten to be local to the scene, the n-th entity gets index n.\
+    /// References to entities that aren't part of `entities` become [`EntityId::dead`].
+    ///
+    /// Dead entities are skipped.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    /// - A component failed to serialize.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn export_scene(
+        &self,
+        entities: &[EntityId],
+        registry: &ComponentRegistry,
+    ) -> Result<Scene, error::Scene> {
+        let all_entities = self
+            .entities()
+            .map_err(|err| error::Reflect::GetComponent(err.into()))?;
+
+        let mut entity_map = EntityMap::new();
+        for &entity in entities {
+            if all_entities.is_alive(entity) && entity_map.get(entity).is_none() {
+                entity_map.insert(
+                    entity,
+                    EntityId::new_from_index_and_gen(entity_map.len() as u64, 0),
+                );
+            }
+        }
+        drop(all_entities);
+
+        let mut scene = Scene::new();
+        for &entity in entities {
+            let id = match entity_map.get(entity) {
+                Some(id) if id.uindex() == scene.len() => id,
+                _ => continue,
+            };
+
+            let mut components = BTreeMap::new();
+            for registration in registry
+                .iter()
+                .filter(|registration| registration.is_serde())
+            {
+                let value = match registration.get_value(self, entity) {
+                    Ok(value) => value,
+                    Err(error::Reflect::GetComponent(error::GetComponent::MissingComponent(_))) => {
+                        continue
+                    }
+                    Err(err) => return Err(err.into()),
+                };
+
+                components.insert(
+                    registration.name().to_string(),
+                    registration.map_value(value, &entity_map)?,
+                );
+            }
+
+            scene.entities.push(SceneEntity { id, components });
+        }
+
+        Ok(scene)
+    }
+}
+
+impl World {
+    /// Creates all entities of `scene` and returns the scene local to new id mapping.\
+    /// References to entities that aren't part of the scene become [`EntityId::dead`].
+    ///
+    /// Nothing is added if an error occurs.
+    ///
+    /// ### Errors
+    ///
+    /// - Two scene entities have the same id.
+    /// - A component isn't registered in `registry` or was registered without (de)serialization support.
+    /// - A component failed to deserialize.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    #[inline]
+    pub fn spawn_scene(
+        &mut self,
+        scene: &Scene,
+        registry: &ComponentRegistry,
+    ) -> Result<EntityMap, error::Scene> {
+        self.all_storages.get_mut().spawn_scene(scene, registry)
+    }
+    /// Serializes `entities`' components registered with (de)serialization support in `registry`.\
+    /// Entity ids are rewritten to be local to the scene, the n-th entity gets index n.\
+    /// References to entities that aren't part of `entities` become [`EntityId::dead`].
+    ///
+    /// Dead entities are skipped.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - [`Entities`] (shared)
+    /// - storages of the registered components (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    /// - A component failed to serialize.
+    ///
+    /// ### Example
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use shipyard::{Component, ComponentRegistry, World};
+    ///
+    /// #[derive(Component, Serialize, Deserialize)]
+    /// struct Health(u32);
+    ///
+    /// let mut registry = ComponentRegistry::new();
+    /// registry.register_serde::<Health>("Health");
+    ///
+    /// let mut world = World::new();
+    /// world.add_entity(());
+    /// let entity = world.add_entity((Health(10),));
+    ///
+    /// let scene = world.export_scene(&[entity], &registry).unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_string(&scene).unwrap(),
+    ///     r#"{"entities":[{"id":{"index":0,"gen":0},"components":{"Health":10}}]}"#
+    /// );
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn export_scene(
+        &self,
+        entities: &[EntityId],
+        registry: &ComponentRegistry,
+    ) -> Result<Scene, error::Scene> {
+        let all_storages = self.all_storages.borrow().map_err(|err| {
+            error::Reflect::GetComponent(error::GetStorage::AllStoragesBorrow(err).into())
+        })?;
+
+        all_storages.export_scene(entities, registry)
+    }
+}
@@ -0,0 +1,635 @@
+use crate::all_storages::AllStorages;
+use crate::error;
+use crate::scheduler::info::{BatchInfo, Conflict, SystemId, SystemInfo, TypeInfo, WorkloadInfo};
+use crate::scheduler::label::SystemLabel;
+use crate::scheduler::workload::{
+    check_conflict, insert_system, insert_system_in_scheduler, make_run_once,
+};
+use crate::scheduler::{AsLabel, Batches, IntoWorkloadSystem, Label, Scheduler, WorkloadSystem};
+use crate::storage::StorageId;
+use crate::type_id::TypeId;
+use crate::world::World;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+type SystemGenerator = Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>;
+type EnableTracking = fn(&AllStorages) -> Result<(), error::GetStorage>;
+
+impl World {
+    /// Removes `system` from the `workload` workload.\
+    /// `system` can be the system itself or its display name.
+    ///
+    /// The other systems stay in their batch, batches left empty are removed.\
+    /// Returns the new layout of the workload, conflicts are not part of it.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload did not contain `system`.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn physics() {}
+    /// fn debug_draw() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Update")
+    ///     .with_system(physics)
+    ///     .with_system(debug_draw)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let info = world.remove_system("Update", debug_draw).unwrap();
+    ///
+    /// assert_eq!(info.batch_info.len(), 1);
+    /// assert_eq!(info.batch_info[0].systems.1.len(), 1);
+    /// ```
+    pub fn remove_system<T, U>(
+        &self,
+        workload: impl AsLabel<T>,
+        system: impl AsLabel<U>,
+    ) -> Result<WorkloadInfo, error::EditWorkload> {
+        let mut scheduler = self
+            .scheduler
+            .borrow_mut()
+            .map_err(|_| error::EditWorkload::Borrow)?;
+        let Scheduler {
+            system_names,
+            system_generators,
+            workloads,
+            ..
+        } = &mut *scheduler;
+
+        let workload = workload.as_label();
+        let system = system.as_label();
+        let batches = workloads
+            .get_mut(&*workload)
+            .ok_or(error::EditWorkload::MissingWorkload)?;
+
+        let positions = system_positions(batches, system_names, system_generators, &*system);
+        if positions.is_empty() {
+            return Err(error::EditWorkload::MissingSystem(system));
+        }
+
+        let mut removed_systems = Vec::with_capacity(positions.len());
+        for position in positions.into_iter().rev() {
+            removed_systems.push(batches.sequential[position]);
+            remove_position(batches, position);
+        }
+
+        let workload_info = workload_info(&*workload, batches, system_names, system_generators);
+
+        free_unused_systems(&mut scheduler, removed_systems);
+
+        Ok(workload_info)
+    }
+    /// Replaces `system` in the `workload` workload by `new_system`.\
+    /// `system` can be the system itself or its display name.
+    ///
+    /// `new_system` takes the place of `system` in its batch and keeps its `run_if`,
+    /// `new_system`'s `run_if` has to be `true` as well for it to run.\
+    /// The other systems stay in their batch.\
+    /// Returns the new layout of the workload, conflicts are not part of it.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    /// - [`AllStorages`] (shared) to enable tracking
+    /// - `new_system`'s storage (exclusive) to enable tracking
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Workload did not contain `system`.
+    /// - `new_system` can't run in parallel with the other systems of the batch.
+    /// - `new_system` has before or after requirements.
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Panics
+    ///
+    /// - `new_system` is invalid.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position;
+    ///
+    /// fn render(_: View<Position>) {}
+    /// fn render_wireframe(_: View<Position>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Update")
+    ///     .with_system(render)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world
+    ///     .replace_system("Update", render, render_wireframe)
+    ///     .unwrap();
+    ///
+    /// world.run_default().unwrap();
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[track_caller]
+    pub fn replace_system<T, U, B, R, S: IntoWorkloadSystem<B, R>>(
+        &self,
+        workload: impl AsLabel<T>,
+        system: impl AsLabel<U>,
+        new_system: S,
+    ) -> Result<WorkloadInfo, error::EditWorkload> {
+        let mut new_system = new_system.into_workload_system().unwrap();
+        check_requirements(&new_system)?;
+
+        let mut scheduler = self
+            .scheduler
+            .borrow_mut()
+            .map_err(|_| error::EditWorkload::Borrow)?;
+        let Scheduler {
+            systems,
+            last_runs,
+            system_names,
+            system_borrows,
+            system_generators,
+            lookup_table,
+            free_systems,
+            workloads,
+            ..
+        } = &mut *scheduler;
+
+        let workload = workload.as_label();
+        let system = system.as_label();
+        let batches = workloads
+            .get_mut(&*workload)
+            .ok_or(error::EditWorkload::MissingWorkload)?;
+
+        let positions = system_positions(batches, system_names, system_generators, &*system);
+        if positions.is_empty() {
+            return Err(error::EditWorkload::MissingSystem(system));
+        }
+
+        let single = is_single_system(&new_system.borrow_constraints);
+
+        for &position in &positions {
+            if let Some(conflict) = batch_conflict(
+                batches,
+                position,
+                single,
+                &new_system.borrow_constraints,
+                system_names,
+                system_generators,
+            ) {
+                return Err(error::EditWorkload::Conflict(
+                    new_system.display_name,
+                    Box::new(conflict),
+                ));
+            }
+        }
+
+        enable_tracking(self, &new_system.tracking_to_enable)?;
+
+        if new_system.run_once {
+            make_run_once(&mut new_system, &mut batches.run_once.systems);
+        }
+
+        let mut collected_systems = Vec::with_capacity(1);
+        insert_system_in_scheduler(
+            new_system,
+            systems,
+            last_runs,
+            lookup_table,
+            free_systems,
+            &mut collected_systems,
+            system_generators,
+            system_names,
+            system_borrows,
+        );
+        let (system_index, new_system) = collected_systems.pop().unwrap();
+
+        #[allow(clippy::type_complexity)]
+        let new_run_if: Option<
+            Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static>,
+        > = new_system.run_if.map(Arc::from);
+
+        let mut replaced_systems = Vec::with_capacity(positions.len());
+        for position in positions {
+            replaced_systems.push(batches.sequential[position]);
+            batches.sequential[position] = system_index;
+
+            batches.sequential_run_if[position] = match (
+                batches.sequential_run_if[position].take(),
+                new_run_if.clone(),
+            ) {
+                (Some(run_if), Some(new_run_if)) => Some(Box::new(move |world| {
+                    Ok((run_if)(world)? && (new_run_if)(world)?)
+                })),
+                (run_if, None) => run_if,
+                (None, Some(new_run_if)) => Some(Box::new(move |world| (new_run_if)(world))),
+            };
+
+            match find_position(batches, position) {
+                (batch, None) if single => batches.parallel[batch].0 = Some(system_index),
+                (batch, Some(slot)) if !single => batches.parallel[batch].1[slot] = system_index,
+                (batch, None) => {
+                    batches.parallel[batch].0 = None;
+                    batches.parallel_run_if[batch].0 = None;
+                    batches.parallel[batch].1.push(system_index);
+                    batches.parallel_run_if[batch].1.push(position);
+                }
+                (batch, Some(slot)) => {
+                    batches.parallel[batch].1.remove(slot);
+                    batches.parallel_run_if[batch].1.remove(slot);
+                    batches.parallel[batch].0 = Some(system_index);
+                    batches.parallel_run_if[batch].0 = Some(position);
+                }
+            }
+        }
+
+        let workload_info = workload_info(&*workload, batches, system_names, system_generators);
+
+        free_unused_systems(&mut scheduler, replaced_systems);
+
+        Ok(workload_info)
+    }
+    /// Adds `system` at the end of the `workload` workload.\
+    /// It's placed in the earliest batch after the last system it conflicts with, the other systems stay in their batch.
+    ///
+    /// Returns the new layout of the workload, only the new system's conflict is part of it.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    /// - [`AllStorages`] (shared) to enable tracking
+    /// - `system`'s storage (exclusive) to enable tracking
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - `system` has before or after requirements.
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Panics
+    ///
+    /// - `system` is invalid.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position;
+    ///
+    /// fn movement(_: ViewMut<Position>) {}
+    /// fn mod_system(_: View<Position>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Update")
+    ///     .with_system(movement)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let info = world.insert_system("Update", mod_system).unwrap();
+    ///
+    /// assert_eq!(info.batch_info.len(), 2);
+    /// assert!(info.batch_info[1].systems.1[0].conflict.is_some());
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[track_caller]
+    pub fn insert_system<T, B, R, S: IntoWorkloadSystem<B, R>>(
+        &self,
+        workload: impl AsLabel<T>,
+        system: S,
+    ) -> Result<WorkloadInfo, error::EditWorkload> {
+        let mut system = system.into_workload_system().unwrap();
+        check_requirements(&system)?;
+
+        let mut scheduler = self
+            .scheduler
+            .borrow_mut()
+            .map_err(|_| error::EditWorkload::Borrow)?;
+        let Scheduler {
+            systems,
+            last_runs,
+            system_names,
+            system_borrows,
+            system_generators,
+            lookup_table,
+            free_systems,
+            workloads,
+            ..
+        } = &mut *scheduler;
+
+        let workload = workload.as_label();
+        let batches = workloads
+            .get_mut(&*workload)
+            .ok_or(error::EditWorkload::MissingWorkload)?;
+
+        enable_tracking(self, &system.tracking_to_enable)?;
+
+        if system.run_once {
+            make_run_once(&mut system, &mut batches.run_once.systems);
+        }
+
+        let mut workload_info = workload_info(&*workload, batches, system_names, system_generators);
+
+        let mut collected_systems = Vec::with_capacity(1);
+        insert_system_in_scheduler(
+            system,
+            systems,
+            last_runs,
+            lookup_table,
+            free_systems,
+            &mut collected_systems,
+            system_generators,
+            system_names,
+            system_borrows,
+        );
+        let (system_index, system) = collected_systems.pop().unwrap();
+
+        // only used to check requirements when creating a workload
+        let mut seq_system_index_map = Vec::new();
+        let mut par_system_index_map = batches.parallel.clone();
+
+        insert_system(
+            batches,
+            &mut workload_info,
+            0,
+            system_index,
+            system.type_id,
+            system.display_name,
+            system.borrow_constraints,
+            system.run_if,
+            0,
+            &mut seq_system_index_map,
+            &mut par_system_index_map,
+        );
+        batches.requirements.push(Vec::new());
+
+        Ok(workload_info)
+    }
+}
+
+fn check_requirements(system: &WorkloadSystem) -> Result<(), error::EditWorkload> {
+    if system.before_all.is_empty()
+        && system.after_all.is_empty()
+        && system.require_in_workload.is_empty()
+        && system.require_before.is_empty()
+        && system.require_after.is_empty()
+    {
+        Ok(())
+    } else {
+        Err(error::EditWorkload::Requirements(
+            system.display_name.clone(),
+        ))
+    }
+}
+
+fn enable_tracking(
+    world: &World,
+    tracking_to_enable: &[EnableTracking],
+) -> Result<(), error::EditWorkload> {
+    if tracking_to_enable.is_empty() {
+        return Ok(());
+    }
+
+    #[cfg(feature = "tracing")]
+    let tracking_span = tracing::info_span!("enable_tracking");
+    #[cfg(feature = "tracing")]
+    let _tracking_span = tracking_span.enter();
+
+    let all_storages = world
+        .all_storages()
+        .map_err(|err| error::EditWorkload::Tracking(error::GetStorage::AllStoragesBorrow(err)))?;
+
+    for enable_tracking_fn in tracking_to_enable {
+        (enable_tracking_fn)(&all_storages).map_err(error::EditWorkload::Tracking)?;
+    }
+
+    Ok(())
+}
+
+/// Systems borrowing `AllStorages` or a `!Send`/`!Sync` storage can't run in the rayon scope.
+fn is_single_system(borrow_constraints: &[TypeInfo]) -> bool {
+    borrow_constraints.iter().any(|type_info| {
+        type_info.storage_id == StorageId::of::<AllStorages>() || !type_info.thread_safe
+    })
+}
+
+/// Returns the positions in `sequential` of the systems matching `label`.
+fn system_positions(
+    batches: &Batches,
+    system_names: &[Box<dyn Label>],
+    system_generators: &[SystemGenerator],
+    label: &dyn Label,
+) -> Vec<usize> {
+    batches
+        .sequential
+        .iter()
+        .enumerate()
+        .filter(|(_, &index)| {
+            let system_label = SystemLabel {
+                type_id: (system_generators[index])(&mut Vec::new()),
+                name: system_names[index].clone(),
+            };
+
+            label.dyn_eq(&system_label) || label.dyn_eq(&*system_names[index])
+        })
+        .map(|(position, _)| position)
+        .collect()
+}
+
+/// Returns the batch of the system at `position` in `sequential`
+/// and its index in the parallel systems, `None` if it's the batch's single system.
+fn find_position(batches: &Batches, position: usize) -> (usize, Option<usize>) {
+    for (batch, (single_run_if, run_ifs)) in batches.parallel_run_if.iter().enumerate() {
+        if *single_run_if == Some(position) {
+            return (batch, None);
+        }
+
+        if let Some(slot) = run_ifs.iter().position(|&run_if| run_if == position) {
+            return (batch, Some(slot));
+        }
+    }
+
+    unreachable!("all systems are part of a batch")
+}
+
+/// Removes the system at `position` in `sequential` from its batch, the other systems stay in their batch.\
+/// The batch is removed if it ends up empty.
+fn remove_position(batches: &mut Batches, position: usize) {
+    let batch = match find_position(batches, position) {
+        (batch, None) => {
+            batches.parallel[batch].0 = None;
+            batches.parallel_run_if[batch].0 = None;
+            batch
+        }
+        (batch, Some(slot)) => {
+            batches.parallel[batch].1.remove(slot);
+            batches.parallel_run_if[batch].1.remove(slot);
+            batch
+        }
+    };
+
+    if batches.parallel[batch].0.is_none() && batches.parallel[batch].1.is_empty() {
+        batches.parallel.remove(batch);
+        batches.parallel_run_if.remove(batch);
+    }
+
+    batches.sequential.remove(position);
+    batches.sequential_run_if.remove(position);
+    batches.requirements.remove(position);
+
+    for (single_run_if, run_ifs) in &mut batches.parallel_run_if {
+        for other_position in single_run_if.iter_mut().chain(run_ifs) {
+            if *other_position > position {
+                *other_position -= 1;
+            }
+        }
+    }
+
+    for requirements in &mut batches.requirements {
+        requirements.retain(|&other_position| other_position != position);
+
+        for other_position in requirements {
+            if *other_position > position {
+                *other_position -= 1;
+            }
+        }
+    }
+}
+
+/// Drops the systems no workload uses anymore, their index is reused by the next system added.
+fn free_unused_systems(scheduler: &mut Scheduler, system_indexes: Vec<usize>) {
+    for system_index in system_indexes {
+        if scheduler.free_systems.contains(&system_index)
+            || scheduler
+                .workloads
+                .values()
+                .any(|batches| batches.sequential.contains(&system_index))
+        {
+            continue;
+        }
+
+        scheduler.systems[system_index] = Box::new(|_, _| Ok(()));
+        scheduler.system_names[system_index] = Box::new("");
+        scheduler.system_borrows[system_index] = String::new();
+        scheduler.system_generators[system_index] = Box::new(|_| TypeId::of::<()>());
+        scheduler
+            .lookup_table
+            .retain(|_, &mut index| index != system_index);
+        scheduler.free_systems.push(system_index);
+    }
+}
+
+/// Returns the conflict preventing a system borrowing `borrow_constraints`
+/// from taking the place of the system at `position` in `sequential`.
+fn batch_conflict(
+    batches: &Batches,
+    position: usize,
+    single: bool,
+    borrow_constraints: &[TypeInfo],
+    system_names: &[Box<dyn Label>],
+    system_generators: &[SystemGenerator],
+) -> Option<Conflict> {
+    let (batch, _) = find_position(batches, position);
+    let (single_system, parallel_systems) = &batches.parallel[batch];
+    let (single_run_if, run_ifs) = &batches.parallel_run_if[batch];
+
+    let other_single_system = single_system.filter(|_| *single_run_if != Some(position));
+
+    if single {
+        if let Some(other_system) = other_single_system {
+            let other_system = system_info(other_system, system_names, system_generators);
+
+            return Some(Conflict::OtherNotSendSync {
+                type_info: other_system.borrow.last().unwrap().clone(),
+                system: SystemId {
+                    name: other_system.name,
+                    type_id: other_system.type_id,
+                },
+            });
+        }
+    }
+
+    let mut conflict = None;
+    for other_system in other_single_system.into_iter().chain(
+        parallel_systems
+            .iter()
+            .zip(run_ifs)
+            .filter(|(_, &run_if)| run_if != position)
+            .map(|(&other_system, _)| other_system),
+    ) {
+        check_conflict(
+            &system_info(other_system, system_names, system_generators),
+            borrow_constraints,
+            &mut conflict,
+        );
+
+        if conflict.is_some() {
+            break;
+        }
+    }
+
+    conflict
+}
+
+fn system_info(
+    index: usize,
+    system_names: &[Box<dyn Label>],
+    system_generators: &[SystemGenerator],
+) -> SystemInfo {
+    let mut borrow = Vec::new();
+    let type_id = (system_generators[index])(&mut borrow);
+
+    SystemInfo {
+        name: system_names[index].clone(),
+        type_id,
+        borrow,
+        conflict: None,
+    }
+}
+
+/// Rebuilds the layout of a workload from its batches.
+fn workload_info(
+    name: &dyn Label,
+    batches: &Batches,
+    system_names: &[Box<dyn Label>],
+    system_generators: &[SystemGenerator],
+) -> WorkloadInfo {
+    WorkloadInfo {
+        name: name.dyn_clone(),
+        batch_info: batches
+            .parallel
+            .iter()
+            .map(|(single_system, parallel_systems)| BatchInfo {
+                systems: (
+                    single_system.map(|index| system_info(index, system_names, system_generators)),
+                    parallel_systems
+                        .iter()
+                        .map(|&index| system_info(index, system_names, system_generators))
+                        .collect(),
+                ),
+            })
+            .collect(),
+        frequency: batches.run_once.frequency(),
+    }
+}
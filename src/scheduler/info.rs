@@ -6,6 +6,7 @@ use crate::storage::StorageId;
 pub use crate::type_id::TypeId;
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
 
@@ -18,6 +19,32 @@ pub struct WorkloadInfo {
     pub name: Box<dyn Label>,
     #[allow(missing_docs)]
     pub batch_info: Vec<BatchInfo>,
+    /// How many times the workload runs and whether it already ran.
+    pub frequency: RunFrequency,
+}
+
+/// How many times a workload runs.
+///
+/// See [`WorkloadModificator::run_once`] and [`WorkloadModificator::startup`].
+///
+/// [`WorkloadModificator::run_once`]: crate::WorkloadModificator::run_once
+/// [`WorkloadModificator::startup`]: crate::WorkloadModificator::startup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub enum RunFrequency {
+    /// Runs every time it's called.
+    #[default]
+    Always,
+    /// Runs the first time it's called.
+    Once {
+        #[allow(missing_docs)]
+        has_run: bool,
+    },
+    /// Runs the first time it's called or the first time the default workload runs.
+    Startup {
+        #[allow(missing_docs)]
+        has_run: bool,
+    },
 }
 
 /// Contains information related to a batch.
@@ -157,6 +184,182 @@ impl core::hash::Hash for TypeInfo {
     }
 }
 
+/// Serializable version of [`WorkloadInfo`].
+///
+/// Workload and system names are their `Debug` representation, the same as in [`WorkloadsTypeUsage`].
+///
+/// ### Example
+/// ```
+/// use shipyard::{info::WorkloadInfoExport, Component, View, ViewMut, Workload, World};
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// fn read(_: View<Position>) {}
+/// fn write(_: ViewMut<Position>) {}
+///
+/// let world = World::new();
+///
+/// let info = Workload::new("Physics")
+///     .with_system(read)
+///     .with_system(write)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// let export = WorkloadInfoExport::from(&info);
+/// assert_eq!(export.batches.len(), 2);
+/// assert!(export.batches[1].systems[0].conflict.is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkloadInfoExport {
+    #[allow(missing_docs)]
+    pub name: String,
+    /// Batches in execution order.
+    pub batches: Vec<BatchInfoExport>,
+    #[cfg_attr(feature = "serde1", serde(default))]
+    #[allow(missing_docs)]
+    pub frequency: RunFrequency,
+}
+
+/// Serializable version of [`BatchInfo`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchInfoExport {
+    /// System that can't run in parallel with the others, it runs first.
+    pub single_system: Option<SystemInfoExport>,
+    /// Systems running in parallel.
+    pub systems: Vec<SystemInfoExport>,
+}
+
+/// Serializable version of [`SystemInfo`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemInfoExport {
+    #[allow(missing_docs)]
+    pub name: String,
+    #[allow(missing_docs)]
+    pub borrow: Vec<TypeInfo>,
+    /// Information explaining why this system could not be part of the previous batch.
+    pub conflict: Option<ConflictExport>,
+}
+
+/// Serializable version of [`Conflict`], systems are identified by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConflictExport {
+    /// Rust rules do not allow the type described by `type_info` to be borrowed at the same time as `other_type_info`.
+    Borrow {
+        #[allow(missing_docs)]
+        type_info: Option<TypeInfo>,
+        #[allow(missing_docs)]
+        other_system: String,
+        #[allow(missing_docs)]
+        other_type_info: TypeInfo,
+    },
+    /// A `!Send` and/or `!Sync` type currently prevents any parrallelism.
+    NotSendSync(TypeInfo),
+    /// A `!Send` and/or `!Sync` type currently prevents any parrallelism.
+    OtherNotSendSync {
+        #[allow(missing_docs)]
+        system: String,
+        #[allow(missing_docs)]
+        type_info: TypeInfo,
+    },
+}
+
+impl From<&WorkloadInfo> for WorkloadInfoExport {
+    fn from(workload_info: &WorkloadInfo) -> WorkloadInfoExport {
+        WorkloadInfoExport {
+            name: format!("{:?}", workload_info.name),
+            batches: workload_info
+                .batch_info
+                .iter()
+                .map(|batch_info| BatchInfoExport {
+                    single_system: batch_info.systems.0.as_ref().map(SystemInfoExport::from),
+                    systems: batch_info
+                        .systems
+                        .1
+                        .iter()
+                        .map(SystemInfoExport::from)
+                        .collect(),
+                })
+                .collect(),
+            frequency: workload_info.frequency,
+        }
+    }
+}
+
+impl From<&SystemInfo> for SystemInfoExport {
+    fn from(system_info: &SystemInfo) -> SystemInfoExport {
+        SystemInfoExport {
+            name: format!("{:?}", system_info.name),
+            borrow: system_info.borrow.clone(),
+            conflict: system_info.conflict.as_ref().map(ConflictExport::from),
+        }
+    }
+}
+
+impl From<&Conflict> for ConflictExport {
+    fn from(conflict: &Conflict) -> ConflictExport {
+        match conflict {
+            Conflict::Borrow {
+                type_info,
+                other_system,
+                other_type_info,
+            } => ConflictExport::Borrow {
+                type_info: type_info.clone(),
+                other_system: format!("{:?}", other_system.name),
+                other_type_info: other_type_info.clone(),
+            },
+            Conflict::NotSendSync(type_info) => ConflictExport::NotSendSync(type_info.clone()),
+            Conflict::OtherNotSendSync { system, type_info } => ConflictExport::OtherNotSendSync {
+                system: format!("{:?}", system.name),
+                type_info: type_info.clone(),
+            },
+        }
+    }
+}
+
+/// Message sent by a `Publisher`, each message is a single line of Json.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub enum PublisherMessage {
+    /// A workload was added to the `World`.
+    Workload(WorkloadInfoExport),
+    /// A workload ran.
+    Timings(WorkloadTimings),
+    /// Entities, components and memory usage of the `World`.
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    Inspection(crate::Inspection),
+}
+
+/// Duration of a workload run and of each of its systems.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkloadTimings {
+    #[allow(missing_docs)]
+    pub workload: String,
+    /// Number of workload runs published before this one.
+    pub frame: u64,
+    #[allow(missing_docs)]
+    pub duration: core::time::Duration,
+    /// Systems in the order they finished running.\
+    /// Systems skipped by a run if condition are not listed.
+    pub systems: Vec<SystemTiming>,
+}
+
+/// Duration of a single system run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemTiming {
+    #[allow(missing_docs)]
+    pub name: String,
+    #[allow(missing_docs)]
+    pub duration: core::time::Duration,
+}
+
 /// Contains a list of workloads, their systems and which storages these systems borrow.
 #[allow(clippy::type_complexity)]
 #[derive(Debug)]
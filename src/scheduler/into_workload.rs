@@ -184,6 +184,8 @@ where
                 require_before: DedupedLabels::new(),
                 require_after: DedupedLabels::new(),
                 barriers: Vec::new(),
+                run_once: false,
+                startup: false,
             }
         }
     }
@@ -226,6 +228,8 @@ macro_rules! impl_into_workload {
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    run_once: false,
+                    startup: false,
                 };
 
                 $(
@@ -256,6 +260,8 @@ macro_rules! impl_into_workload {
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    run_once: false,
+                    startup: false,
                 };
 
                 let mut sequential_tags = Vec::new();
@@ -1,14 +1,17 @@
 use crate::all_storages::AllStorages;
+use crate::atomic_refcell::HolderScope;
 use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
 use crate::error;
 use crate::scheduler::system::{RunIf, WorkloadRunIfFn};
 use crate::scheduler::TypeInfo;
-use crate::type_id::TypeId;
 use crate::storage::StorageId;
+use crate::tracking::rebased;
+use crate::type_id::TypeId;
 use crate::World;
 use alloc::boxed::Box;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::any::type_name;
 use core::sync::atomic::{AtomicU32, Ordering};
 
 pub trait IntoRunIf<B> {
@@ -85,7 +88,8 @@ macro_rules! impl_into_workload_run_if {
                 Ok(RunIf {
                     system_fn: Box::new(move |world: &World| {
                         let current = world.get_current();
-                        let last_run = last_run.swap(current, Ordering::Acquire);
+                        let last_run = rebased(last_run.swap(current, Ordering::Acquire), current);
+                        let _holder = HolderScope::system(type_name::<Func>());
                         let system_id = TypeId::of::<Func>();
                         Ok((&&self)($($type::world_borrow(&world, Some(system_id), Some(last_run), current)?),+))
                     }),
@@ -171,7 +175,8 @@ macro_rules! impl_into_workload_run_if {
                 let last_run = Arc::new(AtomicU32::new(0));
                 Ok(Box::new(move |world: &World| {
                     let current = world.get_current();
-                    let last_run = last_run.swap(current, Ordering::Acquire);
+                    let last_run = rebased(last_run.swap(current, Ordering::Acquire), current);
+                    let _holder = HolderScope::system(type_name::<Func>());
                     let system_id = TypeId::of::<Func>();
                     Ok((&&self)($($type::world_borrow(&world, Some(system_id), Some(last_run), current)?),+))
                 }))
@@ -1,9 +1,11 @@
 use crate::all_storages::AllStorages;
+use crate::atomic_refcell::HolderScope;
 use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
 use crate::info::DedupedLabels;
 use crate::scheduler::label::{SystemLabel, WorkloadLabel};
 use crate::scheduler::{TypeInfo, WorkloadSystem};
 use crate::storage::StorageId;
+use crate::tracking::SystemLastRun;
 use crate::type_id::TypeId;
 use crate::{error, AsLabel, Workload};
 use crate::{Label, World};
@@ -11,7 +13,6 @@ use alloc::boxed::Box;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::any::type_name;
-use core::sync::atomic::{AtomicU32, Ordering};
 
 /// Trait used to add systems to a workload.
 ///
@@ -40,7 +41,7 @@ where
         Ok(WorkloadSystem {
             borrow_constraints: Vec::new(),
             tracking_to_enable: Vec::new(),
-            system_fn: Box::new(move |_: &World| {
+            system_fn: Box::new(move |_: &World, _: &SystemLastRun| {
                 (self)();
                 Ok(())
             }),
@@ -57,6 +58,7 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            run_once: false,
         })
     }
     fn label(&self) -> Box<dyn Label> {
@@ -145,13 +147,13 @@ macro_rules! impl_into_workload_system {
                     $type::enable_tracking(&mut tracking_to_enable);
                 )+
 
-                let last_run = AtomicU32::new(0);
                 Ok(WorkloadSystem {
                     borrow_constraints: borrows,
                     tracking_to_enable,
-                    system_fn: Box::new(move |world: &World| {
+                    system_fn: Box::new(move |world: &World, last_run: &SystemLastRun| {
                         let current = world.get_current();
-                        let last_run = last_run.swap(current, Ordering::Acquire);
+                        let last_run = last_run.swap(current);
+                        let _holder = HolderScope::system(type_name::<Func>());
                         let system_id = TypeId::of::<Func>();
                         Ok(drop((&&self)($($type::world_borrow(&world, Some(system_id), Some(last_run), current)?),+)))
                     }),
@@ -175,6 +177,7 @@ macro_rules! impl_into_workload_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    run_once: false,
                 })
             }
             fn label(&self) -> Box<dyn Label> {
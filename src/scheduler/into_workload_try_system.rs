@@ -1,10 +1,12 @@
 use crate::all_storages::AllStorages;
+use crate::atomic_refcell::HolderScope;
 use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
 use crate::info::DedupedLabels;
 use crate::scheduler::into_workload_system::Nothing;
 use crate::scheduler::label::SystemLabel;
 use crate::scheduler::{TypeInfo, WorkloadSystem};
 use crate::storage::StorageId;
+use crate::tracking::SystemLastRun;
 use crate::type_id::TypeId;
 use crate::World;
 use crate::{error, AsLabel};
@@ -14,7 +16,6 @@ use alloc::vec::Vec;
 use core::any::type_name;
 #[cfg(not(feature = "std"))]
 use core::any::Any;
-use core::sync::atomic::{AtomicU32, Ordering};
 #[cfg(feature = "std")]
 use std::error::Error;
 
@@ -54,7 +55,7 @@ where
         Ok(WorkloadSystem {
             borrow_constraints: Vec::new(),
             tracking_to_enable: Vec::new(),
-            system_fn: Box::new(move |_: &World| {
+            system_fn: Box::new(move |_: &World, _: &SystemLastRun| {
                 (self)().into().map_err(error::Run::from_custom)?;
                 Ok(())
             }),
@@ -71,6 +72,7 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            run_once: false,
         })
     }
     #[cfg(not(feature = "std"))]
@@ -85,7 +87,7 @@ where
         Ok(WorkloadSystem {
             borrow_constraints: Vec::new(),
             tracking_to_enable: Vec::new(),
-            system_fn: Box::new(move |_: &World| {
+            system_fn: Box::new(move |_: &World, _: &SystemLastRun| {
                 (self)().into().map_err(error::Run::from_custom)?;
                 Ok(())
             }),
@@ -102,6 +104,7 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            run_once: false,
         })
     }
 }
@@ -159,13 +162,13 @@ macro_rules! impl_into_workload_try_system {
                     $type::enable_tracking(&mut tracking_to_enable);
                 )+
 
-                let last_run = AtomicU32::new(0);
                 Ok(WorkloadSystem {
                     borrow_constraints: borrows,
                     tracking_to_enable,
-                    system_fn: Box::new(move |world: &World| {
+                    system_fn: Box::new(move |world: &World, last_run: &SystemLastRun| {
                         let current = world.get_current();
-                        let last_run = last_run.swap(current, Ordering::Acquire);
+                        let last_run = last_run.swap(current);
+                        let _holder = HolderScope::system(type_name::<Func>());
                         Ok(drop((&&self)($($type::world_borrow(&world, Some(system_id), Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
                     }),
                     type_id: TypeId::of::<Func>(),
@@ -188,6 +191,7 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    run_once: false,
                 })
             }
             #[cfg(not(feature = "std"))]
@@ -231,13 +235,13 @@ macro_rules! impl_into_workload_try_system {
                     $type::enable_tracking(&mut tracking_to_enable);
                 )+
 
-                let last_run = AtomicU32::new(0);
                 Ok(WorkloadSystem {
                     borrow_constraints: borrows,
                     tracking_to_enable,
-                    system_fn: Box::new(move |world: &World| {
+                    system_fn: Box::new(move |world: &World, last_run: &SystemLastRun| {
                         let current = world.get_current();
-                        let last_run = last_run.swap(current, Ordering::Acquire);
+                        let last_run = last_run.swap(current);
+                        let _holder = HolderScope::system(type_name::<Func>());
                         Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
                     }),
                     type_id: TypeId::of::<Func>(),
@@ -259,6 +263,7 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    run_once: false,
                 })
             }
         }
@@ -1,9 +1,13 @@
+mod edit_workload;
 pub mod info;
 mod into_workload;
 mod into_workload_run_if;
 mod into_workload_system;
 mod into_workload_try_system;
 mod label;
+mod pipe;
+#[cfg(all(feature = "std", feature = "serde1"))]
+mod publisher;
 mod system;
 mod system_modificator;
 mod workload;
@@ -13,6 +17,9 @@ pub use into_workload::IntoWorkload;
 pub use into_workload_system::IntoWorkloadSystem;
 pub use into_workload_try_system::IntoWorkloadTrySystem;
 pub use label::{AsLabel, Label};
+pub use pipe::{IntoPipedSystem, IntoSystemPipe, Pipe};
+#[cfg(all(feature = "std", feature = "serde1"))]
+pub use publisher::Publisher;
 pub use system::WorkloadSystem;
 pub use system_modificator::SystemModificator;
 pub use workload::{ScheduledWorkload, Workload};
@@ -21,11 +28,18 @@ pub use workload_modificator::WorkloadModificator;
 pub(crate) use info::TypeInfo;
 
 use crate::error;
+use crate::memory_usage::SchedulerMemoryUsage;
+use crate::scheduler::info::RunFrequency;
 use crate::scheduler::system::WorkloadRunIfFn;
+use crate::tracking::SystemLastRun;
 use crate::type_id::TypeId;
 use crate::World;
 use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::mem::{size_of, size_of_val};
+use core::sync::atomic::{AtomicBool, Ordering};
 use hashbrown::HashMap;
 
 /// List of indexes into both systems and system_names
@@ -40,7 +54,53 @@ pub(super) struct Batches {
     pub(super) sequential: Vec<usize>,
     pub(super) sequential_run_if:
         Vec<Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>>,
+    /// For each system in `sequential`, the positions in `sequential` of the systems it has to run after
+    pub(super) requirements: Vec<Vec<usize>>,
     pub(super) run_if: Option<Box<dyn WorkloadRunIfFn>>,
+    pub(super) run_once: RunOnce,
+}
+
+/// Run-once state of a workload and of its run-once systems
+#[derive(Default)]
+pub(super) struct RunOnce {
+    pub(super) enabled: bool,
+    pub(super) startup: bool,
+    pub(super) has_run: AtomicBool,
+    /// Shared with the systems' run_if
+    pub(super) systems: Vec<Arc<AtomicBool>>,
+}
+
+impl RunOnce {
+    /// Returns `true` if the workload is run-once and already ran.
+    pub(super) fn has_run(&self) -> bool {
+        self.enabled && self.has_run.load(Ordering::Acquire)
+    }
+    /// Marks the workload as run, returns `false` if it already ran.
+    pub(super) fn start(&self) -> bool {
+        !(self.enabled && self.has_run.swap(true, Ordering::AcqRel))
+    }
+    /// Lets the workload run again after an error.
+    pub(super) fn abort(&self) {
+        self.has_run.store(false, Ordering::Release);
+    }
+    pub(super) fn reset(&self) {
+        self.has_run.store(false, Ordering::Release);
+
+        for system in &self.systems {
+            system.store(false, Ordering::Release);
+        }
+    }
+    pub(super) fn frequency(&self) -> RunFrequency {
+        let has_run = self.has_run.load(Ordering::Acquire);
+
+        if self.startup {
+            RunFrequency::Startup { has_run }
+        } else if self.enabled {
+            RunFrequency::Once { has_run }
+        } else {
+            RunFrequency::Always
+        }
+    }
 }
 
 #[cfg(test)]
@@ -69,26 +129,43 @@ impl Eq for Batches {}
 // a batch lists systems that can run in parallel
 #[allow(clippy::type_complexity)]
 pub(crate) struct Scheduler {
-    pub(crate) systems: Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
+    pub(crate) systems:
+        Vec<Box<dyn Fn(&World, &SystemLastRun) -> Result<(), error::Run> + Send + Sync + 'static>>,
+    // last time each system ran, reset when a system index is reused
+    pub(crate) last_runs: Vec<SystemLastRun>,
     pub(crate) system_names: Vec<Box<dyn Label>>,
+    // storages borrowed by each system, only listed with the `tracing` feature
+    pub(crate) system_borrows: Vec<String>,
     pub(crate) system_generators:
         Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
     // system's `TypeId` to an index into both systems and system_names
     lookup_table: HashMap<TypeId, usize>,
+    // indexes of the systems no workload uses anymore, reused before growing systems
+    free_systems: Vec<usize>,
     /// workload name to list of "batches"
     pub(crate) workloads: HashMap<Box<dyn Label>, Batches>,
     pub(crate) default: Box<dyn Label>,
+    /// startup workloads in insertion order
+    pub(crate) startup: Vec<Box<dyn Label>>,
+    #[cfg(all(feature = "std", feature = "serde1"))]
+    pub(crate) publisher: Option<std::sync::Mutex<Publisher>>,
 }
 
 impl Default for Scheduler {
     fn default() -> Self {
         Scheduler {
             systems: Vec::new(),
+            last_runs: Vec::new(),
             system_names: Vec::new(),
+            system_borrows: Vec::new(),
             system_generators: Vec::new(),
             lookup_table: HashMap::new(),
+            free_systems: Vec::new(),
             workloads: HashMap::new(),
             default: Box::new(""),
+            startup: Vec::new(),
+            #[cfg(all(feature = "std", feature = "serde1"))]
+            publisher: None,
         }
     }
 }
@@ -113,14 +190,114 @@ impl Scheduler {
             Err(error::RunWorkload::MissingWorkload)
         }
     }
-    pub(crate) fn default_workload(&self) -> &Batches {
-        &self.workloads[&self.default]
+    pub(crate) fn default_workload(&self) -> Option<&Batches> {
+        self.workloads.get(&self.default)
     }
     pub(crate) fn contains_workload(&self, name: &dyn Label) -> bool {
         self.workloads.contains_key(name)
     }
-    pub(crate) fn is_empty(&self) -> bool {
-        self.workloads.is_empty()
+    pub(crate) fn memory_usage(&self) -> SchedulerMemoryUsage {
+        fn vec_memory<T>(vec: &[T], capacity: usize) -> (usize, usize) {
+            (size_of_val(vec), capacity * size_of::<T>())
+        }
+
+        let mut used_memory_bytes = size_of::<Scheduler>();
+        let mut allocated_memory_bytes = size_of::<Scheduler>();
+        let mut add = |(used, allocated): (usize, usize)| {
+            used_memory_bytes += used;
+            allocated_memory_bytes += allocated;
+        };
+
+        add(vec_memory(&self.systems, self.systems.capacity()));
+        add(vec_memory(&self.last_runs, self.last_runs.capacity()));
+        add(vec_memory(&self.system_names, self.system_names.capacity()));
+        add(vec_memory(
+            &self.system_borrows,
+            self.system_borrows.capacity(),
+        ));
+        add(vec_memory(
+            &self.system_generators,
+            self.system_generators.capacity(),
+        ));
+        add(vec_memory(&self.startup, self.startup.capacity()));
+        add(vec_memory(&self.free_systems, self.free_systems.capacity()));
+        for system in &self.systems {
+            let size = size_of_val(&**system);
+            add((size, size));
+        }
+        for name in &self.system_names {
+            let size = size_of_val(&**name);
+            add((size, size));
+        }
+        for borrow in &self.system_borrows {
+            add((borrow.len(), borrow.capacity()));
+        }
+        for generator in &self.system_generators {
+            let size = size_of_val(&**generator);
+            add((size, size));
+        }
+
+        let lookup_table_entry = size_of::<(TypeId, usize)>();
+        add((
+            self.lookup_table.len() * lookup_table_entry,
+            self.lookup_table.capacity() * lookup_table_entry,
+        ));
+
+        let workload_entry = size_of::<(Box<dyn Label>, Batches)>();
+        add((
+            self.workloads.len() * workload_entry,
+            self.workloads.capacity() * workload_entry,
+        ));
+        for (label, batches) in &self.workloads {
+            let size = size_of_val(&**label);
+            add((size, size));
+
+            add(vec_memory(&batches.parallel, batches.parallel.capacity()));
+            for (_, systems) in &batches.parallel {
+                add(vec_memory(systems, systems.capacity()));
+            }
+            add(vec_memory(
+                &batches.parallel_run_if,
+                batches.parallel_run_if.capacity(),
+            ));
+            for (_, systems) in &batches.parallel_run_if {
+                add(vec_memory(systems, systems.capacity()));
+            }
+            add(vec_memory(
+                &batches.sequential,
+                batches.sequential.capacity(),
+            ));
+            add(vec_memory(
+                &batches.sequential_run_if,
+                batches.sequential_run_if.capacity(),
+            ));
+            for run_if in batches.sequential_run_if.iter().flatten() {
+                let size = size_of_val(&**run_if);
+                add((size, size));
+            }
+            add(vec_memory(
+                &batches.requirements,
+                batches.requirements.capacity(),
+            ));
+            for requirements in &batches.requirements {
+                add(vec_memory(requirements, requirements.capacity()));
+            }
+            if let Some(run_if) = &batches.run_if {
+                let size = size_of_val(&**run_if);
+                add((size, size));
+            }
+            add(vec_memory(
+                &batches.run_once.systems,
+                batches.run_once.systems.capacity(),
+            ));
+        }
+
+        SchedulerMemoryUsage {
+            system_count: self.systems.len() - self.free_systems.len(),
+            workload_count: self.workloads.len(),
+            used_memory_bytes,
+            allocated_memory_bytes,
+        }
     }
     pub(crate) fn rename(&mut self, old: &dyn Label, new: Box<dyn Label>) {
         if let Some(batches) = self.workloads.remove(old) {
@@ -128,6 +305,12 @@ impl Scheduler {
                 self.default = new.clone();
             }
 
+            for startup in &mut self.startup {
+                if &**startup == old {
+                    *startup = new.clone();
+                }
+            }
+
             self.workloads.insert(new, batches);
         }
     }
@@ -140,8 +323,21 @@ impl core::fmt::Debug for Scheduler {
         debug_struct.field("default_workload", &self.default);
         debug_struct.field("workload_count", &self.workloads.len());
         debug_struct.field("workloads", &self.workloads.keys());
-        debug_struct.field("system_count", &self.system_names.len());
-        debug_struct.field("systems", &self.system_names);
+        debug_struct.field("startup_workloads", &self.startup);
+        debug_struct.field(
+            "system_count",
+            &(self.system_names.len() - self.free_systems.len()),
+        );
+        debug_struct.field(
+            "systems",
+            &self
+                .system_names
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !self.free_systems.contains(index))
+                .map(|(_, name)| name)
+                .collect::<Vec<_>>(),
+        );
 
         debug_struct.finish()
     }
@@ -0,0 +1,473 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::HolderScope;
+use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
+use crate::info::DedupedLabels;
+use crate::scheduler::into_workload_run_if::IntoRunIf;
+use crate::scheduler::into_workload_system::Nothing;
+use crate::scheduler::label::SystemLabel;
+use crate::scheduler::{IntoWorkloadSystem, SystemModificator, TypeInfo, WorkloadSystem};
+use crate::storage::StorageId;
+use crate::tracking::SystemLastRun;
+use crate::type_id::TypeId;
+use crate::{error, AsLabel, Label, World};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::marker::PhantomData;
+
+/// Systems whose output is fed into the next system.
+///
+/// Created with [`IntoSystemPipe::pipe`], it can be added to a workload like any other system.\
+/// All systems run one after the other, the pipe borrows the union of their storages.
+#[allow(clippy::type_complexity)]
+pub struct Pipe<Id, R> {
+    display_name: Box<dyn Label>,
+    // receives the pipe's last run and current timestamp, shared by all its systems
+    system_fn: Box<dyn Fn(&World, u32, u32) -> Result<R, error::Run> + Send + Sync + 'static>,
+    borrow_constraints: Vec<TypeInfo>,
+    tracking_to_enable: Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    generator: Box<dyn Fn(&mut Vec<TypeInfo>) + Send + Sync + 'static>,
+    _phantom: PhantomData<fn() -> Id>,
+}
+
+/// System receiving the output of the previous system of a [`Pipe`].
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct PipedSystem<In, R> {
+    display_name: Box<dyn Label>,
+    system_fn: Box<dyn Fn(In, &World, u32, u32) -> Result<R, error::Run> + Send + Sync + 'static>,
+    borrow_constraints: Vec<TypeInfo>,
+    tracking_to_enable: Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    generator: Box<dyn Fn(&mut Vec<TypeInfo>) + Send + Sync + 'static>,
+}
+
+/// Trait used to feed the output of a system into another system.
+pub trait IntoSystemPipe<B, R>: Sized {
+    /// Type identifying the pipe, only used to give it a unique `TypeId`.
+    #[doc(hidden)]
+    type Id: 'static;
+
+    /// Wraps a function in a pipe made of a single system.
+    #[doc(hidden)]
+    fn into_pipe(self) -> Result<Pipe<Self::Id, R>, error::InvalidSystem>;
+    /// Feeds the output of this system into `system`.\
+    /// The output is passed as `system`'s first argument.
+    ///
+    /// ### Panics
+    ///
+    /// - One of the systems is invalid.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoSystemPipe, Unique, UniqueViewMut, View, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// #[derive(Unique)]
+    /// struct EnemyCount(usize);
+    ///
+    /// fn count_enemies(enemies: View<Enemy>) -> usize {
+    ///     enemies.len()
+    /// }
+    ///
+    /// fn display_count(count: usize, mut enemy_count: UniqueViewMut<EnemyCount>) {
+    ///     enemy_count.0 = count;
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.add_unique(EnemyCount(0));
+    /// world.add_entity(Enemy);
+    ///
+    /// Workload::new("UI")
+    ///     .with_system(count_enemies.pipe(display_count))
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default().unwrap();
+    ///
+    /// assert_eq!(world.borrow::<UniqueViewMut<EnemyCount>>().unwrap().0, 1);
+    /// ```
+    #[track_caller]
+    fn pipe<PipeB, PipeR, S: IntoPipedSystem<R, PipeB, PipeR>>(
+        self,
+        system: S,
+    ) -> Pipe<(Self::Id, S::Id), PipeR>
+    where
+        R: 'static,
+        PipeR: 'static,
+    {
+        let Pipe {
+            display_name,
+            system_fn,
+            mut borrow_constraints,
+            mut tracking_to_enable,
+            generator,
+            ..
+        } = self.into_pipe().unwrap();
+        let piped_system = system.into_piped_system().unwrap();
+        let piped_system_fn = piped_system.system_fn;
+        let piped_generator = piped_system.generator;
+
+        merge_borrows(&mut borrow_constraints, piped_system.borrow_constraints);
+        tracking_to_enable.extend(piped_system.tracking_to_enable);
+
+        Pipe {
+            display_name: Box::new(format!(
+                "{} | {}",
+                label_name(&*display_name),
+                label_name(&*piped_system.display_name)
+            )),
+            system_fn: Box::new(move |world, last_run, current| {
+                let output = (system_fn)(world, last_run, current)?;
+
+                (piped_system_fn)(output, world, last_run, current)
+            }),
+            borrow_constraints,
+            tracking_to_enable,
+            generator: Box::new(move |constraints| {
+                let mut borrows = Vec::new();
+                (generator)(&mut borrows);
+
+                let mut piped_borrows = Vec::new();
+                (piped_generator)(&mut piped_borrows);
+
+                merge_borrows(&mut borrows, piped_borrows);
+                constraints.extend(borrows);
+            }),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Trait used to receive the output of the previous system of a [`Pipe`].
+///
+/// The output is the first argument of the function, the other arguments are views.
+pub trait IntoPipedSystem<In, B, R> {
+    /// Type identifying the system, only used to give pipes a unique `TypeId`.
+    #[doc(hidden)]
+    type Id: 'static;
+
+    /// Wraps a function in a struct containing all information required by a pipe.
+    #[doc(hidden)]
+    fn into_piped_system(self) -> Result<PipedSystem<In, R>, error::InvalidSystem>;
+}
+
+fn label_name(label: &dyn Label) -> String {
+    if let Some(name) = label.as_any().downcast_ref::<&'static str>() {
+        (*name).into()
+    } else if let Some(name) = label.as_any().downcast_ref::<String>() {
+        name.clone()
+    } else {
+        format!("{:?}", label)
+    }
+}
+
+/// Adds `other` to `borrows`, storages borrowed by both are only present once.
+fn merge_borrows(borrows: &mut Vec<TypeInfo>, other: Vec<TypeInfo>) {
+    for type_info in other {
+        if let Some(borrow) = borrows
+            .iter_mut()
+            .find(|borrow| borrow.storage_id == type_info.storage_id)
+        {
+            if type_info.mutability == Mutability::Exclusive {
+                borrow.mutability = Mutability::Exclusive;
+            }
+            borrow.thread_safe &= type_info.thread_safe;
+        } else {
+            borrows.push(type_info);
+        }
+    }
+
+    // `AllStorages` already covers all other storages
+    if borrows.len() > 1
+        && borrows
+            .iter()
+            .any(|borrow| borrow.storage_id == StorageId::of::<AllStorages>())
+    {
+        borrows.retain(|borrow| borrow.storage_id == StorageId::of::<AllStorages>());
+    }
+}
+
+/// Checks that a single system doesn't borrow a storage twice.
+fn check_borrows(borrows: &[TypeInfo]) -> Result<(), error::InvalidSystem> {
+    if borrows.len() > 1
+        && borrows
+            .iter()
+            .any(|borrow| borrow.storage_id == StorageId::of::<AllStorages>())
+    {
+        return Err(error::InvalidSystem::AllStorages);
+    }
+
+    for (i, a_type_info) in borrows.iter().enumerate() {
+        for b_type_info in &borrows[i + 1..] {
+            if a_type_info.storage_id == b_type_info.storage_id {
+                match (a_type_info.mutability, b_type_info.mutability) {
+                    (Mutability::Exclusive, Mutability::Exclusive) => {
+                        return Err(error::InvalidSystem::MultipleViewsMut)
+                    }
+                    (Mutability::Exclusive, Mutability::Shared)
+                    | (Mutability::Shared, Mutability::Exclusive) => {
+                        return Err(error::InvalidSystem::MultipleViews)
+                    }
+                    (Mutability::Shared, Mutability::Shared) => {}
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl<Id: 'static, R: 'static> IntoSystemPipe<Pipe<Id, R>, R> for Pipe<Id, R> {
+    type Id = Id;
+
+    fn into_pipe(self) -> Result<Pipe<Id, R>, error::InvalidSystem> {
+        Ok(self)
+    }
+}
+
+impl<R, F> IntoSystemPipe<Nothing, R> for F
+where
+    R: 'static,
+    F: 'static + Send + Sync + Fn() -> R,
+{
+    type Id = F;
+
+    fn into_pipe(self) -> Result<Pipe<F, R>, error::InvalidSystem> {
+        Ok(Pipe {
+            display_name: Box::new(type_name::<F>()),
+            system_fn: Box::new(move |_: &World, _, _| Ok((self)())),
+            borrow_constraints: Vec::new(),
+            tracking_to_enable: Vec::new(),
+            generator: Box::new(|_| {}),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<In, R, F> IntoPipedSystem<In, Nothing, R> for F
+where
+    In: 'static,
+    R: 'static,
+    F: 'static + Send + Sync + Fn(In) -> R,
+{
+    type Id = F;
+
+    fn into_piped_system(self) -> Result<PipedSystem<In, R>, error::InvalidSystem> {
+        Ok(PipedSystem {
+            display_name: Box::new(type_name::<F>()),
+            system_fn: Box::new(move |input: In, _: &World, _, _| Ok((self)(input))),
+            borrow_constraints: Vec::new(),
+            tracking_to_enable: Vec::new(),
+            generator: Box::new(|_| {}),
+        })
+    }
+}
+
+impl<Id: 'static, R: 'static> IntoWorkloadSystem<Pipe<Id, R>, R> for Pipe<Id, R> {
+    fn into_workload_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
+        let Pipe {
+            display_name,
+            system_fn,
+            borrow_constraints,
+            tracking_to_enable,
+            generator,
+            ..
+        } = self;
+
+        Ok(WorkloadSystem {
+            type_id: TypeId::of::<Id>(),
+            tags: vec![Box::new(SystemLabel {
+                type_id: TypeId::of::<Id>(),
+                name: display_name.clone(),
+            })],
+            display_name,
+            system_fn: Box::new(move |world: &World, last_run: &SystemLastRun| {
+                let current = world.get_current();
+                let last_run = last_run.swap(current);
+                (system_fn)(world, last_run, current)?;
+                Ok(())
+            }),
+            borrow_constraints,
+            tracking_to_enable,
+            generator: Box::new(move |constraints| {
+                (generator)(constraints);
+
+                TypeId::of::<Id>()
+            }),
+            run_if: None,
+            before_all: DedupedLabels::new(),
+            after_all: DedupedLabels::new(),
+            require_in_workload: DedupedLabels::new(),
+            require_before: DedupedLabels::new(),
+            require_after: DedupedLabels::new(),
+            run_once: false,
+        })
+    }
+    fn label(&self) -> Box<dyn Label> {
+        Box::new(SystemLabel {
+            type_id: TypeId::of::<Id>(),
+            name: self.display_name.clone(),
+        })
+    }
+    fn call(&self) -> R {
+        unreachable!()
+    }
+}
+
+impl<Id: 'static, R: 'static> SystemModificator<Pipe<Id, R>, R> for Pipe<Id, R> {
+    #[track_caller]
+    fn run_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
+        self.into_workload_system().unwrap().run_if(run_if)
+    }
+    #[track_caller]
+    fn skip_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
+        self.into_workload_system().unwrap().skip_if(run_if)
+    }
+    #[track_caller]
+    fn before_all<T>(self, other: impl AsLabel<T>) -> WorkloadSystem {
+        self.into_workload_system().unwrap().before_all(other)
+    }
+    #[track_caller]
+    fn after_all<T>(self, other: impl AsLabel<T>) -> WorkloadSystem {
+        self.into_workload_system().unwrap().after_all(other)
+    }
+    #[track_caller]
+    fn display_name<T>(self, name: impl AsLabel<T>) -> WorkloadSystem {
+        self.into_workload_system().unwrap().display_name(name)
+    }
+    #[track_caller]
+    fn tag<T>(self, tag: impl AsLabel<T>) -> WorkloadSystem {
+        self.into_workload_system().unwrap().tag(tag)
+    }
+    #[track_caller]
+    fn require_in_workload<T>(self, other: impl AsLabel<T>) -> WorkloadSystem {
+        self.into_workload_system()
+            .unwrap()
+            .require_in_workload(other)
+    }
+    #[track_caller]
+    fn require_before<T>(self, other: impl AsLabel<T>) -> WorkloadSystem {
+        self.into_workload_system().unwrap().require_before(other)
+    }
+    #[track_caller]
+    fn require_after<T>(self, other: impl AsLabel<T>) -> WorkloadSystem {
+        self.into_workload_system().unwrap().require_after(other)
+    }
+    #[track_caller]
+    fn run_once(self) -> WorkloadSystem {
+        self.into_workload_system().unwrap().run_once()
+    }
+}
+
+macro_rules! impl_pipe {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: WorldBorrow + BorrowInfo,)+ R, Func> IntoSystemPipe<($($type,)+), R> for Func
+        where
+            R: 'static,
+            Func: 'static
+                + Send
+                + Sync,
+            for<'a, 'b> &'b Func:
+                Fn($($type),+) -> R
+                + Fn($($type::WorldView<'a>),+) -> R {
+
+            type Id = Func;
+
+            fn into_pipe(self) -> Result<Pipe<Func, R>, error::InvalidSystem> {
+                let system_id = TypeId::of::<Func>();
+                let mut borrows = Vec::new();
+                $(
+                    $type::borrow_info(&mut borrows, Some(system_id));
+                )+
+
+                check_borrows(&borrows)?;
+
+                let mut tracking_to_enable = Vec::new();
+                $(
+                    $type::enable_tracking(&mut tracking_to_enable);
+                )+
+
+                Ok(Pipe {
+                    display_name: Box::new(type_name::<Func>()),
+                    system_fn: Box::new(move |world: &World, last_run: u32, current: u32| {
+                        let _holder = HolderScope::system(type_name::<Func>());
+                        let system_id = TypeId::of::<Func>();
+                        Ok((&&self)($($type::world_borrow(&world, Some(system_id), Some(last_run), current)?),+))
+                    }),
+                    borrow_constraints: borrows,
+                    tracking_to_enable,
+                    generator: Box::new(|constraints| {
+                        $(
+                            let system_id = TypeId::of::<Func>();
+                            $type::borrow_info(constraints, Some(system_id));
+                        )+
+                    }),
+                    _phantom: PhantomData,
+                })
+            }
+        }
+
+        impl<In, $($type: WorldBorrow + BorrowInfo,)+ R, Func> IntoPipedSystem<In, ($($type,)+), R> for Func
+        where
+            In: 'static,
+            R: 'static,
+            Func: 'static
+                + Send
+                + Sync,
+            for<'a, 'b> &'b Func:
+                Fn(In, $($type),+) -> R
+                + Fn(In, $($type::WorldView<'a>),+) -> R {
+
+            type Id = Func;
+
+            fn into_piped_system(self) -> Result<PipedSystem<In, R>, error::InvalidSystem> {
+                let system_id = TypeId::of::<Func>();
+                let mut borrows = Vec::new();
+                $(
+                    $type::borrow_info(&mut borrows, Some(system_id));
+                )+
+
+                check_borrows(&borrows)?;
+
+                let mut tracking_to_enable = Vec::new();
+                $(
+                    $type::enable_tracking(&mut tracking_to_enable);
+                )+
+
+                Ok(PipedSystem {
+                    display_name: Box::new(type_name::<Func>()),
+                    system_fn: Box::new(move |input: In, world: &World, last_run: u32, current: u32| {
+                        let _holder = HolderScope::system(type_name::<Func>());
+                        let system_id = TypeId::of::<Func>();
+                        Ok((&&self)(input, $($type::world_borrow(&world, Some(system_id), Some(last_run), current)?),+))
+                    }),
+                    borrow_constraints: borrows,
+                    tracking_to_enable,
+                    generator: Box::new(|constraints| {
+                        $(
+                            let system_id = TypeId::of::<Func>();
+                            $type::borrow_info(constraints, Some(system_id));
+                        )+
+                    }),
+                })
+            }
+        }
+    }
+}
+
+macro_rules! pipe {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_pipe![$(($type, $index))*];
+        pipe![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_pipe![$(($type, $index))*];
+    }
+}
+
+pipe![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
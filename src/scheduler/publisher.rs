@@ -0,0 +1,287 @@
+use crate::error;
+use crate::inspect::Inspection;
+use crate::reflect::ComponentRegistry;
+use crate::scheduler::info::{
+    PublisherMessage, SystemTiming, WorkloadInfo, WorkloadInfoExport, WorkloadTimings,
+};
+use crate::scheduler::{Batches, Label, Scheduler};
+use crate::world::World;
+use std::fs::File;
+use std::io::{self, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Publishes workload information and systems' duration of a `World` as they run.
+///
+/// Each [`PublisherMessage`] is written as a single line of Json,
+/// either to the clients connected to a local TCP socket or to a file.\
+/// Workloads are published when they are added to the `World`, the publisher has to be set before adding them.
+///
+/// Publishing is best effort and never blocks, a client that can't keep up or a file that can't be written to is dropped.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Publisher, Workload, World};
+///
+/// fn sys1() {}
+///
+/// let world = World::new();
+/// world.set_publisher(Publisher::listen("127.0.0.1:0").unwrap());
+///
+/// Workload::new("Update")
+///     .with_system(sys1)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Update").unwrap();
+/// ```
+///
+/// [`PublisherMessage`]: crate::info::PublisherMessage
+pub struct Publisher {
+    listener: Option<TcpListener>,
+    sinks: Vec<Box<dyn Write + Send>>,
+    /// Workload messages, sent again to each new client
+    workloads: Vec<Vec<u8>>,
+    frame: u64,
+}
+
+impl Publisher {
+    /// Listens on `addr` for clients, they are accepted the next time something is published.\
+    /// Clients receive all workloads published so far when they connect.
+    pub fn listen<A: ToSocketAddrs>(addr: A) -> io::Result<Publisher> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Publisher {
+            listener: Some(listener),
+            sinks: Vec::new(),
+            workloads: Vec::new(),
+            frame: 0,
+        })
+    }
+    /// Creates or truncates the file at `path` and appends each message to it.
+    pub fn file<P: AsRef<Path>>(path: P) -> io::Result<Publisher> {
+        let file = File::create(path)?;
+
+        Ok(Publisher::from_writer(file))
+    }
+    /// Writes each message to `writer`.
+    pub fn from_writer<W: Write + Send + 'static>(writer: W) -> Publisher {
+        Publisher {
+            listener: None,
+            sinks: vec![Box::new(writer)],
+            workloads: Vec::new(),
+            frame: 0,
+        }
+    }
+    /// Returns the address the publisher listens on, if it was created with [`Publisher::listen`].
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.listener
+            .as_ref()
+            .and_then(|listener| listener.local_addr().ok())
+    }
+    /// Publishes `workload_info`.\
+    /// Workloads added to a `World` with a publisher are published automatically.
+    pub fn publish_workload(&mut self, workload_info: &WorkloadInfo) {
+        let line = message_line(&PublisherMessage::Workload(WorkloadInfoExport::from(
+            workload_info,
+        )));
+
+        self.accept();
+        self.write(&line);
+        self.workloads.push(line);
+    }
+    /// Publishes `inspection`.\
+    /// Unlike workloads, clients connecting later don't receive it.
+    pub fn publish_inspection(&mut self, inspection: Inspection) {
+        let line = message_line(&PublisherMessage::Inspection(inspection));
+
+        self.accept();
+        self.write(&line);
+    }
+    pub(crate) fn publish_timings(&mut self, mut timings: WorkloadTimings) {
+        timings.frame = self.frame;
+        self.frame += 1;
+
+        let line = message_line(&PublisherMessage::Timings(timings));
+
+        self.accept();
+        self.write(&line);
+    }
+    fn accept(&mut self) {
+        if let Some(listener) = &self.listener {
+            while let Ok((stream, _)) = listener.accept() {
+                if let Some(stream) = Publisher::init_client(stream, &self.workloads) {
+                    self.sinks.push(Box::new(stream));
+                }
+            }
+        }
+    }
+    fn init_client(mut stream: TcpStream, workloads: &[Vec<u8>]) -> Option<TcpStream> {
+        // some platforms don't make accepted streams inherit the listener's non blocking mode
+        // a full socket buffer then fails the write with `WouldBlock` and the client is dropped
+        stream.set_nonblocking(true).ok()?;
+        stream.set_nodelay(true).ok()?;
+
+        for line in workloads {
+            stream.write_all(line).ok()?;
+        }
+
+        Some(stream)
+    }
+    fn write(&mut self, line: &[u8]) {
+        self.sinks
+            .retain_mut(|sink| sink.write_all(line).and_then(|_| sink.flush()).is_ok());
+    }
+}
+
+impl core::fmt::Debug for Publisher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Publisher")
+            .field("local_addr", &self.local_addr())
+            .field("sink_count", &self.sinks.len())
+            .field("workload_count", &self.workloads.len())
+            .field("frame", &self.frame)
+            .finish()
+    }
+}
+
+fn message_line(message: &PublisherMessage) -> Vec<u8> {
+    let mut line = serde_json::to_vec(message).unwrap();
+    line.push(b'\n');
+    line
+}
+
+impl World {
+    /// Publishes workloads added to the `World` from now on and the duration of their systems every time they run.\
+    /// Replaces the previous publisher.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    #[track_caller]
+    pub fn set_publisher(&self, publisher: Publisher) {
+        self.scheduler.borrow_mut().unwrap().publisher = Some(Mutex::new(publisher));
+    }
+    /// Stops publishing and returns the current publisher.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    #[track_caller]
+    pub fn take_publisher(&self) -> Option<Publisher> {
+        self.scheduler
+            .borrow_mut()
+            .unwrap()
+            .publisher
+            .take()
+            .map(|publisher| {
+                publisher
+                    .into_inner()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+            })
+    }
+    /// Inspects the `World` with `registry` and publishes the result.\
+    /// Does nothing if the `World` doesn't have a publisher.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - [`AllStorages`] (shared)
+    /// - [`Entities`] (shared)
+    /// - storages of the registered components (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    /// - A component failed to serialize.
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    /// [`Entities`]: crate::Entities
+    #[track_caller]
+    pub fn publish_inspection(&self, registry: &ComponentRegistry) -> Result<(), error::Reflect> {
+        let scheduler = self.scheduler.borrow().unwrap();
+
+        if let Some(publisher) = &scheduler.publisher {
+            let inspection = self.inspect(registry)?;
+
+            publisher
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .publish_inspection(inspection);
+        }
+
+        Ok(())
+    }
+    /// Runs `batches` timing each system, then publishes the timings.\
+    /// Nothing is published if the workload doesn't run.
+    pub(crate) fn run_batches_published(
+        &self,
+        scheduler: &Scheduler,
+        publisher: &Mutex<Publisher>,
+        batches: &Batches,
+        workload_name: &dyn Label,
+    ) -> Result<(), error::RunWorkload> {
+        if !self.start_batches(batches, workload_name)? {
+            return Ok(());
+        }
+
+        let timings = Mutex::new(Vec::new());
+
+        let start = Instant::now();
+        let result = self.run_started_batches(
+            &|index| {
+                let system_start = Instant::now();
+                let result = (scheduler.systems[index])(self, &scheduler.last_runs[index]);
+                let duration = system_start.elapsed();
+
+                if let Ok(mut timings) = timings.lock() {
+                    timings.push((index, duration));
+                }
+
+                result
+            },
+            &scheduler.system_names,
+            &scheduler.system_borrows,
+            batches,
+            workload_name,
+        );
+        let duration = start.elapsed();
+
+        let systems = timings
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .into_iter()
+            .map(|(index, duration)| SystemTiming {
+                name: format!("{:?}", scheduler.system_names[index]),
+                duration,
+            })
+            .collect();
+
+        if let Ok(mut publisher) = publisher.lock() {
+            publisher.publish_timings(WorkloadTimings {
+                workload: format!("{:?}", workload_name),
+                frame: 0,
+                duration,
+                systems,
+            });
+        }
+
+        result
+    }
+}
@@ -4,6 +4,7 @@ use crate::error;
 use crate::info::DedupedLabels;
 use crate::scheduler::label::Label;
 use crate::scheduler::workload::Workload;
+use crate::tracking::SystemLastRun;
 use crate::type_id::TypeId;
 use crate::world::World;
 use alloc::boxed::Box;
@@ -38,7 +39,8 @@ pub struct WorkloadSystem {
     #[doc(hidden)]
     pub(crate) type_id: TypeId,
     pub(crate) display_name: Box<dyn Label>,
-    pub(crate) system_fn: Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>,
+    pub(crate) system_fn:
+        Box<dyn Fn(&World, &SystemLastRun) -> Result<(), error::Run> + Send + Sync + 'static>,
     /// access information
     pub(crate) borrow_constraints: Vec<TypeInfo>,
     pub(crate) tracking_to_enable: Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
@@ -51,6 +53,7 @@ pub struct WorkloadSystem {
     pub(crate) require_in_workload: DedupedLabels,
     pub(crate) require_before: DedupedLabels,
     pub(crate) require_after: DedupedLabels,
+    pub(crate) run_once: bool,
 }
 
 impl Extend<WorkloadSystem> for Workload {
@@ -112,6 +112,13 @@ pub trait SystemModificator<B, R> {
     ///
     /// Does not change system ordering.
     fn require_after<T>(self, other: impl AsLabel<T>) -> WorkloadSystem;
+    /// Only run the system the first time its workload runs.\
+    /// The system counts as run even if it returned an error.
+    ///
+    /// [`World::reset_run_once`] lets it run again.
+    ///
+    /// [`World::reset_run_once`]: crate::World::reset_run_once()
+    fn run_once(self) -> WorkloadSystem;
 }
 
 pub struct Nothing;
@@ -191,6 +198,14 @@ where
 
         system.require_after.add(other);
 
+        system
+    }
+    #[track_caller]
+    fn run_once(self) -> WorkloadSystem {
+        let mut system = self.into_workload_system().unwrap();
+
+        system.run_once = true;
+
         system
     }
 }
@@ -254,6 +269,11 @@ impl SystemModificator<WorkloadSystem, ()> for WorkloadSystem {
     fn require_after<T>(mut self, other: impl AsLabel<T>) -> WorkloadSystem {
         self.require_after.add(other);
 
+        self
+    }
+    fn run_once(mut self) -> WorkloadSystem {
+        self.run_once = true;
+
         self
     }
 }
@@ -341,6 +361,14 @@ macro_rules! impl_into_workload_system {
 
                 system.require_after.add(other);
 
+                system
+            }
+            #[track_caller]
+            fn run_once(self) -> WorkloadSystem {
+                let mut system = IntoWorkloadSystem::<($($type,)+), R>::into_workload_system(self).unwrap();
+
+                system.run_once = true;
+
                 system
             }
         }
@@ -2,28 +2,36 @@ use crate::all_storages::AllStorages;
 use crate::borrow::Mutability;
 use crate::component::{Component, Unique};
 use crate::scheduler::info::{
-    BatchInfo, Conflict, DedupedLabels, SystemId, SystemInfo, TypeInfo, WorkloadInfo,
+    BatchInfo, Conflict, DedupedLabels, RunFrequency, SystemId, SystemInfo, TypeInfo, WorkloadInfo,
 };
 use crate::scheduler::label::{SystemLabel, WorkloadLabel};
 use crate::scheduler::system::{ExtractWorkloadRunIf, WorkloadRunIfFn};
-use crate::scheduler::{AsLabel, Batches, IntoWorkloadTrySystem, Label, Scheduler, WorkloadSystem};
+use crate::scheduler::{
+    AsLabel, Batches, IntoWorkloadTrySystem, Label, RunOnce, Scheduler, WorkloadSystem,
+};
 use crate::storage::StorageId;
+use crate::tracking::SystemLastRun;
 use crate::type_id::TypeId;
 use crate::unique::UniqueStorage;
-use crate::world::World;
+use crate::world::{run_in_worlds, World};
 use crate::{error, IntoWorkload, IntoWorkloadSystem};
 use alloc::boxed::Box;
 use alloc::format;
+use alloc::string::String;
+use alloc::sync::Arc;
 // macro not module
 use alloc::vec;
 use alloc::vec::Vec;
 use core::any::type_name;
 #[cfg(not(feature = "std"))]
 use core::any::Any;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use hashbrown::HashMap;
 #[cfg(feature = "std")]
 use std::error::Error;
 
+static SCHEDULED_WORKLOAD_ID: AtomicU64 = AtomicU64::new(0);
+
 /// Used to create a [`Workload`].
 ///
 /// You can also use [`Workload::new`].
@@ -32,10 +40,14 @@ use std::error::Error;
 /// [`Workload::new`]: crate::Workload::new()
 #[allow(clippy::type_complexity)]
 pub struct ScheduledWorkload {
+    // identifies the workload's last runs in each `World`
+    id: u64,
     name: Box<dyn Label>,
     #[allow(clippy::type_complexity)]
-    systems: Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
+    systems:
+        Vec<Box<dyn Fn(&World, &SystemLastRun) -> Result<(), error::Run> + Send + Sync + 'static>>,
     system_names: Vec<Box<dyn Label>>,
+    system_borrows: Vec<String>,
     #[allow(unused)]
     system_generators: Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
     // system's `TypeId` to an index into both systems and system_names
@@ -58,13 +70,41 @@ impl ScheduledWorkload {
     /// - Storage borrow failed.
     /// - User error returned by system.
     pub fn run_with_world(&self, world: &World) -> Result<(), error::RunWorkload> {
+        let last_runs = world.scheduled_last_runs(self.id, self.systems.len());
+
         world.run_batches(
-            &self.systems,
+            &|index| (self.systems[index])(world, &last_runs[index]),
             &self.system_names,
+            &self.system_borrows,
             &self.workloads[&self.name],
             &self.name,
         )
     }
+    /// Runs the workload in each [`World`] of `worlds`.\
+    /// With the `parallel` feature, the worlds run in parallel on rayon's global thread pool.
+    ///
+    /// The workload isn't rebuilt, systems keep their tracking information separate for each [`World`].\
+    /// The run-once state of the workload and its systems is shared by all worlds,
+    /// as is the tracking information of run-if conditions.
+    ///
+    /// All worlds run even if some of them fail.
+    ///
+    /// ### Borrows
+    ///
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - The error of each [`World`] that failed, alongside its index in `worlds`.
+    pub fn run_with_worlds(&self, worlds: &[World]) -> Result<(), error::RunWorlds> {
+        run_in_worlds(worlds, |world| self.run_with_world(world))
+    }
+    /// Lets the workload run again if it's run-once, as well as its run-once systems.
+    pub fn reset_run_once(&self) {
+        for batches in self.workloads.values() {
+            batches.run_once.reset();
+        }
+    }
 
     /// Apply tracking to all storages using it during this workload.
     ///
@@ -78,6 +118,11 @@ impl ScheduledWorkload {
     /// - [`AllStorages`] borrow failed.
     /// - Storage borrow failed.
     pub fn apply_tracking(&self, world: &World) -> Result<(), error::GetStorage> {
+        #[cfg(feature = "tracing")]
+        let tracking_span = tracing::info_span!("enable_tracking", workload = ?self.name);
+        #[cfg(feature = "tracing")]
+        let _tracking_span = tracking_span.enter();
+
         let all_storages = world
             .all_storages()
             .map_err(error::GetStorage::AllStoragesBorrow)?;
@@ -134,6 +179,8 @@ pub struct Workload {
     pub(super) require_before: DedupedLabels,
     pub(super) require_after: DedupedLabels,
     pub(super) barriers: Vec<usize>,
+    pub(super) run_once: bool,
+    pub(super) startup: bool,
 }
 
 impl Workload {
@@ -192,6 +239,8 @@ impl Workload {
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
             barriers: Vec::new(),
+            run_once: false,
+            startup: false,
         }
     }
     /// Moves all systems of `other` into `Self`, leaving `other` empty.  
@@ -202,7 +251,8 @@ impl Workload {
         self
     }
     /// Propagates all information from `self` and `other` into their respective systems before merging their systems.  
-    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements.
+    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements.  
+    /// A run-once or startup workload makes all its systems run-once.
     pub fn merge(mut self, mut other: Workload) -> Workload {
         self.propagate();
         other.propagate();
@@ -218,7 +268,7 @@ impl Workload {
         self.append(&mut other)
     }
     /// Propagates all information into the systems.  
-    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements.
+    /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements and run-once.
     fn propagate(&mut self) {
         for system in &mut self.systems {
             system.run_if = match (system.run_if.take(), self.run_if.clone()) {
@@ -240,6 +290,7 @@ impl Workload {
             system
                 .require_after
                 .extend(self.require_after.iter().cloned());
+            system.run_once |= self.run_once || self.startup;
         }
 
         self.run_if = None;
@@ -248,6 +299,8 @@ impl Workload {
         self.after_all.clear();
         self.require_before.clear();
         self.require_after.clear();
+        self.run_once = false;
+        self.startup = false;
     }
     /// Adds a system to the workload being created.
     ///
@@ -426,11 +479,17 @@ impl Workload {
     pub fn add_to_world(self, world: &World) -> Result<WorkloadInfo, error::AddWorkload> {
         let Scheduler {
             systems,
+            last_runs,
             system_names,
+            system_borrows,
             system_generators,
             lookup_table,
+            free_systems,
             workloads,
             default,
+            startup,
+            #[cfg(all(feature = "std", feature = "serde1"))]
+            publisher,
         } = &mut *world
             .scheduler
             .borrow_mut()
@@ -441,14 +500,34 @@ impl Workload {
         let workload_info = create_workload(
             self,
             systems,
+            last_runs,
             system_names,
+            system_borrows,
             system_generators,
             lookup_table,
+            free_systems,
             &mut tracking_to_enable,
             workloads,
             default,
         )?;
 
+        if let RunFrequency::Startup { .. } = workload_info.frequency {
+            startup.push(workload_info.name.clone());
+        }
+
+        #[cfg(all(feature = "std", feature = "serde1"))]
+        if let Some(publisher) = publisher {
+            publisher
+                .get_mut()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .publish_workload(&workload_info);
+        }
+
+        #[cfg(feature = "tracing")]
+        let tracking_span = tracing::info_span!("enable_tracking", workload = ?workload_info.name);
+        #[cfg(feature = "tracing")]
+        let _tracking_span = tracking_span.enter();
+
         let all_storages = world
             .all_storages()
             .map_err(|_| error::AddWorkload::TrackingAllStoragesBorrow)?;
@@ -501,9 +580,11 @@ impl Workload {
     /// Build the [`Workload`](super::Workload) from the [`Workload`].
     pub fn build(self) -> Result<(ScheduledWorkload, WorkloadInfo), error::AddWorkload> {
         let mut workload = ScheduledWorkload {
+            id: SCHEDULED_WORKLOAD_ID.fetch_add(1, Ordering::Relaxed),
             name: self.name.clone(),
             systems: Vec::new(),
             system_names: Vec::new(),
+            system_borrows: Vec::new(),
             system_generators: Vec::new(),
             lookup_table: HashMap::new(),
             tracking_to_enable: Vec::new(),
@@ -515,9 +596,12 @@ impl Workload {
         let workload_info = create_workload(
             self,
             &mut workload.systems,
+            &mut Vec::new(),
             &mut workload.system_names,
+            &mut workload.system_borrows,
             &mut workload.system_generators,
             &mut workload.lookup_table,
+            &mut Vec::new(),
             &mut workload.tracking_to_enable,
             &mut workload.workloads,
             &mut default,
@@ -555,10 +639,15 @@ fn check_uniques_in_systems(
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn create_workload(
     mut builder: Workload,
-    systems: &mut Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
+    systems: &mut Vec<
+        Box<dyn Fn(&World, &SystemLastRun) -> Result<(), error::Run> + Send + Sync + 'static>,
+    >,
+    last_runs: &mut Vec<SystemLastRun>,
     system_names: &mut Vec<Box<dyn Label>>,
+    system_borrows: &mut Vec<String>,
     system_generators: &mut Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
     lookup_table: &mut HashMap<TypeId, usize>,
+    free_systems: &mut Vec<usize>,
     tracking_to_enable: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
     workloads: &mut HashMap<Box<dyn Label>, Batches>,
     default: &mut Box<dyn Label>,
@@ -582,28 +671,44 @@ fn create_workload(
     let mut collected_systems: Vec<(usize, WorkloadSystem)> =
         Vec::with_capacity(builder.systems.len());
 
+    let mut run_once_systems = Vec::new();
+
     for mut system in builder.systems.drain(..) {
         for tracking_to_enable_fn in system.tracking_to_enable.drain(..) {
             tracking_to_enable.push(tracking_to_enable_fn);
         }
 
+        if system.run_once {
+            make_run_once(&mut system, &mut run_once_systems);
+        }
+
         insert_system_in_scheduler(
             system,
             systems,
+            last_runs,
             lookup_table,
+            free_systems,
             &mut collected_systems,
             system_generators,
             system_names,
+            system_borrows,
         );
     }
 
-    if workloads.is_empty() {
+    // startup workloads are run by `run_default`, they can't be the default workload
+    if !builder.startup && workloads.values().all(|batches| batches.run_once.startup) {
         *default = builder.name.clone();
     }
 
     let batches = workloads.entry(builder.name.clone()).or_default();
 
     batches.run_if = builder.run_if;
+    batches.run_once = RunOnce {
+        enabled: builder.run_once || builder.startup,
+        startup: builder.startup,
+        has_run: AtomicBool::new(false),
+        systems: run_once_systems,
+    };
 
     if collected_systems.len() == 1 {
         let (
@@ -640,6 +745,7 @@ fn create_workload(
 
         batches.sequential.push(system_index);
         batches.sequential_run_if.push(run_if);
+        batches.requirements.push(Vec::new());
 
         let batch_info = BatchInfo {
             systems: (
@@ -656,12 +762,14 @@ fn create_workload(
         return Ok(WorkloadInfo {
             name: builder.name,
             batch_info: vec![batch_info],
+            frequency: batches.run_once.frequency(),
         });
     }
 
     let mut workload_info = WorkloadInfo {
         name: builder.name,
         batch_info: vec![],
+        frequency: batches.run_once.frequency(),
     };
 
     // // Extract systems that have before/after requirements as they are not scheduled the same way
@@ -864,6 +972,7 @@ fn create_workload(
             display_name,
             borrow_constraints,
             run_if,
+            0,
             &mut seq_system_index_map,
             &mut par_system_index_map,
         );
@@ -901,6 +1010,29 @@ fn create_workload(
         )?;
     }
 
+    batches.requirements = seq_system_index_map
+        .iter()
+        .map(|&index| {
+            let after = &memoize_after[&index];
+
+            seq_system_index_map
+                .iter()
+                .enumerate()
+                .filter(|&(_, &other_index)| {
+                    let other_tags = &collected_tags[other_index];
+                    let other_before = &memoize_before[&other_index];
+
+                    other_index != index
+                        && (after.iter().any(|label| other_tags.contains(label))
+                            || other_before
+                                .iter()
+                                .any(|label| collected_tags[index].contains(label)))
+                })
+                .map(|(position, _)| position)
+                .collect()
+        })
+        .collect();
+
     for (i, &index) in seq_system_index_map.iter().enumerate() {
         let mut require_in_workload = collected_require_in_workload[index].to_vec();
         let mut require_before = collected_before[index].to_vec();
@@ -1000,6 +1132,22 @@ fn create_workload(
     Ok(workload_info)
 }
 
+/// Makes `system` only run once, until its flag is reset.
+pub(super) fn make_run_once(
+    system: &mut WorkloadSystem,
+    run_once_systems: &mut Vec<Arc<AtomicBool>>,
+) {
+    let has_run = Arc::new(AtomicBool::new(false));
+    run_once_systems.push(has_run.clone());
+
+    system.run_if = Some(match system.run_if.take() {
+        Some(run_if) => {
+            Box::new(move |world| Ok((run_if)(world)? && !has_run.swap(true, Ordering::AcqRel)))
+        }
+        None => Box::new(move |_| Ok(!has_run.swap(true, Ordering::AcqRel))),
+    });
+}
+
 #[allow(clippy::needless_range_loop)]
 fn dependencies(
     index: usize,
@@ -1029,7 +1177,7 @@ fn dependencies(
 }
 
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
-fn insert_system(
+pub(super) fn insert_system(
     batches: &mut Batches,
     workload_info: &mut WorkloadInfo,
     index: usize,
@@ -1038,6 +1186,7 @@ fn insert_system(
     display_name: Box<dyn Label>,
     borrow_constraints: Vec<TypeInfo>,
     run_if: Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>,
+    first_batch: usize,
     seq_system_index_map: &mut Vec<usize>,
     par_system_index_map: &mut Vec<(Option<usize>, Vec<usize>)>,
 ) {
@@ -1061,7 +1210,13 @@ fn insert_system(
     }
 
     if let Some(all_storages_type_info) = all_storages {
-        for (i, batch_info) in workload_info.batch_info.iter().enumerate().rev() {
+        for (i, batch_info) in workload_info
+            .batch_info
+            .iter()
+            .enumerate()
+            .skip(first_batch)
+            .rev()
+        {
             match (
                 &batch_info.systems.0,
                 batch_info
@@ -1136,7 +1291,13 @@ fn insert_system(
     } else {
         let mut conflict = None;
 
-        'batch: for (i, batch_info) in workload_info.batch_info.iter().enumerate().rev() {
+        'batch: for (i, batch_info) in workload_info
+            .batch_info
+            .iter()
+            .enumerate()
+            .skip(first_batch)
+            .rev()
+        {
             if let (Some(non_send_sync_type_info), Some(other_system_info)) =
                 (&non_send_sync, &batch_info.systems.0)
             {
@@ -1232,7 +1393,7 @@ fn insert_system(
     }
 }
 
-fn check_conflict(
+pub(super) fn check_conflict(
     other_system: &SystemInfo,
     borrow_constraints: &[TypeInfo],
     conflict: &mut Option<Conflict>,
@@ -1594,27 +1755,71 @@ fn valid_parallel(
 }
 
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
-fn insert_system_in_scheduler(
+pub(super) fn insert_system_in_scheduler(
     mut system: WorkloadSystem,
-    systems: &mut Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync>>,
+    systems: &mut Vec<Box<dyn Fn(&World, &SystemLastRun) -> Result<(), error::Run> + Send + Sync>>,
+    last_runs: &mut Vec<SystemLastRun>,
     lookup_table: &mut HashMap<TypeId, usize>,
+    free_systems: &mut Vec<usize>,
     collected_systems: &mut Vec<(usize, WorkloadSystem)>,
     system_generators: &mut Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync>>,
     system_names: &mut Vec<Box<dyn Label>>,
+    system_borrows: &mut Vec<String>,
 ) {
     let system_index = *lookup_table.entry(system.type_id).or_insert_with(|| {
-        let system_fn = core::mem::replace(&mut system.system_fn, Box::new(|_| Ok(())));
+        let system_fn = core::mem::replace(&mut system.system_fn, Box::new(|_, _| Ok(())));
         let generator = core::mem::replace(&mut system.generator, Box::new(|_| TypeId::of::<()>()));
-
-        systems.push(system_fn);
-        system_names.push(system.display_name.clone());
-        system_generators.push(generator);
-        systems.len() - 1
+        let borrow = system_borrow(&*generator);
+
+        if let Some(system_index) = free_systems.pop() {
+            systems[system_index] = system_fn;
+            last_runs[system_index] = SystemLastRun::default();
+            system_names[system_index] = system.display_name.clone();
+            system_borrows[system_index] = borrow;
+            system_generators[system_index] = generator;
+            system_index
+        } else {
+            systems.push(system_fn);
+            last_runs.push(SystemLastRun::default());
+            system_names.push(system.display_name.clone());
+            system_borrows.push(borrow);
+            system_generators.push(generator);
+            systems.len() - 1
+        }
     });
 
     collected_systems.push((system_index, system));
 }
 
+/// Lists the storages borrowed by a system, recorded by its tracing span.
+#[cfg(feature = "tracing")]
+fn system_borrow(generator: &(dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync)) -> String {
+    let mut type_infos = Vec::new();
+    (generator)(&mut type_infos);
+
+    let mut borrow = String::new();
+    for type_info in &type_infos {
+        if !borrow.is_empty() {
+            borrow.push_str(", ");
+        }
+
+        match type_info.mutability {
+            Mutability::Shared => borrow.push('&'),
+            Mutability::Exclusive => borrow.push_str("&mut "),
+        }
+
+        borrow.push_str(&type_info.name);
+    }
+
+    borrow
+}
+
+/// Without the `tracing` feature borrows aren't listed.
+#[cfg(not(feature = "tracing"))]
+fn system_borrow(_: &(dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync)) -> String {
+    String::new()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1659,7 +1864,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1689,7 +1896,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1721,7 +1930,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1753,7 +1964,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1785,7 +1998,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1809,7 +2024,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1848,7 +2065,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1879,7 +2098,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1902,7 +2123,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1926,7 +2149,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1949,7 +2174,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1988,7 +2215,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2012,7 +2241,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2035,7 +2266,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2058,7 +2291,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2082,7 +2317,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2114,7 +2351,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2139,7 +2378,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2180,7 +2421,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2, 3],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2476,7 +2719,9 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                requirements: Vec::new(),
                 run_if: None,
+                run_once: RunOnce::default(),
             }
         );
     }
@@ -107,6 +107,53 @@ pub trait WorkloadModificator {
     fn rename<T>(self, name: impl AsLabel<T>) -> Workload;
     /// Adds a tag to this workload. Tags can be used to control system ordering when running workloads.
     fn tag<T>(self, tag: impl AsLabel<T>) -> Workload;
+    /// Only run the workload the first time it's called.\
+    /// A workload returning an error can run again.
+    ///
+    /// When nested in another workload, its systems become run-once instead.\
+    /// [`World::reset_run_once`] lets it run again.
+    ///
+    /// [`World::reset_run_once`]: crate::World::reset_run_once()
+    fn run_once(self) -> Workload;
+    /// Makes the workload run-once and runs it before the default workload the first time [`World::run_default`] is called.\
+    /// Startup workloads run in the order they were added and never become the default workload.
+    ///
+    /// It can still be run with [`World::run_workload`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntitiesViewMut, View, ViewMut, Workload, WorkloadModificator, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Tile;
+    ///
+    /// fn spawn_map(mut entities: EntitiesViewMut, mut tiles: ViewMut<Tile>) {
+    ///     entities.add_entity(&mut tiles, Tile);
+    /// }
+    ///
+    /// fn check(tiles: View<Tile>) {
+    ///     assert_eq!(tiles.len(), 1);
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Init")
+    ///     .with_system(spawn_map)
+    ///     .startup()
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    /// Workload::new("Update")
+    ///     .with_system(check)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default().unwrap();
+    /// world.run_default().unwrap();
+    /// ```
+    ///
+    /// [`World::run_default`]: crate::World::run_default()
+    /// [`World::run_workload`]: crate::World::run_workload()
+    fn startup(self) -> Workload;
 }
 
 impl WorkloadModificator for Workload {
@@ -201,6 +248,16 @@ impl WorkloadModificator for Workload {
     fn tag<T>(mut self, tag: impl AsLabel<T>) -> Workload {
         self.tags.push(tag.as_label());
 
+        self
+    }
+    fn run_once(mut self) -> Workload {
+        self.run_once = true;
+
+        self
+    }
+    fn startup(mut self) -> Workload {
+        self.startup = true;
+
         self
     }
 }
@@ -287,4 +344,30 @@ where
 
         workload.tag(tag)
     }
+    fn run_once(self) -> Workload {
+        let mut workload = (self)();
+
+        let label = WorkloadLabel {
+            type_id: TypeId::of::<W>(),
+            name: type_name::<W>().as_label(),
+        };
+
+        workload = workload.tag(label.clone());
+        workload.name = Box::new(label);
+
+        workload.run_once()
+    }
+    fn startup(self) -> Workload {
+        let mut workload = (self)();
+
+        let label = WorkloadLabel {
+            type_id: TypeId::of::<W>(),
+            name: type_name::<W>().as_label(),
+        };
+
+        workload = workload.tag(label.clone());
+        workload.name = Box::new(label);
+
+        workload.startup()
+    }
 }
@@ -0,0 +1,455 @@
+use crate::all_storages::AllStorages;
+use crate::component::{Component, Unique};
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::sparse_set::{repack, SparseArray, SparseSet, BUCKET_SIZE};
+use crate::storage::StorageId;
+use crate::tracking::MAX_TRACK_AGE;
+use crate::unique::UniqueStorage;
+use crate::world::World;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::any::Any;
+use hashbrown::HashSet;
+
+type SaveFn =
+    fn(&mut AllStorages, Option<Box<dyn Any + Send + Sync>>) -> Box<dyn Any + Send + Sync>;
+type RestoreFn = fn(&mut AllStorages, &(dyn Any + Send + Sync), u32);
+
+/// Ring buffer of the last states of a set of storages, used to rollback a `World`.
+///
+/// A snapshot contains [`Entities`] and the storages registered with [`Snapshots::register`] and [`Snapshots::register_unique`],
+/// tracking data included.\
+/// Once `capacity` snapshots are stored, saving a new one replaces the oldest.
+///
+/// ### Tracking
+///
+/// Tracking data is restored with the components.
+/// A component is seen as inserted or modified after a rollback when it was when the snapshot was taken,
+/// deleted and removed components are the ones deleted and removed at that time.\
+/// Components inserted or modified after a rollback are tracked normally.
+///
+/// Storages that aren't registered keep their content,
+/// except for the components of entities that are not alive anymore after the rollback.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, Snapshots, View, World};
+///
+/// #[derive(Component, Clone, Debug, PartialEq)]
+/// struct Position(f32);
+///
+/// let mut world = World::new();
+/// let mut snapshots = Snapshots::new(8);
+/// snapshots.register::<Position>();
+///
+/// let entity = world.add_entity((Position(0.0),));
+/// world.save_snapshot(&mut snapshots);
+///
+/// world.add_component(entity, (Position(1.0),));
+/// world.add_entity((Position(2.0),));
+///
+/// world.rollback(&mut snapshots, 0).unwrap();
+///
+/// world.run(|positions: View<Position>| {
+///     assert_eq!(positions.len(), 1);
+///     assert_eq!(positions[entity], Position(0.0));
+/// });
+/// ```
+pub struct Snapshots {
+    capacity: usize,
+    storages: Vec<SnapshotStorage>,
+    snapshots: VecDeque<Snapshot>,
+}
+
+struct SnapshotStorage {
+    storage_id: StorageId,
+    save: SaveFn,
+    restore: RestoreFn,
+}
+
+struct Snapshot {
+    entities: Vec<EntityId>,
+    list: Option<(usize, usize)>,
+    storages: Vec<Box<dyn Any + Send + Sync>>,
+}
+
+impl Snapshots {
+    /// Creates an empty ring buffer able to hold `capacity` snapshots.
+    ///
+    /// ### Panics
+    ///
+    /// - `capacity` is 0.
+    #[track_caller]
+    pub fn new(capacity: usize) -> Snapshots {
+        assert!(capacity > 0, "Snapshots capacity has to be at least 1.");
+
+        Snapshots {
+            capacity,
+            storages: Vec::new(),
+            snapshots: VecDeque::with_capacity(capacity),
+        }
+    }
+    /// Saves `SparseSet<T>` in the following snapshots.\
+    /// Registering a storage clears all snapshots.
+    pub fn register<T: Send + Sync + Component + Clone>(&mut self) {
+        self.insert(SnapshotStorage {
+            storage_id: StorageId::of::<SparseSet<T>>(),
+            save: save_sparse_set::<T>,
+            restore: restore_sparse_set::<T>,
+        });
+    }
+    /// Saves the unique storage of `T` in the following snapshots.\
+    /// Registering a storage clears all snapshots.
+    pub fn register_unique<T: Send + Sync + Unique + Clone>(&mut self) {
+        self.insert(SnapshotStorage {
+            storage_id: StorageId::of::<UniqueStorage<T>>(),
+            save: save_unique::<T>,
+            restore: restore_unique::<T>,
+        });
+    }
+    fn insert(&mut self, storage: SnapshotStorage) {
+        self.snapshots.clear();
+
+        if !self
+            .storages
+            .iter()
+            .any(|other| other.storage_id == storage.storage_id)
+        {
+            self.storages.push(storage);
+        }
+    }
+    /// Returns the maximum number of snapshots.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// Returns the number of snapshots.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+    /// Returns `true` if there is no snapshot.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+    /// Deletes all snapshots.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
+
+impl core::fmt::Debug for Snapshots {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Snapshots")
+            .field("capacity", &self.capacity)
+            .field("len", &self.snapshots.len())
+            .field(
+                "storages",
+                &self
+                    .storages
+                    .iter()
+                    .map(|storage| storage.storage_id)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl AllStorages {
+    /// Saves the current state of [`Entities`] and the storages registered in `snapshots`.\
+    /// Replaces the oldest snapshot when `snapshots` is full.
+    pub fn save_snapshot(&mut self, snapshots: &mut Snapshots) {
+        // reuse the oldest snapshot's allocations
+        let mut old = if snapshots.snapshots.len() == snapshots.capacity {
+            snapshots.snapshots.pop_front()
+        } else {
+            None
+        };
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let mut data = old
+            .as_mut()
+            .map(|old| core::mem::take(&mut old.entities))
+            .unwrap_or_default();
+        data.clone_from(&entities.data);
+        let list = entities.list;
+
+        let mut old_storages = old.map(|old| old.storages).unwrap_or_default().into_iter();
+        let storages = snapshots
+            .storages
+            .iter()
+            .map(|storage| (storage.save)(self, old_storages.next()))
+            .collect();
+
+        snapshots.snapshots.push_back(Snapshot {
+            entities: data,
+            list,
+            storages,
+        });
+    }
+    /// Restores the state saved `age` snapshots ago, 0 being the most recent snapshot.\
+    /// Newer snapshots are deleted, the restored one is kept.
+    ///
+    /// ### Errors
+    ///
+    /// - There are less than `age + 1` snapshots.
+    pub fn rollback(
+        &mut self,
+        snapshots: &mut Snapshots,
+        age: usize,
+    ) -> Result<(), error::Rollback> {
+        if age >= snapshots.snapshots.len() {
+            return Err(error::Rollback::MissingSnapshot(age));
+        }
+
+        let new_len = snapshots.snapshots.len() - age;
+        snapshots.snapshots.truncate(new_len);
+        let snapshot = snapshots.snapshots.back().unwrap();
+
+        let current = self.get_current_mut();
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let alive = entities.iter().collect::<Vec<_>>();
+        entities.data.clone_from(&snapshot.entities);
+        entities.list = snapshot.list;
+
+        let deleted = alive
+            .into_iter()
+            .filter(|&entity| !entities.is_alive(entity))
+            .collect::<Vec<_>>();
+
+        if !deleted.is_empty() {
+            let registered = snapshots
+                .storages
+                .iter()
+                .map(|storage| storage.storage_id)
+                .collect::<HashSet<_>>();
+
+            for (storage_id, storage) in self.storages.get_mut().iter_mut() {
+                if !registered.contains(storage_id) {
+                    let storage = unsafe { &mut *storage.0 }.get_mut();
+
+                    for &entity in &deleted {
+                        storage.delete(entity, current);
+                    }
+                }
+            }
+        }
+
+        for (storage, saved) in snapshots.storages.iter().zip(&snapshot.storages) {
+            (storage.restore)(self, &**saved, current);
+        }
+
+        for i in 0..self.groups.len() {
+            let members = self.groups[i].clone();
+
+            repack(self, &members);
+        }
+
+        Ok(())
+    }
+}
+
+impl World {
+    /// Saves the current state of [`Entities`] and the storages registered in `snapshots`.\
+    /// Replaces the oldest snapshot when `snapshots` is full.
+    #[inline]
+    pub fn save_snapshot(&mut self, snapshots: &mut Snapshots) {
+        self.all_storages.get_mut().save_snapshot(snapshots);
+    }
+    /// Restores the state saved `age` snapshots ago, 0 being the most recent snapshot.\
+    /// Newer snapshots are deleted, the restored one is kept.
+    ///
+    /// ### Errors
+    ///
+    /// - There are less than `age + 1` snapshots.
+    #[inline]
+    pub fn rollback(
+        &mut self,
+        snapshots: &mut Snapshots,
+        age: usize,
+    ) -> Result<(), error::Rollback> {
+        self.all_storages.get_mut().rollback(snapshots, age)
+    }
+}
+
+struct SparseSetSnapshot<T> {
+    sparse: SparseArray<EntityId, BUCKET_SIZE>,
+    dense: Vec<EntityId>,
+    data: Vec<T>,
+    last_insert: u32,
+    last_modified: u32,
+    insertion_data: Vec<u32>,
+    modification_data: Vec<u32>,
+    deletion_data: Vec<(EntityId, u32, T)>,
+    removal_data: Vec<(EntityId, u32)>,
+}
+
+fn save_sparse_set<T: Send + Sync + Component + Clone>(
+    all_storages: &mut AllStorages,
+    old: Option<Box<dyn Any + Send + Sync>>,
+) -> Box<dyn Any + Send + Sync> {
+    let sparse_set = all_storages
+        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+    match old.and_then(|old| old.downcast::<SparseSetSnapshot<T>>().ok()) {
+        Some(mut snapshot) => {
+            snapshot.sparse.clone_from(&sparse_set.sparse);
+            snapshot.dense.clone_from(&sparse_set.dense);
+            snapshot.data.clone_from(&sparse_set.data);
+            snapshot.last_insert = sparse_set.last_insert;
+            snapshot.last_modified = sparse_set.last_modified;
+            snapshot
+                .insertion_data
+                .clone_from(&sparse_set.insertion_data);
+            snapshot
+                .modification_data
+                .clone_from(&sparse_set.modification_data);
+            snapshot.deletion_data.clone_from(&sparse_set.deletion_data);
+            snapshot.removal_data.clone_from(&sparse_set.removal_data);
+
+            snapshot
+        }
+        None => Box::new(SparseSetSnapshot {
+            sparse: sparse_set.sparse.clone(),
+            dense: sparse_set.dense.clone(),
+            data: sparse_set.data.clone(),
+            last_insert: sparse_set.last_insert,
+            last_modified: sparse_set.last_modified,
+            insertion_data: sparse_set.insertion_data.clone(),
+            modification_data: sparse_set.modification_data.clone(),
+            deletion_data: sparse_set.deletion_data.clone(),
+            removal_data: sparse_set.removal_data.clone(),
+        }),
+    }
+}
+
+fn restore_sparse_set<T: Send + Sync + Component + Clone>(
+    all_storages: &mut AllStorages,
+    snapshot: &(dyn Any + Send + Sync),
+    current: u32,
+) {
+    let snapshot = snapshot.downcast_ref::<SparseSetSnapshot<T>>().unwrap();
+    let sparse_set = all_storages
+        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+    sparse_set.sparse.clone_from(&snapshot.sparse);
+    sparse_set.dense.clone_from(&snapshot.dense);
+    sparse_set.data.clone_from(&snapshot.data);
+    sparse_set.last_insert = snapshot.last_insert;
+    sparse_set.last_modified = snapshot.last_modified;
+
+    // tracking might have been enabled after the snapshot was taken
+    let len = sparse_set.dense.len();
+    restore_timestamps(
+        &mut sparse_set.insertion_data,
+        &snapshot.insertion_data,
+        sparse_set.is_tracking_insertion,
+        len,
+        current,
+    );
+    restore_timestamps(
+        &mut sparse_set.modification_data,
+        &snapshot.modification_data,
+        sparse_set.is_tracking_modification,
+        len,
+        current.wrapping_sub(MAX_TRACK_AGE),
+    );
+    sparse_set.deletion_data.clone_from(&snapshot.deletion_data);
+    sparse_set.removal_data.clone_from(&snapshot.removal_data);
+
+    if let Some(group) = &mut sparse_set.group {
+        group.is_dirty = true;
+    }
+
+    if let Some(index) = &mut sparse_set.index {
+        index.clear();
+        sparse_set.index_from(0);
+    }
+}
+
+fn restore_timestamps(
+    timestamps: &mut Vec<u32>,
+    saved: &[u32],
+    is_tracking: bool,
+    len: usize,
+    default: u32,
+) {
+    timestamps.clear();
+
+    if is_tracking {
+        if saved.len() == len {
+            timestamps.extend_from_slice(saved);
+        } else {
+            timestamps.resize(len, default);
+        }
+    }
+}
+
+fn save_unique<T: Send + Sync + Unique + Clone>(
+    all_storages: &mut AllStorages,
+    old: Option<Box<dyn Any + Send + Sync>>,
+) -> Box<dyn Any + Send + Sync> {
+    let unique = all_storages
+        .exclusive_storage_mut::<UniqueStorage<T>>()
+        .ok()
+        .map(|unique| UniqueStorage {
+            value: unique.value.clone(),
+            insert: unique.insert,
+            modification: unique.modification,
+            last_insert: unique.last_insert,
+            last_modification: unique.last_modification,
+        });
+
+    match old.and_then(|old| old.downcast::<Option<UniqueStorage<T>>>().ok()) {
+        Some(mut snapshot) => {
+            *snapshot = unique;
+
+            snapshot
+        }
+        None => Box::new(unique),
+    }
+}
+
+fn restore_unique<T: Send + Sync + Unique + Clone>(
+    all_storages: &mut AllStorages,
+    snapshot: &(dyn Any + Send + Sync),
+    _current: u32,
+) {
+    let snapshot = snapshot.downcast_ref::<Option<UniqueStorage<T>>>().unwrap();
+
+    match (
+        snapshot,
+        all_storages.exclusive_storage_mut::<UniqueStorage<T>>(),
+    ) {
+        (Some(snapshot), Ok(unique)) => {
+            unique.value.clone_from(&snapshot.value);
+            unique.insert = snapshot.insert;
+            unique.modification = snapshot.modification;
+            unique.last_insert = snapshot.last_insert;
+            unique.last_modification = snapshot.last_modification;
+        }
+        (Some(snapshot), Err(_)) => {
+            all_storages.exclusive_storage_or_insert_mut(
+                StorageId::of::<UniqueStorage<T>>(),
+                || UniqueStorage {
+                    value: snapshot.value.clone(),
+                    insert: snapshot.insert,
+                    modification: snapshot.modification,
+                    last_insert: snapshot.last_insert,
+                    last_modification: snapshot.last_modification,
+                },
+            );
+        }
+        (None, Ok(_)) => {
+            // can't fail, `all_storages` is borrowed exclusively
+            let _ = all_storages.remove_unique::<T>();
+        }
+        (None, Err(_)) => {}
+    }
+}
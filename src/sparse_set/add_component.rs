@@ -1,7 +1,7 @@
 use crate::all_storages::AllStorages;
 use crate::component::Component;
 use crate::entity_id::EntityId;
-use crate::sparse_set::SparseSet;
+use crate::sparse_set::{pack, SparseSet};
 use crate::storage::StorageId;
 #[cfg(doc)]
 use crate::world::World;
@@ -21,9 +21,14 @@ impl<T: Send + Sync + Component> TupleAddComponent for T {
     #[inline]
     #[track_caller]
     fn add_component(self, all_storages: &mut AllStorages, entity: EntityId, current: u32) {
-        all_storages
-            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
-            .insert(entity, self, current);
+        let sparse_set = all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new);
+
+        sparse_set.insert(entity, self, current);
+
+        if let Some(members) = sparse_set.group_members() {
+            pack(all_storages, &members, entity);
+        }
     }
 }
 
@@ -32,9 +37,14 @@ impl<T: Send + Sync + Component> TupleAddComponent for Option<T> {
     #[track_caller]
     fn add_component(self, all_storages: &mut AllStorages, entity: EntityId, current: u32) {
         if let Some(component) = self {
-            all_storages
-                .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
-                .insert(entity, component, current);
+            let sparse_set = all_storages
+                .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new);
+
+            sparse_set.insert(entity, component, current);
+
+            if let Some(members) = sparse_set.group_members() {
+                pack(all_storages, &members, entity);
+            }
         }
     }
 }
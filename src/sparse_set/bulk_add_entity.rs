@@ -4,6 +4,7 @@ use crate::entities::Entities;
 use crate::entity_id::EntityId;
 use crate::reserve::BulkEntityIter;
 use crate::sparse_set::SparseSet;
+use crate::tracking::MAX_TRACK_AGE;
 #[cfg(doc)]
 use crate::world::World;
 use core::iter::IntoIterator;
@@ -63,7 +64,7 @@ impl<T: Send + Sync + Component> BulkInsert for T {
         iter: I,
     ) -> BulkEntityIter<'_> {
         let iter = iter.into_iter();
-        let current = all_storages.get_current();
+        let current = all_storages.get_current_mut();
         let mut entities = all_storages.entities_mut().unwrap();
         let mut sparse_set = all_storages
             .custom_storage_or_insert_mut(SparseSet::<T>::new)
@@ -88,9 +89,11 @@ impl<T: Send + Sync + Component> BulkInsert for T {
                 .extend(new_entities.iter().map(|_| current));
         }
         if sparse_set.is_tracking_modification() {
-            sparse_set
-                .modification_data
-                .extend(new_entities.iter().map(|_| 0));
+            sparse_set.modification_data.extend(
+                new_entities
+                    .iter()
+                    .map(|_| current.wrapping_sub(MAX_TRACK_AGE)),
+            );
         }
 
         let SparseSet { sparse, dense, .. } = &mut *sparse_set;
@@ -103,6 +106,8 @@ impl<T: Send + Sync + Component> BulkInsert for T {
             }
         }
 
+        sparse_set.index_from(old_len);
+
         drop((entities, sparse_set));
 
         let entities = all_storages.exclusive_storage_mut::<Entities>().unwrap();
@@ -130,6 +135,7 @@ macro_rules! impl_bulk_insert {
             fn bulk_insert<Source: IntoIterator<Item = Self>>(all_storages: &mut AllStorages, iter: Source) -> BulkEntityIter<'_> {
                 let iter = iter.into_iter();
                 let size_hint = iter.size_hint().0;
+                let current = all_storages.get_current_mut();
                 let mut entities = all_storages.entities_mut().unwrap();
                 let mut $sparse_set1 = all_storages.custom_storage_or_insert_mut(SparseSet::<$type1>::new).unwrap();
                 $(
@@ -161,14 +167,14 @@ macro_rules! impl_bulk_insert {
                     $sparse_set1.insertion_data.extend(new_entities.iter().map(|_| 0));
                 }
                 if $sparse_set1.is_tracking_modification() {
-                    $sparse_set1.modification_data.extend(new_entities.iter().map(|_| 0));
+                    $sparse_set1.modification_data.extend(new_entities.iter().map(|_| current.wrapping_sub(MAX_TRACK_AGE)));
                 }
                 $(
                     if $sparse_set.is_tracking_insertion() {
                         $sparse_set.insertion_data.extend(new_entities.iter().map(|_| 0));
                     }
                     if $sparse_set.is_tracking_modification() {
-                        $sparse_set.modification_data.extend(new_entities.iter().map(|_| 0));
+                        $sparse_set.modification_data.extend(new_entities.iter().map(|_| current.wrapping_sub(MAX_TRACK_AGE)));
                     }
                 )*
 
@@ -181,6 +187,7 @@ macro_rules! impl_bulk_insert {
                         *sparse.get_mut_unchecked(entity) = EntityId::new((old_len + i) as u64);
                     }
                 }
+                $sparse_set1.index_from(old_len);
                 $(
                     let old_len = $sparse_set.dense.len() - new_entities_count;
                     let SparseSet { sparse, dense, .. } = &mut *$sparse_set;
@@ -191,10 +198,19 @@ macro_rules! impl_bulk_insert {
                             *sparse.get_mut_unchecked(entity) = EntityId::new((old_len + i) as u64);
                         }
                     }
+                    $sparse_set.index_from(old_len);
                 )*
 
                 drop((entities, $sparse_set1, $($sparse_set),*));
 
+                if !all_storages.groups.is_empty() {
+                    let new_entities = all_storages.exclusive_storage_mut::<Entities>().unwrap().data[entities_len..].to_vec();
+
+                    for entity in new_entities {
+                        all_storages.pack_entity(entity);
+                    }
+                }
+
                 let entities = all_storages.exclusive_storage_mut::<Entities>().unwrap();
 
                 BulkEntityIter {
@@ -1,7 +1,7 @@
 use crate::all_storages::AllStorages;
 use crate::component::Component;
 use crate::entity_id::EntityId;
-use crate::sparse_set::SparseSet;
+use crate::sparse_set::{unpack_component, SparseSet};
 use crate::storage::StorageId;
 #[cfg(doc)]
 use crate::world::World;
@@ -15,7 +15,9 @@ pub trait TupleDelete {
 impl<T: Send + Sync + Component> TupleDelete for T {
     #[inline]
     fn delete(all_storages: &mut AllStorages, entity: EntityId) -> bool {
-        let current = all_storages.get_current();
+        let current = all_storages.get_current_mut();
+
+        unpack_component::<T>(all_storages, entity);
 
         all_storages
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
@@ -27,7 +29,11 @@ macro_rules! impl_delete_component {
     ($(($type: ident, $index: tt))+) => {
         impl<$($type: Send + Sync + Component,)+> TupleDelete for ($($type,)+) {
             fn delete(all_storages: &mut AllStorages, entity: EntityId) -> bool {
-                let current = all_storages.get_current();
+                let current = all_storages.get_current_mut();
+
+                $(
+                    unpack_component::<$type>(all_storages, entity);
+                )+
 
                 $(
                     all_storages
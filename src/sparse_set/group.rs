@@ -0,0 +1,386 @@
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+#[cfg(doc)]
+use crate::world::World;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::type_name;
+
+/// Group information stored in each storage owned by the group.
+///
+/// Changes made through views are cheap to catch up with, only the entities they add or remove are visited.\
+/// Sorting, clearing or restoring a snapshot of a grouped storage flags the group as dirty,
+/// the next `AllStorages` operation touching it then repacks all its entities.
+// The first `len` elements of all storages of a group belong to the same entities, in the same order.
+// These entities are the ones that have a component in all storages of the group.
+// Only `AllStorages` has access to all storages of a group at once.
+// Views record the entities they add or remove, a removal also moves the entity out of the packed part of its storage.
+// The next `AllStorages` operation touching the group applies these changes to the other storages.
+pub(crate) struct Group {
+    pub(crate) members: Arc<[GroupMember]>,
+    pub(crate) len: usize,
+    pub(crate) is_dirty: bool,
+    /// Entities added or removed through a view since the group was last up to date.
+    pub(crate) pending: Vec<EntityId>,
+    /// Packed positions emptied by removals through a view, storages can disagree on the entity at these positions.
+    pub(crate) swapped: Vec<usize>,
+}
+
+impl Group {
+    /// Returns the position of the storage in the group.
+    fn position(&self, storage_id: StorageId) -> usize {
+        self.members
+            .iter()
+            .position(|member| member.storage_id == storage_id)
+            .unwrap()
+    }
+}
+
+/// Type erased access to a storage owned by a group.
+#[derive(Clone, Copy)]
+pub(crate) struct GroupMember {
+    pub(crate) storage_id: StorageId,
+    name: &'static str,
+    storage: fn(&mut AllStorages) -> &mut dyn GroupStorage,
+}
+
+impl GroupMember {
+    fn new<T: Send + Sync + Component>() -> GroupMember {
+        GroupMember {
+            storage_id: StorageId::of::<SparseSet<T>>(),
+            name: type_name::<SparseSet<T>>(),
+            storage: group_storage::<T>,
+        }
+    }
+}
+
+fn group_storage<T: Send + Sync + Component>(
+    all_storages: &mut AllStorages,
+) -> &mut dyn GroupStorage {
+    all_storages
+        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+}
+
+trait GroupStorage {
+    fn group(&mut self) -> &mut Option<Group>;
+    fn index_of(&self, entity: EntityId) -> Option<usize>;
+    fn dense(&self) -> &[EntityId];
+    fn swap_index(&mut self, a: usize, b: usize);
+}
+
+impl<T: Component> GroupStorage for SparseSet<T> {
+    #[inline]
+    fn group(&mut self) -> &mut Option<Group> {
+        &mut self.group
+    }
+    #[inline]
+    fn index_of(&self, entity: EntityId) -> Option<usize> {
+        SparseSet::index_of(self, entity)
+    }
+    #[inline]
+    fn dense(&self) -> &[EntityId] {
+        &self.dense
+    }
+    #[inline]
+    fn swap_index(&mut self, a: usize, b: usize) {
+        SparseSet::swap_index(self, a, b);
+    }
+}
+
+/// State of a group as seen by one of its storages, used to pick the iteration strategy.
+#[doc(hidden)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct GroupState {
+    id: *const (),
+    member_count: usize,
+    position: usize,
+    len: usize,
+}
+
+impl<T: Component> SparseSet<T> {
+    /// Returns the state of the group owning this storage.\
+    /// Returns `None` if the storage isn't part of a group or if the group has to be repacked.
+    pub(crate) fn group_state(&self) -> Option<GroupState> {
+        match &self.group {
+            Some(group) if !group.is_dirty && group.pending.is_empty() => Some(GroupState {
+                id: Arc::as_ptr(&group.members) as *const (),
+                member_count: group.members.len(),
+                position: group.position(StorageId::of::<SparseSet<T>>()),
+                len: group.len,
+            }),
+            _ => None,
+        }
+    }
+    /// Returns the members of the group owning this storage.
+    pub(crate) fn group_members(&self) -> Option<Arc<[GroupMember]>> {
+        self.group.as_ref().map(|group| group.members.clone())
+    }
+    /// Records `entity` to be packed by the next `AllStorages` operation if it isn't packed.\
+    /// Used by views, they can't move `entity` in the other storages of the group.
+    #[inline]
+    pub(crate) fn invalidate_group(&mut self, entity: EntityId) {
+        if self.group.is_some() {
+            let index = self.index_of(entity);
+
+            if let (Some(group), Some(index)) = (&mut self.group, index) {
+                if index >= group.len {
+                    group.pending.push(entity);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the mask of the storages to iterate using the group, the position in the tuple of one of them and the group's length.\
+/// Returns `None` if the group isn't packed or if not all storages of the group are present.
+pub(crate) fn group_iteration(groups: &[Option<GroupState>]) -> Option<(u16, usize, usize)> {
+    let mut found: Option<GroupState> = None;
+    let mut first = 0;
+    let mut mask: u16 = 0;
+    let mut positions: u16 = 0;
+
+    for (index, state) in groups.iter().enumerate() {
+        if let Some(state) = state {
+            match found {
+                None => {
+                    found = Some(*state);
+                    first = index;
+                    mask = 1 << index;
+                    positions = 1 << state.position;
+                }
+                Some(group) if group.id == state.id => {
+                    if group.len != state.len {
+                        return None;
+                    }
+
+                    mask |= 1 << index;
+                    positions |= 1 << state.position;
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    let group = found?;
+
+    if positions.count_ones() as usize == group.member_count {
+        Some((mask, first, group.len))
+    } else {
+        None
+    }
+}
+
+/// Trait used as bound for [`World::add_group`] and [`AllStorages::add_group`].
+pub trait TupleGroup {
+    /// See [`World::add_group`] and [`AllStorages::add_group`].
+    fn add_group(all_storages: &mut AllStorages) -> Result<(), error::AddGroup>;
+}
+
+macro_rules! impl_group {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: Send + Sync + Component,)+> TupleGroup for ($($type,)+) {
+            fn add_group(all_storages: &mut AllStorages) -> Result<(), error::AddGroup> {
+                register(all_storages, [$(GroupMember::new::<$type>(),)+].to_vec())
+            }
+        }
+    }
+}
+
+macro_rules! group {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_group![$(($type, $index))*];
+        group![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_group![$(($type, $index))*];
+    }
+}
+
+group![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+
+fn register(
+    all_storages: &mut AllStorages,
+    members: Vec<GroupMember>,
+) -> Result<(), error::AddGroup> {
+    for (i, member) in members.iter().enumerate() {
+        if members[..i]
+            .iter()
+            .any(|other| other.storage_id == member.storage_id)
+        {
+            return Err(error::AddGroup::DuplicateStorage(member.name));
+        }
+
+        if (member.storage)(all_storages).group().is_some() {
+            return Err(error::AddGroup::AlreadyGrouped(member.name));
+        }
+    }
+
+    let members: Arc<[GroupMember]> = members.into();
+
+    for member in members.iter() {
+        *(member.storage)(all_storages).group() = Some(Group {
+            members: members.clone(),
+            len: 0,
+            is_dirty: false,
+            pending: Vec::new(),
+            swapped: Vec::new(),
+        });
+    }
+
+    repack(all_storages, &members);
+    all_storages.groups.push(members);
+
+    Ok(())
+}
+
+/// Applies the changes made through views to all storages of the group.\
+/// Repacks the group from scratch if it's dirty.
+fn refresh(all_storages: &mut AllStorages, members: &[GroupMember]) {
+    let mut is_dirty = false;
+    let mut pending = Vec::new();
+    let mut swapped = Vec::new();
+
+    for member in members {
+        let group = (member.storage)(all_storages).group().as_mut().unwrap();
+
+        is_dirty |= group.is_dirty;
+        pending.append(&mut group.pending);
+        swapped.append(&mut group.swapped);
+    }
+
+    if is_dirty {
+        repack(all_storages, members);
+        return;
+    }
+
+    if pending.is_empty() {
+        return;
+    }
+
+    // entities removed through a view only left the packed part of the storage they were removed from
+    for member in members {
+        let storage = (member.storage)(all_storages);
+
+        for &entity in &pending {
+            let len = storage.group().as_ref().unwrap().len;
+
+            if let Some(index) = storage.index_of(entity).filter(|&index| index < len) {
+                storage.swap_index(index, len - 1);
+                storage.group().as_mut().unwrap().len -= 1;
+            }
+        }
+    }
+
+    // all storages pack the same entities but not necessarily at the positions emptied by views
+    let len = (members[0].storage)(all_storages)
+        .group()
+        .as_ref()
+        .unwrap()
+        .len;
+    for position in swapped {
+        if position >= len {
+            continue;
+        }
+
+        let entity = (members[0].storage)(all_storages).dense()[position];
+
+        for member in &members[1..] {
+            let storage = (member.storage)(all_storages);
+            let index = storage.index_of(entity).unwrap();
+
+            storage.swap_index(index, position);
+        }
+    }
+
+    for entity in pending {
+        pack_unchecked(all_storages, members, entity);
+    }
+}
+
+/// Packs all entities present in all storages of the group from scratch.
+pub(crate) fn repack(all_storages: &mut AllStorages, members: &[GroupMember]) {
+    for member in members {
+        let group = (member.storage)(all_storages).group().as_mut().unwrap();
+        group.len = 0;
+        group.is_dirty = false;
+        group.pending.clear();
+        group.swapped.clear();
+    }
+
+    let entities = (members[0].storage)(all_storages).dense().to_vec();
+
+    for entity in entities {
+        pack_unchecked(all_storages, members, entity);
+    }
+}
+
+/// Moves `entity` in the packed part of all storages of the group, if it has a component in each of them.
+pub(crate) fn pack(all_storages: &mut AllStorages, members: &[GroupMember], entity: EntityId) {
+    refresh(all_storages, members);
+    pack_unchecked(all_storages, members, entity);
+}
+
+fn pack_unchecked(all_storages: &mut AllStorages, members: &[GroupMember], entity: EntityId) {
+    let first = (members[0].storage)(all_storages);
+    let len = first.group().as_ref().unwrap().len;
+
+    match first.index_of(entity) {
+        Some(index) if index >= len => {}
+        _ => return,
+    }
+
+    for member in &members[1..] {
+        if (member.storage)(all_storages).index_of(entity).is_none() {
+            return;
+        }
+    }
+
+    for member in members {
+        let storage = (member.storage)(all_storages);
+        let index = storage.index_of(entity).unwrap();
+
+        storage.swap_index(index, len);
+        storage.group().as_mut().unwrap().len += 1;
+    }
+}
+
+/// Moves `entity` out of the packed part of all storages of the group.\
+/// Has to be called before removing one of `entity`'s components from a storage of the group.
+pub(crate) fn unpack(all_storages: &mut AllStorages, members: &[GroupMember], entity: EntityId) {
+    refresh(all_storages, members);
+
+    let first = (members[0].storage)(all_storages);
+    let len = first.group().as_ref().unwrap().len;
+
+    match first.index_of(entity) {
+        Some(index) if index < len => {}
+        _ => return,
+    }
+
+    for member in members {
+        let storage = (member.storage)(all_storages);
+        let index = storage.index_of(entity).unwrap();
+
+        storage.swap_index(index, len - 1);
+        storage.group().as_mut().unwrap().len -= 1;
+    }
+}
+
+/// Moves `entity` out of the packed part of `T`'s group, if `T` is part of one.
+pub(crate) fn unpack_component<T: Send + Sync + Component>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+) {
+    let members = all_storages
+        .exclusive_storage_mut::<SparseSet<T>>()
+        .ok()
+        .and_then(|sparse_set| sparse_set.group_members());
+
+    if let Some(members) = members {
+        unpack(all_storages, &members, entity);
+    }
+}
@@ -0,0 +1,335 @@
+use crate::component::{Component, Indexed};
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::sparse_set::SparseSet;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::{type_name, Any};
+use core::hash::Hash;
+use core::marker::PhantomData;
+use hashbrown::{HashMap, HashSet};
+
+/// Type erased index stored in the `SparseSet` of an [`Indexed`] component.
+pub(crate) trait ComponentIndex<T: Component>: Send + Sync {
+    /// Indexes `component`, replacing the component previously indexed in `entity`'s slot.
+    ///
+    /// ### Errors
+    ///
+    /// - In a unique index, the key is already associated with another entity. The index is left untouched.
+    fn insert(&mut self, entity: EntityId, component: &T) -> Result<(), error::DuplicateKey>;
+    fn remove(&mut self, entity: EntityId);
+    fn clear(&mut self);
+    /// Marks `entity`'s component as possibly modified.
+    fn mark_dirty(&mut self, entity: EntityId);
+    /// Marks all components as possibly modified.
+    fn mark_all_dirty(&mut self);
+    /// Reindexes the components marked dirty.\
+    /// Components with a key already associated with another entity in a unique index are left out of the index.
+    fn reindex(&mut self, sparse_set: &SparseSet<T>) -> Result<(), error::DuplicateKey>;
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Value to entities index.
+// `keys` remembers the key each entity slot was indexed with,
+// the component might have been modified since then so its current key can't be used.
+pub(crate) struct KeyIndex<K> {
+    is_unique: bool,
+    entities: HashMap<K, Vec<EntityId>>,
+    keys: HashMap<u64, K>,
+    dirty: HashSet<EntityId>,
+    all_dirty: bool,
+}
+
+impl<K> KeyIndex<K> {
+    fn new(is_unique: bool) -> Self {
+        KeyIndex {
+            is_unique,
+            entities: HashMap::new(),
+            keys: HashMap::new(),
+            dirty: HashSet::new(),
+            all_dirty: false,
+        }
+    }
+}
+
+impl<K: Hash + Eq> KeyIndex<K> {
+    fn remove_entity(&mut self, entity: EntityId) {
+        if let Some(key) = self.keys.remove(&entity.index()) {
+            if let Some(entities) = self.entities.get_mut(&key) {
+                entities.retain(|&other| other.index() != entity.index());
+
+                if entities.is_empty() {
+                    self.entities.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyIndex<K> {
+    /// Returns the entity already associated with `key` if the index is unique.\
+    /// The index is only modified when the insertion succeeds.
+    fn try_insert(&mut self, entity: EntityId, key: K) -> Result<(), EntityId> {
+        if self.is_unique {
+            if let Some(&other) = self
+                .entities
+                .get(&key)
+                .and_then(|entities| entities.first())
+            {
+                if other.index() != entity.index() {
+                    return Err(other);
+                }
+            }
+        }
+
+        self.remove_entity(entity);
+
+        self.entities.entry(key.clone()).or_default().push(entity);
+        self.keys.insert(entity.index(), key);
+
+        Ok(())
+    }
+}
+
+impl<T: Indexed> ComponentIndex<T> for KeyIndex<T::Key> {
+    fn insert(&mut self, entity: EntityId, component: &T) -> Result<(), error::DuplicateKey> {
+        self.try_insert(entity, component.key())
+            .map_err(|other| error::DuplicateKey {
+                entity,
+                other,
+                name: type_name::<T>(),
+            })
+    }
+    fn remove(&mut self, entity: EntityId) {
+        self.remove_entity(entity);
+        self.dirty.remove(&entity);
+    }
+    fn clear(&mut self) {
+        self.entities.clear();
+        self.keys.clear();
+        self.dirty.clear();
+        self.all_dirty = false;
+    }
+    fn mark_dirty(&mut self, entity: EntityId) {
+        if !self.all_dirty {
+            self.dirty.insert(entity);
+        }
+    }
+    fn mark_all_dirty(&mut self) {
+        self.all_dirty = true;
+        self.dirty.clear();
+    }
+    fn reindex(&mut self, sparse_set: &SparseSet<T>) -> Result<(), error::DuplicateKey> {
+        // dirty components are reindexed in storage order to always keep the same entity for a key
+        let dirty: Vec<usize> = if self.all_dirty {
+            self.entities.clear();
+            self.keys.clear();
+            self.all_dirty = false;
+
+            (0..sparse_set.dense.len()).collect()
+        } else {
+            let mut dirty = self
+                .dirty
+                .drain()
+                .filter_map(|entity| sparse_set.index_of(entity))
+                .collect::<Vec<_>>();
+            dirty.sort_unstable();
+
+            for &index in &dirty {
+                self.remove_entity(sparse_set.dense[index]);
+            }
+
+            dirty
+        };
+
+        let mut result = Ok(());
+        for index in dirty {
+            let entity = sparse_set.dense[index];
+
+            if let Err(other) = self.try_insert(entity, sparse_set.data[index].key()) {
+                if result.is_ok() {
+                    result = Err(error::DuplicateKey {
+                        entity,
+                        other,
+                        name: type_name::<T>(),
+                    });
+                }
+            }
+        }
+
+        result
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<T: Component> SparseSet<T> {
+    /// Marks `entity`'s component to be reindexed by the next [`SparseSet::reindex`] or when the mutable borrow ends.
+    #[inline]
+    pub(crate) fn mark_index_dirty(&mut self, entity: EntityId) {
+        if let Some(index) = &mut self.index {
+            index.mark_dirty(entity);
+        }
+    }
+    /// Marks all components to be reindexed by the next [`SparseSet::reindex`] or when the mutable borrow ends.
+    #[inline]
+    pub(crate) fn mark_index_all_dirty(&mut self) {
+        if let Some(index) = &mut self.index {
+            index.mark_all_dirty();
+        }
+    }
+    /// Indexes the components starting at `start` in `dense`.
+    ///
+    /// ### Panics
+    ///
+    /// - In a unique index, one of the components has the same key as another entity's component.
+    #[track_caller]
+    pub(crate) fn index_from(&mut self, start: usize) {
+        if let Some(index) = &mut self.index {
+            for (&entity, component) in self.dense[start..].iter().zip(&self.data[start..]) {
+                if let Err(err) = index.insert(entity, component) {
+                    panic!("{:?}", err);
+                }
+            }
+        }
+    }
+    /// Reindexes the components modified during a mutable borrow that is ending.\
+    /// Nothing is reindexed if the thread is already panicking.
+    ///
+    /// ### Panics
+    ///
+    /// - In a unique index, a modified component has the same key as another entity's component.
+    pub(crate) fn reindex_on_borrow_end(&mut self) {
+        #[cfg(feature = "std")]
+        if std::thread::panicking() {
+            return;
+        }
+
+        if let Err(err) = self.reindex() {
+            panic!("{:?}", err);
+        }
+    }
+    /// Updates the index with the components mutably accessed since the last reindex.\
+    /// Does nothing if the storage isn't indexed.
+    ///
+    /// Components are reindexed when they are added, removed or deleted.
+    /// When they are modified, for example through [`ViewMut`] or [`World::get`], the index is updated when the mutable borrow ends.\
+    /// This method updates it sooner and reports duplicate keys instead of panicking.\
+    /// Accessing a component mutably marks it for reindexing, iterating the storage mutably marks the whole storage.
+    ///
+    /// ### Errors
+    ///
+    /// - In a unique index, a modified component has the same key as another entity's component.\
+    ///   The other entity keeps the key and the modified component is left out of the index.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, Indexed, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Cell(i32, i32);
+    ///
+    /// impl Indexed for Cell {
+    ///     type Key = (i32, i32);
+    ///
+    ///     fn key(&self) -> (i32, i32) {
+    ///         (self.0, self.1)
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_index::<Cell>();
+    ///
+    /// let entity = world.add_entity((Cell(0, 0),));
+    ///
+    /// world.run(|mut cells: ViewMut<Cell>| {
+    ///     cells[entity].1 = 1;
+    ///     assert_eq!(cells.find(&(0, 0)), &[entity]);
+    ///
+    ///     cells.reindex().unwrap();
+    ///     assert_eq!(cells.find(&(0, 1)), &[entity]);
+    /// });
+    /// ```
+    ///
+    /// [`ViewMut`]: crate::ViewMut
+    /// [`World::get`]: crate::World::get()
+    pub fn reindex(&mut self) -> Result<(), error::DuplicateKey> {
+        if let Some(mut index) = self.index.take() {
+            let result = index.reindex(self);
+            self.index = Some(index);
+
+            result
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: Indexed> SparseSet<T> {
+    /// Indexes this storage using [`Indexed::key`].
+    ///
+    /// If `is_unique` is `true`, a key can only be associated with a single entity.
+    pub(crate) fn index(&mut self, is_unique: bool) -> Result<(), error::DuplicateKey> {
+        let mut index: Box<dyn ComponentIndex<T>> = Box::new(KeyIndex::<T::Key>::new(is_unique));
+
+        index.mark_all_dirty();
+        index.reindex(self)?;
+
+        self.index = Some(index);
+
+        Ok(())
+    }
+    /// Returns the entities whose component has `key`.\
+    /// Returns an empty slice if the storage isn't indexed.
+    ///
+    /// Components modified through a mutable borrow still in use are only found with their new key after [`SparseSet::reindex`].
+    pub fn find(&self, key: &T::Key) -> &[EntityId] {
+        self.index
+            .as_ref()
+            .and_then(|index| index.as_any().downcast_ref::<KeyIndex<T::Key>>())
+            .and_then(|index| index.entities.get(key))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Reindexes a storage when the mutable borrow giving access to its components ends.
+pub struct ReindexOnDrop<'a> {
+    sparse_set: *mut (),
+    reindex: unsafe fn(*mut ()),
+    _phantom: PhantomData<&'a mut ()>,
+}
+
+// SAFE the storage is exclusively borrowed for 'a
+unsafe impl Send for ReindexOnDrop<'_> {}
+unsafe impl Sync for ReindexOnDrop<'_> {}
+
+impl<'a> ReindexOnDrop<'a> {
+    /// Returns `None` if the storage isn't indexed.
+    ///
+    /// # Safety
+    ///
+    /// `sparse_set` has to stay exclusively borrowed for `'a`.
+    pub(crate) unsafe fn new<T: Component>(sparse_set: &mut SparseSet<T>) -> Option<Self> {
+        unsafe fn reindex<T: Component>(sparse_set: *mut ()) {
+            (*sparse_set.cast::<SparseSet<T>>()).reindex_on_borrow_end();
+        }
+
+        let is_indexed = sparse_set.index.is_some();
+        let sparse_set: *mut SparseSet<T> = sparse_set;
+
+        is_indexed.then(|| ReindexOnDrop {
+            sparse_set: sparse_set.cast(),
+            reindex: reindex::<T>,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl Drop for ReindexOnDrop<'_> {
+    fn drop(&mut self) {
+        // SAFE the storage is still borrowed and the components accessed through it are no longer in use
+        unsafe { (self.reindex)(self.sparse_set) }
+    }
+}
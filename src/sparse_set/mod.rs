@@ -2,6 +2,8 @@ mod add_component;
 mod bulk_add_entity;
 mod delete;
 mod drain;
+mod group;
+mod index;
 mod remove;
 mod sparse_array;
 mod window;
@@ -10,23 +12,37 @@ pub use add_component::TupleAddComponent;
 pub use bulk_add_entity::BulkAddEntity;
 pub use delete::TupleDelete;
 pub use drain::SparseSetDrain;
+pub use group::TupleGroup;
 pub use remove::TupleRemove;
 pub use sparse_array::SparseArray;
 
+pub(crate) use group::{
+    group_iteration, pack, repack, unpack, unpack_component, Group, GroupMember, GroupState,
+};
+pub(crate) use index::{ComponentIndex, ReindexOnDrop};
 pub(crate) use window::{FullRawWindow, FullRawWindowMut};
 
 use crate::all_storages::AllStorages;
 #[cfg(feature = "thread_local")]
 use crate::borrow::{NonSend, NonSendSync, NonSync};
+use crate::checksum::ChecksumHasher;
 use crate::component::Component;
 use crate::entity_id::EntityId;
-use crate::memory_usage::StorageMemoryUsage;
+use crate::map_entities::{EntityMap, MapEntities};
+use crate::memory_usage::{
+    MemorySection, StorageKind, StorageMemoryBreakdown, StorageMemoryReport, StorageMemoryUsage,
+};
 use crate::storage::{Storage, StorageId};
 use crate::tracking::Tracking;
-use crate::tracking::{is_track_within_bounds, TrackingTimestamp};
+use crate::tracking::{is_track_within_bounds, rebased, TrackingTimestamp, MAX_TRACK_AGE};
 use crate::{error, track};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
 use core::any::type_name;
+use core::hash::{Hash, Hasher};
+use core::mem::size_of;
 use core::{
     cmp::{Ord, Ordering},
     fmt,
@@ -57,6 +73,8 @@ pub struct SparseSet<T: Component> {
     pub(crate) is_tracking_modification: bool,
     pub(crate) is_tracking_deletion: bool,
     pub(crate) is_tracking_removal: bool,
+    pub(crate) group: Option<Group>,
+    pub(crate) index: Option<Box<dyn ComponentIndex<T>>>,
 }
 
 impl<T: fmt::Debug + Component> fmt::Debug for SparseSet<T> {
@@ -84,6 +102,8 @@ impl<T: Component> SparseSet<T> {
             is_tracking_modification: false,
             is_tracking_deletion: false,
             is_tracking_removal: false,
+            group: None,
+            index: None,
         }
     }
     /// Returns a new [`SparseSet`] to be used in custom storage.
@@ -98,6 +118,34 @@ impl<T: Component> SparseSet<T> {
     }
 }
 
+impl<T: MapEntities> SparseSet<T> {
+    /// Rewrites the [`EntityId`]s stored in the components.
+    pub(crate) fn map_entities(&mut self, entity_map: &EntityMap) {
+        for component in &mut self.data {
+            component.map_entities(entity_map);
+        }
+
+        self.mark_index_all_dirty();
+    }
+}
+
+impl<T: Hash + Component> SparseSet<T> {
+    /// Hashes the components in `EntityId` order, `dense` order depends on the insertion and deletion history.
+    pub(crate) fn checksum(&self) -> u64 {
+        let mut order = (0..self.dense.len()).collect::<Vec<_>>();
+        order.sort_unstable_by_key(|&i| self.dense[i].index());
+
+        let mut hasher = ChecksumHasher::new();
+        hasher.write_usize(order.len());
+        for i in order {
+            hasher.write_u64(self.dense[i].inner());
+            self.data[i].hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+}
+
 impl<T: Component> SparseSet<T> {
     /// Returns `true` if `entity` owns a component in this storage.
     #[inline]
@@ -159,8 +207,28 @@ impl<T: Component> SparseSet<T> {
     ///
     /// In case `entity` had a component of this type, the new component will be considered `modified`.  
     /// In all other cases it'll be considered `inserted`.
+    ///
+    /// ### Panics
+    ///
+    /// - In a unique index, `value` has the same key as another entity's component.
     #[track_caller]
     pub(crate) fn insert(&mut self, entity: EntityId, value: T, current: u32) -> Option<T> {
+        match self.try_insert(entity, value, current) {
+            Ok(old_component) => old_component,
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+    /// Inserts `value` in the `SparseSet`.
+    ///
+    /// ### Errors
+    ///
+    /// - In a unique index, `value` has the same key as another entity's component. The storage is left untouched.
+    pub(crate) fn try_insert(
+        &mut self,
+        entity: EntityId,
+        value: T,
+        current: u32,
+    ) -> Result<Option<T>, error::DuplicateKey> {
         self.sparse.allocate_at(entity);
 
         // at this point there can't be nothing at the sparse index
@@ -169,6 +237,10 @@ impl<T: Component> SparseSet<T> {
         let old_component;
 
         if sparse_entity.is_dead() {
+            if let Some(index) = &mut self.index {
+                index.insert(entity, &value)?;
+            }
+
             *sparse_entity =
                 EntityId::new_from_index_and_gen(self.dense.len() as u64, entity.gen());
 
@@ -176,7 +248,8 @@ impl<T: Component> SparseSet<T> {
                 self.insertion_data.push(current);
             }
             if self.is_tracking_modification {
-                self.modification_data.push(0);
+                self.modification_data
+                    .push(current.wrapping_sub(MAX_TRACK_AGE));
             }
 
             self.dense.push(entity);
@@ -184,6 +257,10 @@ impl<T: Component> SparseSet<T> {
 
             old_component = None;
         } else if entity.gen() >= sparse_entity.gen() {
+            if let Some(index) = &mut self.index {
+                index.insert(entity, &value)?;
+            }
+
             let old_data = unsafe {
                 core::mem::replace(self.data.get_unchecked_mut(sparse_entity.uindex()), value)
             };
@@ -211,7 +288,7 @@ impl<T: Component> SparseSet<T> {
             old_component = None;
         }
 
-        old_component
+        Ok(old_component)
     }
 }
 
@@ -244,13 +321,33 @@ impl<T: Component> SparseSet<T> {
 
     #[inline]
     pub(crate) fn actual_remove(&mut self, entity: EntityId) -> Option<T> {
-        let sparse_entity = self.sparse.get(entity)?;
+        let mut sparse_entity = self.sparse.get(entity)?;
 
         if entity.gen() >= sparse_entity.gen() {
+            // `AllStorages` unpacks the entity beforehand, views can't reach the other storages of the group
+            // the entity leaves the packed part of this storage and the others catch up on the next `AllStorages` operation
+            if let Some(len) = self.group.as_ref().map(|group| group.len) {
+                let index = sparse_entity.uindex();
+
+                if index < len {
+                    self.swap_index(index, len - 1);
+                    sparse_entity.set_index((len - 1) as u64);
+
+                    let group = self.group.as_mut().unwrap();
+                    group.len -= 1;
+                    group.pending.push(self.dense[len - 1]);
+                    group.swapped.push(index);
+                }
+            }
+
             unsafe {
                 *self.sparse.get_mut_unchecked(entity) = EntityId::dead();
             }
 
+            if let Some(index) = &mut self.index {
+                index.remove(self.dense[sparse_entity.uindex()]);
+            }
+
             self.dense.swap_remove(sparse_entity.uindex());
             if self.is_tracking_insertion() {
                 self.insertion_data.swap_remove(sparse_entity.uindex());
@@ -295,6 +392,54 @@ impl<T: Component> SparseSet<T> {
         self.last_insert = current;
         self.last_modified = current;
     }
+    /// Moves timestamps older than [`MAX_TRACK_AGE`] forward.
+    pub(crate) fn private_rebase_tracking(&mut self, current: u32) {
+        self.last_insert = rebased(self.last_insert, current);
+        self.last_modified = rebased(self.last_modified, current);
+
+        for timestamp in self
+            .insertion_data
+            .iter_mut()
+            .chain(&mut self.modification_data)
+            .chain(
+                self.deletion_data
+                    .iter_mut()
+                    .map(|(_, timestamp, _)| timestamp),
+            )
+            .chain(self.removal_data.iter_mut().map(|(_, timestamp)| timestamp))
+        {
+            *timestamp = rebased(*timestamp, current);
+        }
+    }
+    /// Returns a type erased pointer to the component at `index` in `data`.
+    #[inline]
+    pub(crate) fn private_component_ptr(&self, index: usize) -> Option<*const u8> {
+        if index < self.data.len() {
+            Some(unsafe { self.data.as_ptr().add(index) }.cast::<u8>())
+        } else {
+            None
+        }
+    }
+    /// Returns a type erased mutable pointer to the component at `index` in `data`.\
+    /// The component isn't flagged as modified, see [`SparseSet::private_flag_modified`].
+    #[inline]
+    pub(crate) fn private_component_ptr_mut(&mut self, index: usize) -> Option<*mut u8> {
+        if index < self.data.len() {
+            Some(unsafe { self.data.as_mut_ptr().add(index) }.cast::<u8>())
+        } else {
+            None
+        }
+    }
+    /// Flags the component at `index` in `data` as modified and marks it for reindexing.
+    #[inline]
+    pub(crate) fn private_flag_modified(&mut self, index: usize, current: u32) {
+        if index < self.data.len() {
+            if self.is_tracking_modification {
+                self.modification_data[index] = current;
+            }
+            self.mark_index_dirty(self.dense[index]);
+        }
+    }
     /// Clear all deletion tracking data.
     pub fn clear_all_deleted(&mut self) {
         self.deletion_data.clear();
@@ -433,6 +578,78 @@ impl<T: Component> SparseSet<T> {
         self.dense.reserve(additional);
         self.data.reserve(additional);
     }
+    /// Shrinks the capacity of the storage as much as possible.\
+    /// Buckets of the sparse array without any component are deallocated and tracking data shrinks to its length.
+    pub fn shrink_to_fit(&mut self) {
+        self.sparse.shrink_to_fit();
+        self.dense.shrink_to_fit();
+        self.data.shrink_to_fit();
+        self.insertion_data.shrink_to_fit();
+        self.modification_data.shrink_to_fit();
+        self.deletion_data.shrink_to_fit();
+        self.removal_data.shrink_to_fit();
+    }
+    pub(crate) fn private_memory_usage(
+        &self,
+        storage_name: Cow<'static, str>,
+    ) -> StorageMemoryUsage {
+        let breakdown = StorageMemoryBreakdown {
+            component_memory_bytes: self.dense.capacity() * size_of::<EntityId>()
+                + self.data.capacity() * size_of::<T>(),
+            sparse_memory_bytes: self.sparse.reserved_memory(),
+            tracking_memory_bytes: self.insertion_data.capacity() * size_of::<u32>()
+                + self.modification_data.capacity() * size_of::<u32>()
+                + self.deletion_data.capacity() * size_of::<(EntityId, u32, T)>()
+                + self.removal_data.capacity() * size_of::<(EntityId, u32)>(),
+        };
+
+        StorageMemoryUsage {
+            storage_name,
+            allocated_memory_bytes: breakdown.component_memory_bytes
+                + breakdown.sparse_memory_bytes
+                + breakdown.tracking_memory_bytes
+                + size_of::<Self>(),
+            used_memory_bytes: self.sparse.used_memory()
+                + self.dense.len() * size_of::<EntityId>()
+                + self.data.len() * size_of::<T>()
+                + self.insertion_data.len() * size_of::<u32>()
+                + self.modification_data.len() * size_of::<u32>()
+                + self.deletion_data.len() * size_of::<(EntityId, u32, T)>()
+                + self.removal_data.len() * size_of::<(EntityId, u32)>()
+                + size_of::<Self>(),
+            breakdown,
+            component_count: self.len(),
+        }
+    }
+    pub(crate) fn private_memory_report(
+        &self,
+        storage_name: Cow<'static, str>,
+    ) -> StorageMemoryReport {
+        let memory_usage = self.private_memory_usage(storage_name);
+
+        StorageMemoryReport {
+            storage_name: memory_usage.storage_name,
+            kind: StorageKind::SparseSet,
+            component_count: memory_usage.component_count,
+            used_memory_bytes: memory_usage.used_memory_bytes,
+            allocated_memory_bytes: memory_usage.allocated_memory_bytes,
+            breakdown: memory_usage.breakdown,
+            sections: vec![
+                MemorySection {
+                    name: "sparse".into(),
+                    len: self.sparse.allocated_buckets(),
+                    used_memory_bytes: self.sparse.used_memory(),
+                    allocated_memory_bytes: self.sparse.reserved_memory(),
+                },
+                MemorySection::vec("dense", &self.dense),
+                MemorySection::vec("data", &self.data),
+                MemorySection::vec("insertion_data", &self.insertion_data),
+                MemorySection::vec("modification_data", &self.modification_data),
+                MemorySection::vec("deletion_data", &self.deletion_data),
+                MemorySection::vec("removal_data", &self.removal_data),
+            ],
+        }
+    }
     /// Sorts the `SparseSet` with a comparator function, but may not preserve the order of equal elements.
     pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
         let mut transform: Vec<usize> = (0..self.dense.len()).collect();
@@ -444,6 +661,74 @@ impl<T: Component> SparseSet<T> {
             })
         });
 
+        self.apply_transform(transform);
+    }
+    /// Sorts the `SparseSet` with a key extraction function, but may not preserve the order of equal elements.
+    pub fn sort_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_unstable_by(|a, b| f(a).cmp(&f(b)))
+    }
+    /// Sorts the `SparseSet` with a comparator function, preserving the order of equal elements.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let mut transform: Vec<usize> = (0..self.dense.len()).collect();
+
+        transform.sort_by(|&i, &j| {
+            // SAFE dense and data have the same length
+            compare(unsafe { self.data.get_unchecked(i) }, unsafe {
+                self.data.get_unchecked(j)
+            })
+        });
+
+        self.apply_transform(transform);
+    }
+    /// Sorts the `SparseSet` with a key extraction function, preserving the order of equal elements.
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+    /// Reorders the `SparseSet` to follow `other`'s order.\
+    /// Entities present in both storages are moved to the start of this storage, in the same order as in `other`.
+    /// The other entities are placed after them and keep their relative order.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntitiesViewMut, IntoIter, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let world = World::new();
+    ///
+    /// let (mut entities, mut u32s, mut usizes) = world
+    ///     .borrow::<(EntitiesViewMut, ViewMut<U32>, ViewMut<USIZE>)>()
+    ///     .unwrap();
+    ///
+    /// entities.add_entity((&mut u32s, &mut usizes), (U32(2), USIZE(2)));
+    /// entities.add_entity(&mut usizes, USIZE(3));
+    /// entities.add_entity((&mut u32s, &mut usizes), (U32(1), USIZE(1)));
+    ///
+    /// u32s.sort_unstable();
+    /// usizes.sort_like(&u32s);
+    ///
+    /// assert_eq!(usizes.as_slice(), &[USIZE(1), USIZE(2), USIZE(3)]);
+    /// ```
+    pub fn sort_like<U: Component>(&mut self, other: &SparseSet<U>) {
+        let mut transform: Vec<usize> = Vec::with_capacity(self.dense.len());
+
+        transform.extend(other.dense.iter().filter_map(|&id| self.index_of(id)));
+        transform.extend(
+            self.dense
+                .iter()
+                .enumerate()
+                .filter(|(_, &id)| !other.contains(id))
+                .map(|(index, _)| index),
+        );
+
+        self.apply_transform(transform);
+    }
+    /// Moves the component at `transform[i]` to index `i` for all indices, tracking data included.
+    fn apply_transform(&mut self, transform: Vec<usize>) {
         let mut pos;
         for i in 0..transform.len() {
             // SAFE we're in bound
@@ -454,6 +739,12 @@ impl<T: Component> SparseSet<T> {
             }
             self.dense.swap(i, pos);
             self.data.swap(i, pos);
+            if self.is_tracking_insertion {
+                self.insertion_data.swap(i, pos);
+            }
+            if self.is_tracking_modification {
+                self.modification_data.swap(i, pos);
+            }
         }
 
         for (i, id) in self.dense.iter().enumerate() {
@@ -461,6 +752,31 @@ impl<T: Component> SparseSet<T> {
                 self.sparse.get_mut_unchecked(*id).set_index(i as u64);
             }
         }
+
+        if let Some(group) = &mut self.group {
+            group.is_dirty = true;
+        }
+    }
+    /// Swaps the components at indices `a` and `b`, tracking data included.
+    pub(crate) fn swap_index(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+
+        self.dense.swap(a, b);
+        self.data.swap(a, b);
+        if self.is_tracking_insertion {
+            self.insertion_data.swap(a, b);
+        }
+        if self.is_tracking_modification {
+            self.modification_data.swap(a, b);
+        }
+
+        let (a_id, b_id) = (self.dense[a], self.dense[b]);
+        unsafe {
+            self.sparse.get_mut_unchecked(a_id).set_index(a as u64);
+            self.sparse.get_mut_unchecked(b_id).set_index(b as u64);
+        }
     }
 
     /// Applies the given function `f` to the entities `a` and `b`.\
@@ -495,6 +811,7 @@ impl<T: Component> SparseSet<T> {
             if self.is_tracking_modification {
                 self.modification_data[a_index] = current;
             }
+            self.mark_index_dirty(a);
 
             let a = unsafe { &mut *self.data.as_mut_ptr().add(a_index) };
             let b = unsafe { &*self.data.as_mut_ptr().add(b_index) };
@@ -538,6 +855,8 @@ impl<T: Component> SparseSet<T> {
                 self.modification_data[a_index] = current;
                 self.modification_data[b_index] = current;
             }
+            self.mark_index_dirty(a);
+            self.mark_index_dirty(b);
 
             let a = unsafe { &mut *self.data.as_mut_ptr().add(a_index) };
             let b = unsafe { &mut *self.data.as_mut_ptr().add(b_index) };
@@ -556,6 +875,14 @@ impl<T: Component> SparseSet<T> {
             }
         }
 
+        if let Some(group) = &mut self.group {
+            group.len = 0;
+            group.is_dirty = true;
+        }
+        if let Some(index) = &mut self.index {
+            index.clear();
+        }
+
         self.insertion_data.clear();
 
         let is_tracking_deletion = self.is_tracking_deletion();
@@ -585,6 +912,14 @@ impl<T: Component> SparseSet<T> {
             }
         }
 
+        if let Some(group) = &mut self.group {
+            group.len = 0;
+            group.is_dirty = true;
+        }
+        if let Some(index) = &mut self.index {
+            index.clear();
+        }
+
         let dense_ptr = self.dense.as_ptr();
         let dense_len = self.dense.len();
 
@@ -605,6 +940,10 @@ impl<T: Ord + Component> SparseSet<T> {
     pub fn sort_unstable(&mut self) {
         self.sort_unstable_by(Ord::cmp)
     }
+    /// Sorts the `SparseSet`, preserving the order of equal elements.
+    pub fn sort(&mut self) {
+        self.sort_by(Ord::cmp)
+    }
 }
 
 impl<T: 'static + Component + Send + Sync> Storage for SparseSet<T> {
@@ -617,28 +956,36 @@ impl<T: 'static + Component + Send + Sync> Storage for SparseSet<T> {
         self.private_clear(current);
     }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
-        Some(StorageMemoryUsage {
-            storage_name: type_name::<Self>().into(),
-            allocated_memory_bytes: self.sparse.reserved_memory()
-                + (self.dense.capacity() * core::mem::size_of::<EntityId>())
-                + (self.data.capacity() * core::mem::size_of::<T>())
-                + (self.insertion_data.capacity() * core::mem::size_of::<u32>())
-                + (self.deletion_data.capacity() * core::mem::size_of::<(T, EntityId)>())
-                + (self.removal_data.capacity() * core::mem::size_of::<EntityId>())
-                + core::mem::size_of::<Self>(),
-            used_memory_bytes: self.sparse.used_memory()
-                + (self.dense.len() * core::mem::size_of::<EntityId>())
-                + (self.data.len() * core::mem::size_of::<T>())
-                + (self.insertion_data.len() * core::mem::size_of::<u32>())
-                + (self.deletion_data.len() * core::mem::size_of::<(EntityId, T)>())
-                + (self.removal_data.len() * core::mem::size_of::<EntityId>())
-                + core::mem::size_of::<Self>(),
-            component_count: self.len(),
-        })
+        Some(self.private_memory_usage(type_name::<Self>().into()))
+    }
+    fn memory_report(&self) -> Option<StorageMemoryReport> {
+        Some(self.private_memory_report(type_name::<Self>().into()))
     }
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
         Some(&self.sparse)
     }
+    fn dense_entities(&self) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
+    #[inline]
+    fn component_ptr(&self, index: usize) -> Option<*const u8> {
+        self.private_component_ptr(index)
+    }
+    #[inline]
+    fn component_ptr_mut(&mut self, index: usize) -> Option<*mut u8> {
+        self.private_component_ptr_mut(index)
+    }
+    #[inline]
+    fn flag_modified(&mut self, index: usize, current: u32) {
+        self.private_flag_modified(index, current);
+    }
+    fn shrink_to_fit(&mut self) {
+        SparseSet::shrink_to_fit(self);
+    }
+    #[inline]
+    fn rebase_tracking(&mut self, current: u32) {
+        self.private_rebase_tracking(current);
+    }
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
@@ -686,28 +1033,36 @@ impl<T: 'static + Component + Sync> Storage for NonSend<SparseSet<T>> {
         self.private_clear(current);
     }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
-        Some(StorageMemoryUsage {
-            storage_name: type_name::<Self>().into(),
-            allocated_memory_bytes: self.sparse.reserved_memory()
-                + (self.dense.capacity() * core::mem::size_of::<EntityId>())
-                + (self.data.capacity() * core::mem::size_of::<T>())
-                + (self.insertion_data.capacity() * core::mem::size_of::<u32>())
-                + (self.deletion_data.capacity() * core::mem::size_of::<(T, EntityId)>())
-                + (self.removal_data.capacity() * core::mem::size_of::<EntityId>())
-                + core::mem::size_of::<Self>(),
-            used_memory_bytes: self.sparse.used_memory()
-                + (self.dense.len() * core::mem::size_of::<EntityId>())
-                + (self.data.len() * core::mem::size_of::<T>())
-                + (self.insertion_data.len() * core::mem::size_of::<u32>())
-                + (self.deletion_data.len() * core::mem::size_of::<(EntityId, T)>())
-                + (self.removal_data.len() * core::mem::size_of::<EntityId>())
-                + core::mem::size_of::<Self>(),
-            component_count: self.len(),
-        })
+        Some(self.private_memory_usage(type_name::<Self>().into()))
+    }
+    fn memory_report(&self) -> Option<StorageMemoryReport> {
+        Some(self.private_memory_report(type_name::<Self>().into()))
     }
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
         Some(&self.sparse)
     }
+    fn dense_entities(&self) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
+    #[inline]
+    fn component_ptr(&self, index: usize) -> Option<*const u8> {
+        self.private_component_ptr(index)
+    }
+    #[inline]
+    fn component_ptr_mut(&mut self, index: usize) -> Option<*mut u8> {
+        self.private_component_ptr_mut(index)
+    }
+    #[inline]
+    fn flag_modified(&mut self, index: usize, current: u32) {
+        self.private_flag_modified(index, current);
+    }
+    fn shrink_to_fit(&mut self) {
+        SparseSet::shrink_to_fit(self);
+    }
+    #[inline]
+    fn rebase_tracking(&mut self, current: u32) {
+        self.private_rebase_tracking(current);
+    }
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -755,28 +1110,36 @@ impl<T: 'static + Component + Send> Storage for NonSync<SparseSet<T>> {
         self.private_clear(current);
     }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
-        Some(StorageMemoryUsage {
-            storage_name: type_name::<Self>().into(),
-            allocated_memory_bytes: self.sparse.reserved_memory()
-                + (self.dense.capacity() * core::mem::size_of::<EntityId>())
-                + (self.data.capacity() * core::mem::size_of::<T>())
-                + (self.insertion_data.capacity() * core::mem::size_of::<u32>())
-                + (self.deletion_data.capacity() * core::mem::size_of::<(T, EntityId)>())
-                + (self.removal_data.capacity() * core::mem::size_of::<EntityId>())
-                + core::mem::size_of::<Self>(),
-            used_memory_bytes: self.sparse.used_memory()
-                + (self.dense.len() * core::mem::size_of::<EntityId>())
-                + (self.data.len() * core::mem::size_of::<T>())
-                + (self.insertion_data.len() * core::mem::size_of::<u32>())
-                + (self.deletion_data.len() * core::mem::size_of::<(EntityId, T)>())
-                + (self.removal_data.len() * core::mem::size_of::<EntityId>())
-                + core::mem::size_of::<Self>(),
-            component_count: self.len(),
-        })
+        Some(self.private_memory_usage(type_name::<Self>().into()))
+    }
+    fn memory_report(&self) -> Option<StorageMemoryReport> {
+        Some(self.private_memory_report(type_name::<Self>().into()))
     }
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
         Some(&self.sparse)
     }
+    fn dense_entities(&self) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
+    #[inline]
+    fn component_ptr(&self, index: usize) -> Option<*const u8> {
+        self.private_component_ptr(index)
+    }
+    #[inline]
+    fn component_ptr_mut(&mut self, index: usize) -> Option<*mut u8> {
+        self.private_component_ptr_mut(index)
+    }
+    #[inline]
+    fn flag_modified(&mut self, index: usize, current: u32) {
+        self.private_flag_modified(index, current);
+    }
+    fn shrink_to_fit(&mut self) {
+        SparseSet::shrink_to_fit(self);
+    }
+    #[inline]
+    fn rebase_tracking(&mut self, current: u32) {
+        self.private_rebase_tracking(current);
+    }
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -824,28 +1187,36 @@ impl<T: 'static + Component> Storage for NonSendSync<SparseSet<T>> {
         self.private_clear(current);
     }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
-        Some(StorageMemoryUsage {
-            storage_name: type_name::<Self>().into(),
-            allocated_memory_bytes: self.sparse.reserved_memory()
-                + (self.dense.capacity() * core::mem::size_of::<EntityId>())
-                + (self.data.capacity() * core::mem::size_of::<T>())
-                + (self.insertion_data.capacity() * core::mem::size_of::<u32>())
-                + (self.deletion_data.capacity() * core::mem::size_of::<(T, EntityId)>())
-                + (self.removal_data.capacity() * core::mem::size_of::<EntityId>())
-                + core::mem::size_of::<Self>(),
-            used_memory_bytes: self.sparse.used_memory()
-                + (self.dense.len() * core::mem::size_of::<EntityId>())
-                + (self.data.len() * core::mem::size_of::<T>())
-                + (self.insertion_data.len() * core::mem::size_of::<u32>())
-                + (self.deletion_data.len() * core::mem::size_of::<(EntityId, T)>())
-                + (self.removal_data.len() * core::mem::size_of::<EntityId>())
-                + core::mem::size_of::<Self>(),
-            component_count: self.len(),
-        })
+        Some(self.private_memory_usage(type_name::<Self>().into()))
+    }
+    fn memory_report(&self) -> Option<StorageMemoryReport> {
+        Some(self.private_memory_report(type_name::<Self>().into()))
     }
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
         Some(&self.sparse)
     }
+    fn dense_entities(&self) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
+    #[inline]
+    fn component_ptr(&self, index: usize) -> Option<*const u8> {
+        self.private_component_ptr(index)
+    }
+    #[inline]
+    fn component_ptr_mut(&mut self, index: usize) -> Option<*mut u8> {
+        self.private_component_ptr_mut(index)
+    }
+    #[inline]
+    fn flag_modified(&mut self, index: usize, current: u32) {
+        self.private_flag_modified(index, current);
+    }
+    fn shrink_to_fit(&mut self) {
+        SparseSet::shrink_to_fit(self);
+    }
+    #[inline]
+    fn rebase_tracking(&mut self, current: u32) {
+        self.private_rebase_tracking(current);
+    }
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -1166,6 +1537,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn stable_sort_by_key() {
+        let mut array = SparseSet::new();
+        array.track_insertion();
+
+        for i in 0..10 {
+            array.insert(EntityId::new_from_parts(i, 0), I32(i as i32 % 3), i as u32);
+        }
+
+        array.sort_by_key(|i| i.0);
+
+        assert_eq!(array.data, &[0, 0, 0, 0, 1, 1, 1, 2, 2, 2].map(I32));
+        assert_eq!(
+            array.dense.iter().map(|id| id.index()).collect::<Vec<_>>(),
+            &[0, 3, 6, 9, 1, 4, 7, 2, 5, 8]
+        );
+        for (&id, &inserted) in array.dense.iter().zip(&array.insertion_data) {
+            assert_eq!(id.index() as u32, inserted);
+            assert_eq!(
+                array.index_of(id),
+                array.dense.iter().position(|&other| other == id)
+            );
+        }
+    }
+
+    #[test]
+    fn sort_like() {
+        let mut array = SparseSet::new();
+        let mut other = SparseSet::new();
+        array.track_modification();
+
+        for i in 0..6 {
+            array.insert(EntityId::new_from_parts(i, 0), I32(i as i32), 0);
+            array.modification_data[i as usize] = i as u32;
+        }
+        for i in [4, 7, 0, 2] {
+            other.insert(EntityId::new_from_parts(i, 0), STR(""), 0);
+        }
+
+        array.sort_like(&other);
+
+        assert_eq!(array.data, &[4, 0, 2, 1, 3, 5].map(I32));
+        for (i, &id) in array.dense.iter().enumerate() {
+            assert_eq!(array.modification_data[i], id.index() as u32);
+            assert_eq!(array.index_of(id), Some(i));
+        }
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut array = SparseSet::new();
+
+        for i in 0..100 {
+            array.insert(EntityId::new_from_parts(i, 0), I32(i as i32), 0);
+        }
+        for i in 0..99 {
+            array.dyn_remove(EntityId::new_from_parts(i, 0), 0);
+        }
+
+        let before = array.private_memory_usage("".into()).breakdown;
+        array.shrink_to_fit();
+        let after = array.private_memory_usage("".into()).breakdown;
+
+        assert!(after.component_memory_bytes < before.component_memory_bytes);
+        assert!(after.sparse_memory_bytes < before.sparse_memory_bytes);
+        assert_eq!(array.len(), 1);
+        assert_eq!(
+            array.private_get(EntityId::new_from_parts(99, 0)),
+            Some(&I32(99))
+        );
+        assert_eq!(array.private_get(EntityId::new_from_parts(0, 0)), None);
+    }
+
     #[test]
     fn debug() {
         let mut sparse_set = SparseSet::new();
@@ -1,7 +1,7 @@
 use crate::all_storages::AllStorages;
 use crate::component::Component;
 use crate::entity_id::EntityId;
-use crate::sparse_set::SparseSet;
+use crate::sparse_set::{unpack_component, SparseSet};
 use crate::storage::StorageId;
 #[cfg(doc)]
 use crate::world::World;
@@ -19,7 +19,9 @@ impl<T: Send + Sync + Component> TupleRemove for T {
 
     #[inline]
     fn remove(all_storages: &mut AllStorages, entity: EntityId) -> Self::Out {
-        let current = all_storages.get_current();
+        let current = all_storages.get_current_mut();
+
+        unpack_component::<T>(all_storages, entity);
 
         all_storages
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
@@ -33,7 +35,11 @@ macro_rules! impl_remove_component {
             type Out = ($(Option<$type>,)+);
 
             fn remove(all_storages: &mut AllStorages, entity: EntityId) -> Self::Out {
-                let current = all_storages.get_current();
+                let current = all_storages.get_current_mut();
+
+                $(
+                    unpack_component::<$type>(all_storages, entity);
+                )+
 
                 ($(
                     all_storages
@@ -8,6 +8,7 @@ use core::hint::unreachable_unchecked;
 /// Internal part of a [`SparseSet`].
 ///
 /// [`SparseSet`]: crate::sparse_set::SparseSet
+#[derive(Clone)]
 pub struct SparseArray<T, const N: usize>(Vec<Option<Box<[T; N]>>>);
 
 impl<T, const N: usize> SparseArray<T, N> {
@@ -37,6 +38,9 @@ impl<T, const N: usize> SparseArray<T, N> {
                 }
             })
     }
+    pub(super) fn allocated_buckets(&self) -> usize {
+        self.0.iter().filter(|bucket| bucket.is_some()).count()
+    }
     pub(super) fn reserved_memory(&self) -> usize {
         self.0.capacity() * core::mem::size_of::<Option<Box<T>>>()
             + self.0.iter().fold(0, |count, array| {
@@ -69,6 +73,20 @@ impl<const N: usize> SparseArray<EntityId, N> {
             }
         }
     }
+    /// Deallocates buckets only containing dead entities and shrinks the bucket list.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        for bucket in &mut self.0 {
+            if matches!(bucket, Some(ids) if ids.iter().all(EntityId::is_dead)) {
+                *bucket = None;
+            }
+        }
+
+        while let Some(None) = self.0.last() {
+            self.0.pop();
+        }
+
+        self.0.shrink_to_fit();
+    }
     pub(crate) fn bulk_allocate(&mut self, start: EntityId, end: EntityId) {
         if end.bucket() >= self.0.len() {
             self.0.resize(end.bucket() + 1, None);
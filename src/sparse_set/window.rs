@@ -1,10 +1,12 @@
 use crate::atomic_refcell::{ExclusiveBorrow, SharedBorrow};
 use crate::component::Component;
 use crate::entity_id::EntityId;
+use crate::sparse_set::ReindexOnDrop;
 use crate::views::{View, ViewMut};
 use alloc::boxed::Box;
 use core::hint::unreachable_unchecked;
 use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
 use core::ptr;
 
 pub struct FullRawWindow<'a, T> {
@@ -179,6 +181,8 @@ unsafe impl<T: Send + Component> Send for FullRawWindowMut<'_, T> {}
 impl<'w, T: Component> FullRawWindowMut<'w, T> {
     #[inline]
     pub(crate) fn new<TRACK>(view: &mut ViewMut<'_, T, TRACK>) -> Self {
+        view.sparse_set.mark_index_all_dirty();
+
         let sparse_len = view.sparse.len();
         let sparse: *mut Option<Box<[EntityId; super::BUCKET_SIZE]>> = view.sparse.as_mut_ptr();
         let sparse = sparse as *mut *mut EntityId;
@@ -201,7 +205,13 @@ impl<'w, T: Component> FullRawWindowMut<'w, T> {
     #[inline]
     pub(crate) fn new_owned<TRACK>(
         view: ViewMut<'_, T, TRACK>,
-    ) -> (Self, Option<SharedBorrow<'_>>, ExclusiveBorrow<'_>) {
+    ) -> (
+        Self,
+        Option<SharedBorrow<'_>>,
+        (Option<ReindexOnDrop<'_>>, ExclusiveBorrow<'_>),
+    ) {
+        // the index is updated by `ReindexOnDrop` instead of `ViewMut`'s `Drop`
+        let view = ManuallyDrop::new(view);
         let ViewMut {
             sparse_set,
             _all_borrow: all_borrow,
@@ -210,7 +220,20 @@ impl<'w, T: Component> FullRawWindowMut<'w, T> {
             last_modification,
             current,
             ..
-        } = view;
+        } = &*view;
+        // SAFE `view` is never used or dropped after this
+        let (sparse_set, all_borrow, borrow) = unsafe {
+            (
+                ptr::read(sparse_set),
+                ptr::read(all_borrow),
+                ptr::read(borrow),
+            )
+        };
+        let (last_insertion, last_modification, current) =
+            (*last_insertion, *last_modification, *current);
+
+        sparse_set.mark_index_all_dirty();
+        let reindex = unsafe { ReindexOnDrop::new(sparse_set) };
 
         let sparse_len = sparse_set.len();
         let sparse: *mut Option<Box<[EntityId; super::BUCKET_SIZE]>> =
@@ -233,7 +256,7 @@ impl<'w, T: Component> FullRawWindowMut<'w, T> {
                 _phantom: PhantomData,
             },
             all_borrow,
-            borrow,
+            (reindex, borrow),
         )
     }
     #[inline]
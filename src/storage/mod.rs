@@ -7,7 +7,7 @@ pub(crate) use sbox::SBox;
 
 use crate::all_storages::AllStorages;
 use crate::entity_id::EntityId;
-use crate::memory_usage::StorageMemoryUsage;
+use crate::memory_usage::{StorageMemoryReport, StorageMemoryUsage};
 use crate::sparse_set::SparseArray;
 use crate::tracking::TrackingTimestamp;
 use alloc::borrow::Cow;
@@ -51,6 +51,12 @@ pub trait Storage: SizedAny {
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
         None
     }
+    /// Returns how much memory this storage uses, split by allocation.\
+    /// Defaults to [`Storage::memory_usage`] without any section.
+    fn memory_report(&self) -> Option<StorageMemoryReport> {
+        self.memory_usage()
+            .map(StorageMemoryReport::from_memory_usage)
+    }
     /// Returns the storage's name.
     fn name(&self) -> Cow<'static, str> {
         core::any::type_name::<Self>().into()
@@ -62,10 +68,42 @@ pub trait Storage: SizedAny {
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, 32>> {
         None
     }
+    /// Returns a [`SparseSet`]'s entities, in the same order as its components.
+    ///
+    /// [`SparseSet`]: crate::sparse_set::SparseSet
+    fn dense_entities(&self) -> Option<&[EntityId]> {
+        None
+    }
+    /// Returns a type erased pointer to the component at `index` in a [`SparseSet`].\
+    /// `index` is the one stored in [`Storage::sparse_array`].
+    ///
+    /// [`SparseSet`]: crate::sparse_set::SparseSet
+    #[allow(unused_variables)]
+    fn component_ptr(&self, index: usize) -> Option<*const u8> {
+        None
+    }
+    /// Returns a type erased mutable pointer to the component at `index` in a [`SparseSet`].\
+    /// `index` is the one stored in [`Storage::sparse_array`].\
+    /// The component isn't flagged as modified, see [`Storage::flag_modified`].
+    ///
+    /// [`SparseSet`]: crate::sparse_set::SparseSet
+    #[allow(unused_variables)]
+    fn component_ptr_mut(&mut self, index: usize) -> Option<*mut u8> {
+        None
+    }
+    /// Flags the component at `index` in a [`SparseSet`] as modified at `current` if the storage tracks modification.
+    ///
+    /// [`SparseSet`]: crate::sparse_set::SparseSet
+    #[allow(unused_variables)]
+    fn flag_modified(&mut self, index: usize, current: u32) {}
     /// Returns `true` if the storage is empty.
     fn is_empty(&self) -> bool {
         false
     }
+    /// Shrinks the capacity of the storage as much as possible.
+    fn shrink_to_fit(&mut self) {}
+    /// Moves tracking timestamps older than `u32::MAX / 2` ticks forward to keep them from looking new once the counter wraps around.
+    fn rebase_tracking(&mut self, _current: u32) {}
     /// Clear all deletion and removal tracking data.
     fn clear_all_removed_and_deleted(&mut self) {}
     /// Clear all deletion and removal tracking data older than some timestamp.
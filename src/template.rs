@@ -0,0 +1,142 @@
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::type_name;
+
+/// Set of components that can be spawned many times, see [`World::spawn_template`] and [`World::bulk_spawn_template`].
+///
+/// Each spawned entity receives a clone of all components.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, Template, World};
+///
+/// #[derive(Component, Clone)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Clone)]
+/// struct Enemy;
+///
+/// let mut world = World::new();
+///
+/// let template = Template::new().with(Health(100)).with(Enemy);
+///
+/// let boss = world.spawn_template(&template);
+/// world.bulk_spawn_template(&template, (0..1000).map(|_| ()));
+/// ```
+///
+/// [`World::spawn_template`]: crate::World::spawn_template()
+/// [`World::bulk_spawn_template`]: crate::World::bulk_spawn_template()
+#[derive(Default, Clone)]
+pub struct Template {
+    components: Vec<Box<dyn TemplateComponent>>,
+}
+
+impl Template {
+    /// Creates an empty `Template`.
+    #[inline]
+    pub fn new() -> Template {
+        Template::default()
+    }
+    /// Adds `component` to the template, replacing the previous component of the same type.
+    #[inline]
+    pub fn with<T: Send + Sync + Component + Clone>(mut self, component: T) -> Template {
+        self.insert(component);
+        self
+    }
+    /// Adds `component` to the template, replacing the previous component of the same type.
+    pub fn insert<T: Send + Sync + Component + Clone>(&mut self, component: T) {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        match self
+            .components
+            .iter_mut()
+            .find(|template_component| template_component.storage_id() == storage_id)
+        {
+            Some(template_component) => *template_component = Box::new(component),
+            None => self.components.push(Box::new(component)),
+        }
+    }
+    /// Removes the component of type `T` from the template.
+    pub fn remove<T: Send + Sync + Component + Clone>(&mut self) {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        self.components
+            .retain(|template_component| template_component.storage_id() != storage_id);
+    }
+    /// Returns the number of components in the template.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+    /// Returns `true` if the template has no component.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+    /// Adds a clone of all components to each entity in `entities`.
+    pub(crate) fn add_to(
+        &self,
+        all_storages: &mut AllStorages,
+        entities: &[EntityId],
+        current: u32,
+    ) {
+        for template_component in &self.components {
+            template_component.add_to(all_storages, entities, current);
+        }
+    }
+}
+
+impl core::fmt::Debug for Template {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(
+                self.components
+                    .iter()
+                    .map(|template_component| template_component.type_name()),
+            )
+            .finish()
+    }
+}
+
+trait TemplateComponent: Send + Sync {
+    fn storage_id(&self) -> StorageId;
+    fn type_name(&self) -> &'static str;
+    fn add_to(&self, all_storages: &mut AllStorages, entities: &[EntityId], current: u32);
+    fn clone_box(&self) -> Box<dyn TemplateComponent>;
+}
+
+impl<T: Send + Sync + Component + Clone> TemplateComponent for T {
+    #[inline]
+    fn storage_id(&self) -> StorageId {
+        StorageId::of::<SparseSet<T>>()
+    }
+    #[inline]
+    fn type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+    fn add_to(&self, all_storages: &mut AllStorages, entities: &[EntityId], current: u32) {
+        let sparse_set = all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+        sparse_set.reserve(entities.len());
+        for &entity in entities {
+            sparse_set.insert(entity, self.clone(), current);
+        }
+    }
+    #[inline]
+    fn clone_box(&self) -> Box<dyn TemplateComponent> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn TemplateComponent> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
@@ -15,6 +15,7 @@ use crate::track::{
     ModificationAndDeletionAndRemoval, ModificationAndRemoval, Removal, Untracked,
 };
 use core::fmt;
+use core::sync::atomic::{AtomicU32, Ordering};
 
 /// When tracking will be a const generic it will not be possible to implement traits directly on them.
 /// This type will be the way to implement traits on tracking constants.
@@ -232,6 +233,46 @@ pub(crate) fn is_track_within_bounds(timestamp: u32, last: u32, current: u32) ->
     track < bounds
 }
 
+/// Age after which a timestamp is considered as old as it can be.
+pub(crate) const MAX_TRACK_AGE: u32 = u32::MAX / 2;
+/// Number of ticks after which stored timestamps have to be rebased.
+// Timestamps are rebased before their age can exceed `MAX_TRACK_AGE + REBASE_PERIOD`,
+// well before the counter wraps around and makes them look new again.
+pub(crate) const REBASE_PERIOD: u32 = u32::MAX / 4;
+
+/// Last time a system ran.
+///
+/// Stored by the scheduler running the system, one for each system, and handed to the system when it runs.\
+/// This way the same system can run in multiple [`World`]s.
+///
+/// [`World`]: crate::World
+#[derive(Default)]
+pub(crate) struct SystemLastRun(AtomicU32);
+
+impl SystemLastRun {
+    /// Returns the last time the system ran and updates it to `current`.
+    #[inline]
+    pub(crate) fn swap(&self, current: u32) -> u32 {
+        rebased(self.0.swap(current, Ordering::Acquire), current)
+    }
+}
+
+/// Returns `true` if more than [`REBASE_PERIOD`] ticks passed since the last rebase.
+#[inline]
+pub(crate) fn is_rebase_due(current: u32, last_rebase: &AtomicU32) -> bool {
+    current.wrapping_sub(last_rebase.load(Ordering::Acquire)) > REBASE_PERIOD
+}
+
+/// Returns `timestamp` or, if it's older than [`MAX_TRACK_AGE`], the oldest valid timestamp.
+#[inline]
+pub(crate) fn rebased(timestamp: u32, current: u32) -> u32 {
+    if current.wrapping_sub(timestamp) > MAX_TRACK_AGE {
+        current.wrapping_sub(MAX_TRACK_AGE)
+    } else {
+        timestamp
+    }
+}
+
 #[inline]
 pub(crate) fn map_deletion_data<T>(
     &(entity_id, timestamp, _): &(EntityId, u32, T),
@@ -242,3 +283,105 @@ pub(crate) fn map_deletion_data<T>(
 /// Timestamp used to clear tracking information.
 #[derive(Clone, Copy)]
 pub struct TrackingTimestamp(pub(crate) u32);
+
+#[cfg(test)]
+mod tests {
+    use crate::component::{Component, Unique};
+    use crate::iter::IntoIter;
+    use crate::track;
+    use crate::{UniqueView, UniqueViewMut, View, ViewMut, Workload, World};
+
+    struct U32(u32);
+    impl Component for U32 {}
+
+    struct ModifiedCount(usize);
+    impl Unique for ModifiedCount {}
+
+    #[test]
+    fn workload() {
+        let mut world = World::new();
+        world.track_modification::<U32>();
+        world.add_unique(ModifiedCount(0));
+
+        let entity = world.add_entity((U32(0),));
+
+        Workload::new("")
+            .with_system(
+                |u32s: View<U32, track::Modification>, mut count: UniqueViewMut<ModifiedCount>| {
+                    count.0 = u32s.modified().iter().count();
+                },
+            )
+            .add_to_world(&world)
+            .unwrap();
+
+        world.run(|mut u32s: ViewMut<U32>| {
+            u32s[entity].0 += 1;
+        });
+
+        world.advance_tracking_counter(1000);
+
+        world.run_workload("").unwrap();
+        assert_eq!(world.borrow::<UniqueView<ModifiedCount>>().unwrap().0, 1);
+        world.run_workload("").unwrap();
+        assert_eq!(world.borrow::<UniqueView<ModifiedCount>>().unwrap().0, 0);
+
+        // the counter wraps around and ends up between the modification and the last run
+        for _ in 0..8 {
+            world.advance_tracking_counter((u32::MAX - 500) / 8);
+            world.run_workload("").unwrap();
+            assert_eq!(world.borrow::<UniqueView<ModifiedCount>>().unwrap().0, 0);
+        }
+    }
+
+    #[test]
+    fn exclusive_access() {
+        let mut world = World::new();
+        world.track_modification::<U32>();
+
+        let entity = world.add_entity((U32(0),));
+
+        world.run(|mut u32s: ViewMut<U32>| {
+            u32s[entity].0 += 1;
+        });
+        world.advance_tracking_counter(1000);
+        world.run(|u32s: ViewMut<U32, track::Modification>| u32s.clear_all_modified());
+
+        // the counter wraps around and ends up between the modification and the clear
+        for _ in 0..8 {
+            world.advance_tracking_counter((u32::MAX - 500) / 8);
+            world.add_entity(());
+        }
+
+        world.run(|u32s: View<U32, track::Modification>| {
+            assert!(!u32s.is_modified(entity));
+        });
+    }
+
+    #[test]
+    fn explicit() {
+        let mut world = World::new();
+        world.track_modification::<U32>();
+
+        let entity = world.add_entity((U32(0),));
+
+        world.run(|mut u32s: ViewMut<U32>| {
+            u32s[entity].0 += 1;
+        });
+        world.advance_tracking_counter(1000);
+        world.run(|u32s: ViewMut<U32, track::Modification>| u32s.clear_all_modified());
+
+        // shared borrows never rebase
+        for i in 0..8 {
+            world.advance_tracking_counter((u32::MAX - 500) / 8);
+            world.run(|_: View<U32>| {});
+
+            if i == 3 {
+                world.rebase_tracking();
+            }
+        }
+
+        world.run(|u32s: View<U32, track::Modification>| {
+            assert!(!u32s.is_modified(entity));
+        });
+    }
+}
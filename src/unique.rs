@@ -1,4 +1,10 @@
-use crate::{component::Unique, memory_usage::StorageMemoryUsage, storage::Storage};
+use crate::{
+    component::Unique,
+    memory_usage::{StorageKind, StorageMemoryBreakdown, StorageMemoryReport, StorageMemoryUsage},
+    storage::Storage,
+    tracking::rebased,
+};
+use core::mem::size_of;
 
 /// Unique storage.
 pub struct UniqueStorage<T: Unique> {
@@ -15,12 +21,28 @@ impl<T: Unique> Storage for UniqueStorage<T> {
             storage_name: core::any::type_name::<Self>().into(),
             allocated_memory_bytes: core::mem::size_of::<Self>(),
             used_memory_bytes: core::mem::size_of::<Self>(),
+            breakdown: StorageMemoryBreakdown {
+                component_memory_bytes: size_of::<T>(),
+                ..StorageMemoryBreakdown::default()
+            },
             component_count: 1,
         })
     }
+    fn memory_report(&self) -> Option<StorageMemoryReport> {
+        Some(StorageMemoryReport {
+            kind: StorageKind::Unique,
+            ..StorageMemoryReport::from_memory_usage(self.memory_usage()?)
+        })
+    }
     fn is_empty(&self) -> bool {
         false
     }
+    fn rebase_tracking(&mut self, current: u32) {
+        self.insert = rebased(self.insert, current);
+        self.modification = rebased(self.modification, current);
+        self.last_insert = rebased(self.last_insert, current);
+        self.last_modification = rebased(self.last_modification, current);
+    }
 }
 
 impl<T: Unique> UniqueStorage<T> {
@@ -86,6 +86,56 @@ where
     pub fn clear(&mut self) {
         self.sparse_set.private_clear(self.current);
     }
+    /// Adds `component` to `entity` unless its key is already associated with another entity in the storage's unique index.\
+    /// Like [`AddComponent::add_component_unchecked`], this function does not check `entity` is alive.
+    ///
+    /// ### Errors
+    ///
+    /// - In a unique index, `component` has the same key as another entity's component. The storage is left untouched.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntitiesViewMut, Indexed, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct NetworkId(u64);
+    ///
+    /// impl Indexed for NetworkId {
+    ///     type Key = u64;
+    ///
+    ///     fn key(&self) -> u64 {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.add_unique_index::<NetworkId>().unwrap();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut network_ids: ViewMut<NetworkId>| {
+    ///     let entity0 = entities.add_entity((), ());
+    ///     let entity1 = entities.add_entity((), ());
+    ///
+    ///     network_ids.try_add_component_unchecked(entity0, NetworkId(0)).unwrap();
+    ///
+    ///     let err = network_ids
+    ///         .try_add_component_unchecked(entity1, NetworkId(0))
+    ///         .unwrap_err();
+    ///     assert_eq!(err.other, entity0);
+    /// });
+    /// ```
+    ///
+    /// [`AddComponent::add_component_unchecked`]: crate::AddComponent::add_component_unchecked()
+    pub fn try_add_component_unchecked(
+        &mut self,
+        entity: EntityId,
+        component: T,
+    ) -> Result<(), error::DuplicateKey> {
+        self.sparse_set
+            .try_insert(entity, component, self.current)?;
+        self.sparse_set.invalidate_group(entity);
+
+        Ok(())
+    }
     /// Creates a draining iterator that empties the storage and yields the removed items.
     pub fn drain(&mut self) -> SparseSetDrain<'_, T> {
         self.sparse_set.private_drain(self.current)
@@ -301,6 +351,13 @@ impl<T: Component, TRACK> DerefMut for ViewMut<'_, T, TRACK> {
     }
 }
 
+impl<T: Component, TRACK> Drop for ViewMut<'_, T, TRACK> {
+    #[inline]
+    fn drop(&mut self) {
+        self.sparse_set.reindex_on_borrow_end();
+    }
+}
+
 impl<'a, T: Component, TRACK> AsRef<SparseSet<T>> for ViewMut<'a, T, TRACK> {
     #[inline]
     fn as_ref(&self) -> &SparseSet<T> {
@@ -347,6 +404,8 @@ impl<'a, T: Component, TRACK> core::ops::IndexMut<EntityId> for ViewMut<'a, T, T
             })
             .unwrap();
 
+        self.sparse_set.mark_index_dirty(entity);
+
         let SparseSet {
             data,
             modification_data,
@@ -1,34 +1,46 @@
-use crate::all_storages::{AllStorages, CustomStorageAccess, TupleDeleteAny, TupleRetain};
-use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
+use crate::all_storages::{
+    AllStorages, CustomStorageAccess, EntityDebug, TupleDeleteAny, TupleRetain,
+};
+use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell, HolderScope};
 use crate::borrow::WorldBorrow;
-use crate::component::Unique;
+use crate::component::{Indexed, Unique};
 use crate::entities::Entities;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::get_component::GetComponent;
-use crate::info::WorkloadsTypeUsage;
+use crate::info::{RunFrequency, WorkloadsTypeUsage};
 use crate::iter_component::{IntoIterRef, IterComponent};
-use crate::memory_usage::WorldMemoryUsage;
-use crate::public_transport::ShipyardRwLock;
+use crate::map_entities::EntityMap;
+use crate::memory_usage::{MemoryReport, WorldMemoryUsage};
+use crate::public_transport::{RwLock, ShipyardRwLock};
+use crate::reflect::ComponentRegistry;
 use crate::reserve::BulkEntityIter;
 use crate::scheduler::Label;
 use crate::scheduler::{AsLabel, Batches, Scheduler};
-use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TupleRemove};
+use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TupleGroup, TupleRemove};
 use crate::storage::{Storage, StorageId};
 use crate::system::System;
-use crate::tracking::{TrackingTimestamp, TupleTrack};
+use crate::template::Template;
+use crate::tracking::{is_rebase_due, SystemLastRun, TrackingTimestamp, TupleTrack};
 use crate::views::EntitiesViewMut;
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::format;
+use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::sync::atomic::AtomicU32;
+use hashbrown::HashMap;
 
 /// `World` contains all data this library will manipulate.
 pub struct World {
     pub(crate) all_storages: AtomicRefCell<AllStorages>,
     pub(crate) scheduler: AtomicRefCell<Scheduler>,
-    counter: Arc<AtomicU32>,
+    pub(crate) counter: Arc<AtomicU32>,
+    /// Value of `counter` the last time tracking was rebased
+    last_rebase: Arc<AtomicU32>,
+    /// Last time each system of a [`ScheduledWorkload`](crate::ScheduledWorkload) ran in this `World`, keyed by workload
+    scheduled_last_runs: RwLock<HashMap<u64, Arc<[SystemLastRun]>>>,
     #[cfg(feature = "parallel")]
     thread_pool: Option<rayon::ThreadPool>,
 }
@@ -38,16 +50,22 @@ impl Default for World {
     /// Creates an empty `World`.
     fn default() -> Self {
         let counter = Arc::new(AtomicU32::new(1));
+        let last_rebase = Arc::new(AtomicU32::new(0));
         World {
             #[cfg(not(feature = "thread_local"))]
-            all_storages: AtomicRefCell::new(AllStorages::new(counter.clone())),
+            all_storages: AtomicRefCell::new(AllStorages::new(
+                counter.clone(),
+                last_rebase.clone(),
+            )),
             #[cfg(feature = "thread_local")]
             all_storages: AtomicRefCell::new_non_send(
-                AllStorages::new(counter.clone()),
+                AllStorages::new(counter.clone(), last_rebase.clone()),
                 std::thread::current().id(),
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            last_rebase,
+            scheduled_last_runs: RwLock::new_std(HashMap::new()),
             #[cfg(feature = "parallel")]
             thread_pool: None,
         }
@@ -67,16 +85,22 @@ impl World {
     /// Creates an empty `World` with a custom `RwLock` for `AllStorages`.
     pub fn new_with_custom_lock<L: ShipyardRwLock + Send + Sync>() -> Self {
         let counter = Arc::new(AtomicU32::new(1));
+        let last_rebase = Arc::new(AtomicU32::new(0));
         World {
             #[cfg(not(feature = "thread_local"))]
-            all_storages: AtomicRefCell::new(AllStorages::new_with_lock::<L>(counter.clone())),
+            all_storages: AtomicRefCell::new(AllStorages::new_with_lock::<L>(
+                counter.clone(),
+                last_rebase.clone(),
+            )),
             #[cfg(feature = "thread_local")]
             all_storages: AtomicRefCell::new_non_send(
-                AllStorages::new_with_lock::<L>(counter.clone()),
+                AllStorages::new_with_lock::<L>(counter.clone(), last_rebase.clone()),
                 std::thread::current().id(),
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            last_rebase,
+            scheduled_last_runs: RwLock::new_custom::<L>(HashMap::new()),
             #[cfg(feature = "parallel")]
             thread_pool: None,
         }
@@ -89,16 +113,22 @@ impl World {
     #[cfg(feature = "parallel")]
     pub fn new_with_local_thread_pool(thread_pool: rayon::ThreadPool) -> Self {
         let counter = Arc::new(AtomicU32::new(1));
+        let last_rebase = Arc::new(AtomicU32::new(0));
         World {
             #[cfg(not(feature = "thread_local"))]
-            all_storages: AtomicRefCell::new(AllStorages::new(counter.clone())),
+            all_storages: AtomicRefCell::new(AllStorages::new(
+                counter.clone(),
+                last_rebase.clone(),
+            )),
             #[cfg(feature = "thread_local")]
             all_storages: AtomicRefCell::new_non_send(
-                AllStorages::new(counter.clone()),
+                AllStorages::new(counter.clone(), last_rebase.clone()),
                 std::thread::current().id(),
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            last_rebase,
+            scheduled_last_runs: RwLock::new_std(HashMap::new()),
             #[cfg(feature = "parallel")]
             thread_pool: Some(thread_pool),
         }
@@ -113,16 +143,22 @@ impl World {
         thread_pool: rayon::ThreadPool,
     ) -> Self {
         let counter = Arc::new(AtomicU32::new(1));
+        let last_rebase = Arc::new(AtomicU32::new(0));
         World {
             #[cfg(not(feature = "thread_local"))]
-            all_storages: AtomicRefCell::new(AllStorages::new_with_lock::<L>(counter.clone())),
+            all_storages: AtomicRefCell::new(AllStorages::new_with_lock::<L>(
+                counter.clone(),
+                last_rebase.clone(),
+            )),
             #[cfg(feature = "thread_local")]
             all_storages: AtomicRefCell::new_non_send(
-                AllStorages::new_with_lock::<L>(counter.clone()),
+                AllStorages::new_with_lock::<L>(counter.clone(), last_rebase.clone()),
                 std::thread::current().id(),
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            last_rebase,
+            scheduled_last_runs: RwLock::new_custom::<L>(HashMap::new()),
             #[cfg(feature = "parallel")]
             thread_pool: Some(thread_pool),
         }
@@ -433,7 +469,14 @@ let (entities, mut usizes) = world
     #[cfg_attr(feature = "thread_local", doc = "[NonSend]: crate::NonSend")]
     #[cfg_attr(feature = "thread_local", doc = "[NonSync]: crate::NonSync")]
     #[cfg_attr(feature = "thread_local", doc = "[NonSendSync]: crate::NonSendSync")]
+    #[track_caller]
     pub fn borrow<V: WorldBorrow>(&self) -> Result<V::WorldView<'_>, error::GetStorage> {
+        #[cfg(feature = "tracing")]
+        let borrow_span = tracing::trace_span!("borrow", view = core::any::type_name::<V>());
+        #[cfg(feature = "tracing")]
+        let _borrow_span = borrow_span.enter();
+
+        let _holder = HolderScope::caller();
         let current = self.get_current();
 
         V::world_borrow(self, None, None, current)
@@ -550,6 +593,7 @@ world.run_with_data(sys1, (EntityId::dead(), [0., 0.]));
         let system_span = tracing::info_span!("system", name = ?core::any::type_name::<S>());
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
+        let _holder = HolderScope::caller();
 
         system
             .run((data,), self)
@@ -676,6 +720,7 @@ let i = world.run(sys1);
         let system_span = tracing::info_span!("system", name = ?core::any::type_name::<S>());
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
+        let _holder = HolderScope::caller();
 
         system
             .run((), self)
@@ -742,11 +787,77 @@ let i = world.run(sys1);
         let label = label.as_label();
         let batches = scheduler.workload(&*label)?;
 
+        self.run_scheduler_batches(&scheduler, batches, &*label)
+    }
+    /// Runs the `name` workload of each [`World`] in `worlds`.\
+    /// With the `parallel` feature, the worlds run in parallel on rayon's global thread pool.
+    ///
+    /// All worlds run even if some of them fail.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared) of each [`World`]
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - The error of each [`World`] that failed, alongside its index in `worlds`.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn regenerate(mut healths: ViewMut<Health>) {
+    ///     for mut health in (&mut healths).iter() {
+    ///         health.0 += 1;
+    ///     }
+    /// }
+    ///
+    /// let matches: Vec<World> = (0..4)
+    ///     .map(|_| {
+    ///         let mut world = World::new();
+    ///         world.add_entity(Health(0));
+    ///
+    ///         Workload::new("Tick")
+    ///             .with_system(regenerate)
+    ///             .add_to_world(&world)
+    ///             .unwrap();
+    ///
+    ///         world
+    ///     })
+    ///     .collect();
+    ///
+    /// World::run_workload_in_worlds(&matches, "Tick").unwrap();
+    /// ```
+    pub fn run_workload_in_worlds<T>(
+        worlds: &[World],
+        label: impl AsLabel<T>,
+    ) -> Result<(), error::RunWorlds> {
+        let label = label.as_label();
+
+        run_in_worlds(worlds, |world| world.run_workload(label.clone()))
+    }
+    /// Runs `batches` with the scheduler's systems, publishing their timings if the scheduler has a publisher.
+    fn run_scheduler_batches(
+        &self,
+        scheduler: &Scheduler,
+        batches: &Batches,
+        label: &dyn Label,
+    ) -> Result<(), error::RunWorkload> {
+        #[cfg(all(feature = "std", feature = "serde1"))]
+        if let Some(publisher) = &scheduler.publisher {
+            return self.run_batches_published(scheduler, publisher, batches, label);
+        }
+
         self.run_batches(
-            &scheduler.systems,
+            &|index| (scheduler.systems[index])(self, &scheduler.last_runs[index]),
             &scheduler.system_names,
+            &scheduler.system_borrows,
             batches,
-            &*label,
+            label,
         )
     }
     /// Returns `true` if the world contains the `name` workload.
@@ -776,39 +887,150 @@ let i = world.run(sys1);
 
         self.scheduler.borrow().unwrap().contains_workload(&*label)
     }
+    /// Returns how many times the `name` workload runs and whether it already ran.\
+    /// Returns `None` if the world doesn't contain the workload.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::info::RunFrequency;
+    /// use shipyard::{Workload, WorkloadModificator, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Init").run_once().add_to_world(&world).unwrap();
+    ///
+    /// assert_eq!(
+    ///     world.workload_frequency("Init"),
+    ///     Some(RunFrequency::Once { has_run: false })
+    /// );
+    ///
+    /// world.run_workload("Init").unwrap();
+    ///
+    /// assert_eq!(
+    ///     world.workload_frequency("Init"),
+    ///     Some(RunFrequency::Once { has_run: true })
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn workload_frequency<T>(&self, name: impl AsLabel<T>) -> Option<RunFrequency> {
+        let label = name.as_label();
+
+        self.scheduler
+            .borrow()
+            .unwrap()
+            .workloads
+            .get(&label)
+            .map(|batches| batches.run_once.frequency())
+    }
+    /// Lets all run-once and startup workloads and run-once systems run again.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    #[track_caller]
+    pub fn reset_run_once(&self) {
+        for batches in self.scheduler.borrow().unwrap().workloads.values() {
+            batches.run_once.reset();
+        }
+    }
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        run_system: &(dyn Fn(usize) -> Result<(), error::Run> + Sync),
         system_names: &[Box<dyn Label>],
+        system_borrows: &[String],
         batches: &Batches,
         workload_name: &dyn Label,
     ) -> Result<(), error::RunWorkload> {
+        if !self.start_batches(batches, workload_name)? {
+            return Ok(());
+        }
+
+        self.run_started_batches(
+            run_system,
+            system_names,
+            system_borrows,
+            batches,
+            workload_name,
+        )
+    }
+    /// Returns `false` if the workload doesn't run, it's run-once and already ran or its run_if returned `false`.\
+    /// Otherwise marks run-once workloads as run.
+    pub(crate) fn start_batches(
+        &self,
+        batches: &Batches,
+        workload_name: &dyn Label,
+    ) -> Result<bool, error::RunWorkload> {
+        if batches.run_once.has_run() {
+            return Ok(false);
+        }
+
         if let Some(run_if) = &batches.run_if {
             if !run_if
                 .run(self)
                 .map_err(|err| error::RunWorkload::Run((workload_name.dyn_clone(), err)))?
             {
-                return Ok(());
+                return Ok(false);
             }
         }
 
+        Ok(batches.run_once.start())
+    }
+    /// Runs `batches` once [`World::start_batches`] let them.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn run_started_batches(
+        &self,
+        run_system: &(dyn Fn(usize) -> Result<(), error::Run> + Sync),
+        system_names: &[Box<dyn Label>],
+        system_borrows: &[String],
+        batches: &Batches,
+        workload_name: &dyn Label,
+    ) -> Result<(), error::RunWorkload> {
+        self.try_rebase_tracking();
+
         #[cfg(feature = "parallel")]
-        {
-            self.run_batches_parallel(systems, system_names, batches, workload_name)
-        }
+        let result = self.run_batches_parallel(
+            run_system,
+            system_names,
+            system_borrows,
+            batches,
+            workload_name,
+        );
 
         #[cfg(not(feature = "parallel"))]
-        {
-            self.run_batches_sequential(systems, system_names, batches, workload_name)
+        let result = self.run_batches_sequential(
+            run_system,
+            system_names,
+            system_borrows,
+            batches,
+            workload_name,
+        );
+
+        if result.is_err() {
+            batches.run_once.abort();
         }
+
+        result
     }
     #[cfg(feature = "parallel")]
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches_parallel(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        run_system: &(dyn Fn(usize) -> Result<(), error::Run> + Sync),
         system_names: &[Box<dyn Label>],
+        #[cfg_attr(not(feature = "tracing"), allow(unused))] system_borrows: &[String],
         batches: &Batches,
         #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
     ) -> Result<(), error::RunWorkload> {
@@ -818,7 +1040,15 @@ let i = world.run(sys1);
         let _parent_span = parent_span.enter();
 
         let run_batch = || -> Result<(), error::RunWorkload> {
-            for (batch, batches_run_if) in batches.parallel.iter().zip(&batches.parallel_run_if) {
+            for (batch_index, (batch, batches_run_if)) in batches
+                .parallel
+                .iter()
+                .zip(&batches.parallel_run_if)
+                .enumerate()
+            {
+                #[cfg(not(feature = "tracing"))]
+                let _ = batch_index;
+
                 let mut result = Ok(());
                 let run_if = (
                     if let Some(run_if_index) = batches_run_if.0 {
@@ -856,46 +1086,83 @@ let i = world.run(sys1);
                 rayon::in_place_scope(|scope| {
                     scope.spawn(|_| {
                         if batch.1.len() == 1 {
+                            #[cfg(feature = "tracing")]
+                            let system_span = system_span(
+                                &parent_span,
+                                workload_name,
+                                batch_index,
+                                &*system_names[batch.1[0]],
+                                &system_borrows[batch.1[0]],
+                                !run_if.1[0],
+                            );
+
                             if !run_if.1[0] {
+                                #[cfg(feature = "tracing")]
+                                system_span.in_scope(|| {});
+
                                 return;
                             }
 
-                            #[cfg(feature = "tracing")]
-                            let system_span = tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_names[batch.1[0]]);
                             #[cfg(feature = "tracing")]
                             let _system_span = system_span.enter();
 
-                            result = systems[batch.1[0]](self).map_err(|err| {
+                            result = run_system(batch.1[0]).map_err(|err| {
                                 error::RunWorkload::Run((system_names[batch.1[0]].clone(), err))
                             });
                         } else {
                             use rayon::prelude::*;
 
-                            result = batch.1.par_iter().zip(run_if.1).try_for_each(|(&index, should_run)| {
-                                if !should_run {
-                                    return Ok(());
-                                }
-
-                                #[cfg(feature = "tracing")]
-                                let system_span = tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_names[index]);
-                                #[cfg(feature = "tracing")]
-                                let _system_span = system_span.enter();
-
-                                (systems[index])(self).map_err(|err| {
-                                    error::RunWorkload::Run((system_names[index].clone(), err))
-                                })
-                            });
+                            result = batch.1.par_iter().zip(run_if.1).try_for_each(
+                                |(&index, should_run)| {
+                                    #[cfg(feature = "tracing")]
+                                    let system_span = system_span(
+                                        &parent_span,
+                                        workload_name,
+                                        batch_index,
+                                        &*system_names[index],
+                                        &system_borrows[index],
+                                        !should_run,
+                                    );
+
+                                    if !should_run {
+                                        #[cfg(feature = "tracing")]
+                                        system_span.in_scope(|| {});
+
+                                        return Ok(());
+                                    }
+
+                                    #[cfg(feature = "tracing")]
+                                    let _system_span = system_span.enter();
+
+                                    run_system(index).map_err(|err| {
+                                        error::RunWorkload::Run((system_names[index].clone(), err))
+                                    })
+                                },
+                            );
                         }
                     });
 
                     if let Some(index) = batch.0 {
+                        #[cfg(feature = "tracing")]
+                        let system_span = system_span(
+                            &parent_span,
+                            workload_name,
+                            batch_index,
+                            &*system_names[index],
+                            &system_borrows[index],
+                            !run_if.0,
+                        );
+
+                        #[cfg(feature = "tracing")]
+                        if !run_if.0 {
+                            system_span.in_scope(|| {});
+                        }
+
                         if run_if.0 {
-                            #[cfg(feature = "tracing")]
-                            let system_span = tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_names[index]);
                             #[cfg(feature = "tracing")]
                             let _system_span = system_span.enter();
 
-                            systems[index](self).map_err(|err| {
+                            run_system(index).map_err(|err| {
                                 error::RunWorkload::Run((system_names[index].clone(), err))
                             })?;
                         }
@@ -921,8 +1188,9 @@ let i = world.run(sys1);
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches_sequential(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        run_system: &(dyn Fn(usize) -> Result<(), error::Run> + Sync),
         system_names: &[Box<dyn Label>],
+        #[cfg_attr(not(feature = "tracing"), allow(unused))] system_borrows: &[String],
         batches: &Batches,
         #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
     ) -> Result<(), error::RunWorkload> {
@@ -931,32 +1199,65 @@ let i = world.run(sys1);
         #[cfg(feature = "tracing")]
         let _parent_span = parent_span.enter();
 
+        // batch of each system in `sequential`
+        #[cfg(feature = "tracing")]
+        let batch_indices = {
+            let mut batch_indices = alloc::vec![0; batches.sequential.len()];
+
+            for (batch_index, (single_system, parallel_systems)) in
+                batches.parallel_run_if.iter().enumerate()
+            {
+                for &position in single_system.iter().chain(parallel_systems) {
+                    batch_indices[position] = batch_index;
+                }
+            }
+
+            batch_indices
+        };
+
         batches
             .sequential
             .iter()
             .zip(&batches.sequential_run_if)
-            .try_for_each(|(&index, run_if)| {
-                if let Some(run_if) = run_if.as_ref() {
-                    let should_run = (run_if)(self).map_err(|err| {
+            .enumerate()
+            .try_for_each(|(position, (&index, run_if))| {
+                #[cfg(not(feature = "tracing"))]
+                let _ = position;
+
+                let should_run = if let Some(run_if) = run_if.as_ref() {
+                    (run_if)(self).map_err(|err| {
                         error::RunWorkload::Run((system_names[index].clone(), err))
-                    })?;
+                    })?
+                } else {
+                    true
+                };
 
-                    if !should_run {
-                        return Ok(());
-                    }
+                #[cfg(feature = "tracing")]
+                let system_span = system_span(
+                    &parent_span,
+                    workload_name,
+                    batch_indices[position],
+                    &*system_names[index],
+                    &system_borrows[index],
+                    !should_run,
+                );
+
+                if !should_run {
+                    #[cfg(feature = "tracing")]
+                    system_span.in_scope(|| {});
+
+                    return Ok(());
                 }
 
-                #[cfg(feature = "tracing")]
-                let system_span =
-                    tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_name);
                 #[cfg(feature = "tracing")]
                 let _system_span = system_span.enter();
 
-                (systems[index])(self)
+                run_system(index)
                     .map_err(|err| error::RunWorkload::Run((system_names[index].clone(), err)))
             })
     }
-    /// Run the default workload if there is one.
+    /// Run the default workload if there is one.\
+    /// Startup workloads that haven't run yet run first, in the order they were added.
     ///
     /// ### Borrows
     ///
@@ -974,14 +1275,14 @@ let i = world.run(sys1);
             .borrow()
             .map_err(|_| error::RunWorkload::Scheduler)?;
 
-        if !scheduler.is_empty() {
-            self.run_batches(
-                &scheduler.systems,
-                &scheduler.system_names,
-                scheduler.default_workload(),
-                &scheduler.default,
-            )?
+        for label in &scheduler.startup {
+            self.run_scheduler_batches(&scheduler, scheduler.workload(&**label)?, &**label)?;
+        }
+
+        if let Some(batches) = scheduler.default_workload() {
+            self.run_scheduler_batches(&scheduler, batches, &*scheduler.default)?;
         }
+
         Ok(())
     }
     /// Returns a `Ref<&AllStorages>`, used to implement custom storages.  
@@ -1026,12 +1327,107 @@ let i = world.run(sys1);
             .fetch_add(1, core::sync::atomic::Ordering::Acquire)
     }
 
+    /// Rebases tracking timestamps if it's due and `AllStorages` isn't borrowed.
+    ///
+    /// Has to be called when no system is running, rebasing borrows `AllStorages` exclusively.
+    pub(crate) fn try_rebase_tracking(&self) {
+        let current = self.counter.load(core::sync::atomic::Ordering::Acquire);
+
+        if is_rebase_due(current, &self.last_rebase) {
+            // if `AllStorages` is borrowed, rebasing will be attempted again on the next workload run
+            if let Ok(mut all_storages) = self.all_storages.borrow_mut() {
+                all_storages.rebase_tracking();
+            }
+        }
+    }
+
+    /// Returns the last time each system of the `workload` [`ScheduledWorkload`] ran in this `World`.
+    ///
+    /// [`ScheduledWorkload`]: crate::ScheduledWorkload
+    pub(crate) fn scheduled_last_runs(
+        &self,
+        workload: u64,
+        system_count: usize,
+    ) -> Arc<[SystemLastRun]> {
+        if let Some(last_runs) = self.scheduled_last_runs.read().get(&workload) {
+            return last_runs.clone();
+        }
+
+        self.scheduled_last_runs
+            .write()
+            .entry(workload)
+            .or_insert_with(|| {
+                (0..system_count)
+                    .map(|_| SystemLastRun::default())
+                    .collect()
+            })
+            .clone()
+    }
+
+    /// Moves the tracking counter forward by `ticks`.\
+    /// Used to test tracking in long running applications.
+    #[cfg(test)]
+    pub(crate) fn advance_tracking_counter(&self, ticks: u32) {
+        self.counter
+            .fetch_add(ticks, core::sync::atomic::Ordering::Acquire);
+    }
+
     /// Returns a timestamp used to clear tracking information.
     pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
         TrackingTimestamp(self.counter.load(core::sync::atomic::Ordering::Acquire))
     }
 }
 
+/// Creates the span of a system running in a workload.
+#[cfg(feature = "tracing")]
+fn system_span(
+    parent: &tracing::Span,
+    workload_name: &dyn Label,
+    batch: usize,
+    system_name: &dyn Label,
+    borrow: &str,
+    skipped: bool,
+) -> tracing::Span {
+    tracing::info_span!(
+        parent: parent,
+        "system",
+        name = ?system_name,
+        workload = ?workload_name,
+        batch,
+        borrow,
+        skipped,
+    )
+}
+
+/// Runs `run` with each [`World`], in parallel with the `parallel` feature, and collects the errors.
+pub(crate) fn run_in_worlds(
+    worlds: &[World],
+    run: impl Fn(&World) -> Result<(), error::RunWorkload> + Send + Sync,
+) -> Result<(), error::RunWorlds> {
+    #[cfg(feature = "parallel")]
+    let errors: Vec<_> = {
+        use rayon::prelude::*;
+
+        worlds
+            .par_iter()
+            .enumerate()
+            .filter_map(|(index, world)| run(world).err().map(|err| (index, err)))
+            .collect()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let errors: Vec<_> = worlds
+        .iter()
+        .enumerate()
+        .filter_map(|(index, world)| run(world).err().map(|err| (index, err)))
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(error::RunWorlds { errors })
+    }
+}
+
 impl World {
     /// Creates a new entity with the components passed as argument and returns its `EntityId`.  
     /// `component` must always be a tuple, even for a single component.
@@ -1078,6 +1474,94 @@ impl World {
     pub fn bulk_add_entity<T: BulkAddEntity>(&mut self, source: T) -> BulkEntityIter<'_> {
         self.all_storages.get_mut().bulk_add_entity(source)
     }
+    /// Creates a new entity with a clone of all of `entity`'s components and returns its `EntityId`.\
+    /// Only components registered in `registry` with [`ComponentRegistrationBuilder::with_clone`] are cloned.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is not alive.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ComponentRegistry, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut registry = ComponentRegistry::new();
+    /// registry.register::<U32>("U32").with_clone();
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((U32(0),));
+    /// let clone = world.clone_entity(entity, &registry);
+    ///
+    /// assert_eq!(world.get::<&U32>(clone).as_deref(), Ok(&&U32(0)));
+    /// ```
+    ///
+    /// [`ComponentRegistrationBuilder::with_clone`]: crate::ComponentRegistrationBuilder::with_clone()
+    #[inline]
+    #[track_caller]
+    pub fn clone_entity(&mut self, entity: EntityId, registry: &ComponentRegistry) -> EntityId {
+        self.all_storages.get_mut().clone_entity(entity, registry)
+    }
+    /// Creates a new entity with a clone of all of `template`'s components and returns its `EntityId`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, Template, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let template = Template::new().with(U32(0));
+    /// let entity = world.spawn_template(&template);
+    ///
+    /// assert_eq!(world.get::<&U32>(entity).as_deref(), Ok(&&U32(0)));
+    /// ```
+    #[inline]
+    pub fn spawn_template(&mut self, template: &Template) -> EntityId {
+        self.all_storages.get_mut().spawn_template(template)
+    }
+    /// Creates one entity per item of `overrides` and returns an iterator yielding the new `EntityId`s.\
+    /// Each entity receives a clone of all of `template`'s components, then its overrides, replacing the template's components.\
+    /// `overrides` must always yield a tuple, even for a single component, `()` spawns the template as is.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, Template, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// struct Position(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let template = Template::new().with(Health(100)).with(Position(0));
+    /// let entities = world
+    ///     .bulk_spawn_template(&template, (0..10).map(|i| (Position(i),)))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(world.get::<&Health>(entities[9]).as_deref(), Ok(&&Health(100)));
+    /// assert_eq!(world.get::<&Position>(entities[9]).as_deref(), Ok(&&Position(9)));
+    /// ```
+    #[inline]
+    pub fn bulk_spawn_template<T: TupleAddComponent, I: IntoIterator<Item = T>>(
+        &mut self,
+        template: &Template,
+        overrides: I,
+    ) -> BulkEntityIter<'_> {
+        self.all_storages
+            .get_mut()
+            .bulk_spawn_template(template, overrides)
+    }
     /// Adds components to an existing entity.  
     /// If the entity already owned a component it will be replaced.  
     /// `component` must always be a tuple, even for a single component.  
@@ -1272,6 +1756,96 @@ impl World {
             .get_mut()
             .retain_storage(entity, excluded_storage);
     }
+    /// Returns the id and name of all storages `entity` has a component in, sorted by name.\
+    /// Only storages with a sparse array, like `SparseSet`, are checked.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - All storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - AllStorages borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((U32(0),));
+    ///
+    /// let components = world.components_of(entity).unwrap();
+    /// assert_eq!(components.len(), 1);
+    /// assert_eq!(components[0].0, StorageId::of::<SparseSet<U32>>());
+    /// ```
+    #[inline]
+    pub fn components_of(
+        &self,
+        entity: EntityId,
+    ) -> Result<Vec<(StorageId, Cow<'static, str>)>, error::GetStorage> {
+        self.all_storages
+            .borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .components_of(entity)
+    }
+    /// Returns a value printing all of `entity`'s components with `Debug`.\
+    /// Components are named after their registration in `registry`, or their storage if they're not registered.\
+    /// Components not registered with [`ComponentRegistrationBuilder::with_debug`] are printed as `..`.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - All storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - AllStorages borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ComponentRegistry, World};
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct U32(u32);
+    ///
+    /// let mut registry = ComponentRegistry::new();
+    /// registry.register::<U32>("U32").with_debug();
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((U32(0),));
+    ///
+    /// println!("{:?}", world.debug_entity(entity, &registry).unwrap());
+    /// ```
+    ///
+    /// [`ComponentRegistrationBuilder::with_debug`]: crate::ComponentRegistrationBuilder::with_debug()
+    pub fn debug_entity(
+        &self,
+        entity: EntityId,
+        registry: &ComponentRegistry,
+    ) -> Result<EntityDebug<'_>, error::GetStorage> {
+        let (all_storages, all_borrow) = unsafe {
+            ARef::destructure(
+                self.all_storages
+                    .borrow()
+                    .map_err(error::GetStorage::AllStoragesBorrow)?,
+            )
+        };
+
+        let mut entity_debug = all_storages.debug_entity(entity, registry)?;
+        entity_debug.all_borrow = Some(all_borrow);
+
+        Ok(entity_debug)
+    }
     /// Deletes all entities and components in the `World`.
     ///
     /// ### Example
@@ -1287,6 +1861,40 @@ impl World {
     pub fn clear(&mut self) {
         self.all_storages.get_mut().clear();
     }
+    /// Moves tracking timestamps older than `u32::MAX / 2` ticks forward.\
+    /// This keeps them from looking new once the tracking counter wraps around.
+    ///
+    /// It's done automatically every `u32::MAX / 4` ticks, when a workload starts running
+    /// or when the `World` is modified through `&mut`, for example to add an entity.\
+    /// [`TrackingTimestamp`]s are not rebased, they shouldn't be kept for this long.
+    pub fn rebase_tracking(&mut self) {
+        self.all_storages.get_mut().rebase_tracking();
+    }
+    /// Shrinks the capacity of all storages as much as possible.\
+    /// Returns the number of bytes reclaimed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entities = (0..1000)
+    ///     .map(|i| world.add_entity((U32(i),)))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// for entity in entities {
+    ///     world.delete_entity(entity);
+    /// }
+    ///
+    /// assert!(world.shrink_to_fit() > 0);
+    /// ```
+    pub fn shrink_to_fit(&mut self) -> usize {
+        self.all_storages.get_mut().shrink_to_fit()
+    }
     /// Clear all deletion and removal tracking data.
     pub fn clear_all_removed_and_deleted(&mut self) {
         self.all_storages.get_mut().clear_all_removed_and_deleted()
@@ -1311,6 +1919,51 @@ impl World {
     pub fn memory_usage(&self) -> WorldMemoryUsage<'_> {
         WorldMemoryUsage(self)
     }
+    /// Returns the memory usage of all storages, split by allocation, and of the scheduler.\
+    /// Storages that can't be borrowed are counted in [`MemoryReport::borrowed_storages`].
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, StorageKind, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.add_entity((U32(0),));
+    ///
+    /// let report = world.memory_report();
+    /// let u32s = report
+    ///     .storages
+    ///     .iter()
+    ///     .find(|storage| storage.storage_name.contains("U32"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(u32s.kind, StorageKind::SparseSet);
+    /// assert_eq!(u32s.component_count, 1);
+    /// assert!(report.scheduler.is_some());
+    /// ```
+    #[track_caller]
+    pub fn memory_report(&self) -> MemoryReport {
+        let mut report = self.all_storages.borrow().unwrap().memory_report();
+        let scheduler = self.scheduler.borrow().unwrap().memory_usage();
+
+        report.used_memory_bytes += scheduler.used_memory_bytes;
+        report.allocated_memory_bytes += scheduler.allocated_memory_bytes;
+        report.scheduler = Some(scheduler);
+
+        report
+    }
     /// Returns a list of workloads, their systems and which storages these systems borrow.
     ///
     /// ### Borrows
@@ -1322,7 +1975,7 @@ impl World {
     /// - Scheduler borrow failed.
     #[track_caller]
     pub fn workloads_type_usage(&self) -> WorkloadsTypeUsage {
-        let mut workload_type_info = hashbrown::HashMap::new();
+        let mut workload_type_info = HashMap::new();
 
         let scheduler = self.scheduler.borrow().unwrap();
 
@@ -1376,6 +2029,130 @@ impl World {
         self.all_storages.get_mut().track_all::<T>();
     }
 
+    /// Makes the storages of `G` an owning group.\
+    /// Entities with a component in all of these storages are packed at the start of each of them.
+    /// Iterating all storages of the group together is then as fast as iterating a single storage.
+    ///
+    /// Adding and removing components with `World` or `AllStorages` keeps the group packed.
+    /// Doing it with views flags the group and iteration falls back to the regular strategy
+    /// until the next `World` or `AllStorages` operation touching the group.
+    ///
+    /// A storage can only be part of a single group.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_group::<(Position, Velocity)>().unwrap();
+    ///
+    /// world.add_entity((Position(0.0),));
+    /// world.add_entity((Position(1.0), Velocity(1.0)));
+    ///
+    /// world.run(|positions: View<Position>, velocities: View<Velocity>| {
+    ///     assert_eq!((&positions, &velocities).iter().count(), 1);
+    /// });
+    /// ```
+    pub fn add_group<G: TupleGroup>(&mut self) -> Result<(), error::AddGroup> {
+        self.all_storages.get_mut().add_group::<G>()
+    }
+    /// Indexes `T`'s storage, entities can then be found using their component's key with [`SparseSet::find`].\
+    /// Multiple entities can share the same key.
+    ///
+    /// Components modified through a mutable borrow are reindexed when the borrow ends or with [`SparseSet::reindex`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, Indexed, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Cell(i32, i32);
+    ///
+    /// impl Indexed for Cell {
+    ///     type Key = (i32, i32);
+    ///
+    ///     fn key(&self) -> (i32, i32) {
+    ///         (self.0, self.1)
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_index::<Cell>();
+    ///
+    /// let entity = world.add_entity((Cell(0, 0),));
+    /// world.add_entity((Cell(0, 0),));
+    ///
+    /// world.run(|mut cells: ViewMut<Cell>| {
+    ///     cells[entity].1 = 1;
+    /// });
+    ///
+    /// world.run(|cells: View<Cell>| {
+    ///     assert_eq!(cells.find(&(0, 0)).len(), 1);
+    ///     assert_eq!(cells.find(&(0, 1)), &[entity]);
+    /// });
+    /// ```
+    ///
+    /// [`SparseSet::find`]: crate::SparseSet::find()
+    /// [`SparseSet::reindex`]: crate::SparseSet::reindex()
+    pub fn add_index<T: Send + Sync + Indexed>(&mut self) {
+        self.all_storages.get_mut().add_index::<T>();
+    }
+
+    /// Indexes `T`'s storage, entities can then be found using their component's key with [`SparseSet::find`].\
+    /// A key can only be associated with a single entity.\
+    /// Adding a component with a key already in use panics, [`ViewMut::try_add_component_unchecked`] returns an error instead.
+    ///
+    /// Components modified through a mutable borrow are reindexed when the borrow ends, a duplicate key then panics.\
+    /// [`SparseSet::reindex`] reindexes them sooner and returns an error instead.
+    ///
+    /// ### Errors
+    ///
+    /// - Two components already in the storage have the same key, the storage is not indexed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, Indexed, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Name(String);
+    ///
+    /// impl Indexed for Name {
+    ///     type Key = String;
+    ///
+    ///     fn key(&self) -> String {
+    ///         self.0.clone()
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_unique_index::<Name>().unwrap();
+    ///
+    /// let entity = world.add_entity((Name("Player".to_string()),));
+    ///
+    /// world.run(|names: View<Name>| {
+    ///     assert_eq!(names.find(&"Player".to_string()), &[entity]);
+    /// });
+    /// ```
+    ///
+    /// [`SparseSet::find`]: crate::SparseSet::find()
+    /// [`SparseSet::reindex`]: crate::SparseSet::reindex()
+    /// [`ViewMut::try_add_component_unchecked`]: crate::ViewMut::try_add_component_unchecked()
+    pub fn add_unique_index<T: Send + Sync + Indexed>(
+        &mut self,
+    ) -> Result<(), error::DuplicateKey> {
+        self.all_storages.get_mut().add_unique_index::<T>()
+    }
+
     #[doc = "Retrieve components of `entity`.
 
 Multiple components can be queried at the same time using a tuple.
@@ -1594,6 +2371,53 @@ for (i, j) in &mut iter {
             .move_entity(other_all_storages, entity);
     }
 
+    /// Moves all entities of `other` to this `World`, they're given new ids.\
+    /// Returns the old to new id mapping.
+    ///
+    /// The [`EntityId`]s stored inside components are rewritten if they're registered in `registry`
+    /// with [`ComponentRegistrationBuilder::with_map_entities`].
+    ///
+    /// ```
+    /// use shipyard::{Component, ComponentRegistry, EntityId, EntityMap, MapEntities, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Parent(EntityId);
+    ///
+    /// impl MapEntities for Parent {
+    ///     fn map_entities(&mut self, entity_map: &EntityMap) {
+    ///         self.0 = entity_map.map(self.0);
+    ///     }
+    /// }
+    ///
+    /// let mut registry = ComponentRegistry::new();
+    /// registry.register::<Parent>("Parent").with_map_entities();
+    ///
+    /// let mut world1 = World::new();
+    /// let mut world2 = World::new();
+    ///
+    /// world1.add_entity(());
+    /// let parent = world2.add_entity(());
+    /// let child = world2.add_entity(Parent(parent));
+    ///
+    /// let entity_map = world1.merge_from(&mut world2, &registry);
+    ///
+    /// assert!(!world2.is_entity_alive(child));
+    /// assert_eq!(
+    ///     world1.get::<&Parent>(entity_map.map(child)).as_deref(),
+    ///     Ok(&&Parent(entity_map.map(parent)))
+    /// );
+    /// ```
+    ///
+    /// [`ComponentRegistrationBuilder::with_map_entities`]: crate::ComponentRegistrationBuilder::with_map_entities()
+    #[inline]
+    pub fn merge_from(&mut self, other: &mut World, registry: &ComponentRegistry) -> EntityMap {
+        let other_all_storages = other.all_storages.get_mut();
+
+        self.all_storages
+            .get_mut()
+            .merge_from(other_all_storages, registry)
+    }
+
     /// Moves all components from an entity to another in another `World`.
     ///
     /// ```
@@ -76,35 +76,105 @@ fn all_storages_simple_borrow() {
     assert_eq!(u32s.len(), 0);
 }
 
+/// Holders are only recorded in debug builds.
+fn is_held_at(holder: Option<error::BorrowHolder>, line: u32) -> bool {
+    match holder {
+        Some(error::BorrowHolder::Caller(location)) => {
+            location.file() == file!() && location.line() == line
+        }
+        Some(error::BorrowHolder::System(_)) => false,
+        None => !cfg!(debug_assertions),
+    }
+}
+
 #[test]
 fn invalid_borrow() {
     let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
 
+    let line = line!() + 1;
     let _u32s = world.borrow::<ViewMut<U32>>().unwrap();
-    assert_eq!(
-        world.borrow::<ViewMut<U32>>().err(),
+    match world.borrow::<ViewMut<U32>>().err() {
         Some(error::GetStorage::StorageBorrow {
-            name: Some(type_name::<SparseSet<U32>>()),
-            id: StorageId::of::<SparseSet<U32>>(),
-            borrow: error::Borrow::Unique
-        })
+            name,
+            id,
+            borrow: error::Borrow::Unique(holder),
+        }) => {
+            assert_eq!(name, Some(type_name::<SparseSet<U32>>()));
+            assert_eq!(id, StorageId::of::<SparseSet<U32>>());
+            assert!(is_held_at(holder, line));
+        }
+        err => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn run_borrow_holder() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let line = line!() + 1;
+    world.run(
+        |_u32s: ViewMut<U32>| match world.borrow::<View<U32>>().err() {
+            Some(error::GetStorage::StorageBorrow {
+                borrow: error::Borrow::Unique(holder),
+                ..
+            }) => assert!(is_held_at(holder, line)),
+            err => panic!("{:?}", err),
+        },
     );
 }
 
+#[test]
+fn shared_borrow_holder() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let line = line!() + 1;
+    let first = world.borrow::<View<U32>>().unwrap();
+    let second = world.borrow::<View<U32>>().unwrap();
+    drop(second);
+
+    match world.borrow::<ViewMut<U32>>().err() {
+        Some(error::GetStorage::StorageBorrow {
+            borrow: error::Borrow::Shared(holder),
+            ..
+        }) => assert!(is_held_at(holder, line)),
+        err => panic!("{:?}", err),
+    }
+
+    let line = line!() + 1;
+    let third = world.borrow::<View<U32>>().unwrap();
+    drop(first);
+
+    match world.borrow::<ViewMut<U32>>().err() {
+        Some(error::GetStorage::StorageBorrow {
+            borrow: error::Borrow::Shared(holder),
+            ..
+        }) => assert!(is_held_at(holder, line)),
+        err => panic!("{:?}", err),
+    }
+
+    drop(third);
+    world.borrow::<ViewMut<U32>>().unwrap();
+}
+
 #[test]
 fn all_storages_invalid_borrow() {
     let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
 
     let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    let line = line!() + 1;
     let _u32s = all_storages.borrow::<ViewMut<U32>>().unwrap();
-    assert_eq!(
-        all_storages.borrow::<ViewMut<U32>>().err(),
+    match all_storages.borrow::<ViewMut<U32>>().err() {
         Some(error::GetStorage::StorageBorrow {
-            name: Some(type_name::<SparseSet<U32>>()),
-            id: StorageId::of::<SparseSet<U32>>(),
-            borrow: error::Borrow::Unique
-        })
-    );
+            name,
+            id,
+            borrow: error::Borrow::Unique(holder),
+        }) => {
+            assert_eq!(name, Some(type_name::<SparseSet<U32>>()));
+            assert_eq!(id, StorageId::of::<SparseSet<U32>>());
+            assert!(is_held_at(holder, line));
+        }
+        err => panic!("{:?}", err),
+    }
 }
 
 #[test]
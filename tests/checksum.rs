@@ -0,0 +1,107 @@
+use shipyard::*;
+
+#[derive(Hash)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(Hash)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[derive(Hash)]
+struct OtherU32(u32);
+impl Component for OtherU32 {}
+
+struct NotHash;
+impl Component for NotHash {}
+
+fn registry() -> ComponentRegistry {
+    let mut registry = ComponentRegistry::new();
+    registry.register::<U32>("U32").with_hash();
+    registry.register::<USIZE>("USIZE").with_hash();
+    registry.register::<NotHash>("NotHash");
+    registry
+}
+
+#[test]
+fn order_independent() {
+    let registry = registry();
+
+    let mut world1 = World::new();
+    let entity0 = world1.add_entity((U32(0), USIZE(0)));
+    let entity1 = world1.add_entity((U32(1),));
+    world1.add_entity((NotHash,));
+
+    let mut world2 = World::new();
+    world2.bulk_add_entity((0..3).map(|_| ()));
+    world2.add_component(entity1, (U32(1),));
+    world2.add_component(entity0, (USIZE(0), U32(0)));
+    world2.add_component(entity0, (NotHash,));
+
+    assert_eq!(world1.checksum(&registry), world2.checksum(&registry));
+    assert_eq!(world1.checksum(&registry).storages.len(), 2);
+}
+
+#[test]
+fn generations() {
+    let registry = registry();
+
+    let mut world1 = World::new();
+    world1.add_entity(());
+
+    let mut world2 = World::new();
+    let entity = world2.add_entity(());
+    world2.delete_entity(entity);
+    world2.add_entity(());
+
+    let checksum1 = world1.checksum(&registry);
+    let checksum2 = world2.checksum(&registry);
+    assert_ne!(checksum1.hash, checksum2.hash);
+    assert_ne!(checksum1.entities, checksum2.entities);
+    assert!(checksum1.diverging_storages(&checksum2).is_empty());
+}
+
+#[test]
+fn diverging_storages() {
+    let registry = registry();
+
+    let mut world1 = World::new();
+    let entity = world1.add_entity((U32(0), USIZE(0)));
+
+    let mut world2 = World::new();
+    world2.add_entity((U32(0), USIZE(1)));
+
+    let mut world3 = World::new();
+    world3.add_entity((U32(0),));
+
+    let checksum1 = world1.checksum(&registry);
+    assert_eq!(
+        checksum1.diverging_storages(&world2.checksum(&registry)),
+        vec!["USIZE"]
+    );
+    assert_eq!(
+        checksum1.diverging_storages(&world3.checksum(&registry)),
+        vec!["USIZE"]
+    );
+
+    world1.delete_component::<(USIZE,)>(entity);
+    assert_eq!(world1.checksum(&registry), world3.checksum(&registry));
+}
+
+#[test]
+fn registered_name() {
+    let mut registry1 = ComponentRegistry::new();
+    registry1.register::<U32>("Value").with_hash();
+    let mut registry2 = ComponentRegistry::new();
+    registry2.register::<OtherU32>("Value").with_hash();
+
+    let mut world1 = World::new();
+    world1.add_entity((U32(0),));
+
+    let mut world2 = World::new();
+    world2.add_entity((OtherU32(0),));
+
+    let checksum1 = world1.checksum(&registry1);
+    assert_eq!(checksum1.storages[0].0, "Value");
+    assert_eq!(checksum1, world2.checksum(&registry2));
+}
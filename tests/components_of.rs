@@ -0,0 +1,117 @@
+use shipyard::*;
+
+#[derive(Debug)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(Debug)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+struct NotDebug;
+impl Component for NotDebug {}
+
+struct MyUnique;
+impl Unique for MyUnique {}
+
+#[test]
+fn components_of() {
+    let mut world = World::new();
+
+    world.add_unique(MyUnique);
+    let entity0 = world.add_entity((U32(0), USIZE(0)));
+    let entity1 = world.add_entity((U32(1),));
+    world.remove::<(U32,)>(entity0);
+
+    assert_eq!(
+        world.components_of(entity0).unwrap(),
+        vec![(
+            StorageId::of::<SparseSet<USIZE>>(),
+            core::any::type_name::<SparseSet<USIZE>>().into()
+        )]
+    );
+    assert_eq!(
+        world
+            .components_of(entity1)
+            .unwrap()
+            .into_iter()
+            .map(|(storage_id, _)| storage_id)
+            .collect::<Vec<_>>(),
+        vec![StorageId::of::<SparseSet<U32>>()]
+    );
+
+    world.delete_entity(entity1);
+    assert!(world.components_of(entity1).unwrap().is_empty());
+}
+
+#[test]
+fn debug_entity() {
+    let mut registry = ComponentRegistry::new();
+    registry.register::<U32>("U32").with_debug();
+    registry.register::<USIZE>("USIZE").with_debug();
+    registry.register::<NotDebug>("NotDebug");
+
+    let mut world = World::new();
+
+    let entity = world.add_entity((U32(0), USIZE(1), NotDebug));
+
+    assert_eq!(
+        format!("{:?}", world.debug_entity(entity, &registry).unwrap()),
+        format!(
+            "{:?} {{\"NotDebug\": .., \"U32\": U32(0), \"USIZE\": USIZE(1)}}",
+            entity
+        )
+    );
+
+    world.delete_entity(entity);
+    assert_eq!(
+        format!("{:?}", world.debug_entity(entity, &registry).unwrap()),
+        format!("{:?} {{}}", entity)
+    );
+}
+
+#[test]
+fn debug_entity_unregistered() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((U32(0),));
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            world
+                .debug_entity(entity, &ComponentRegistry::new())
+                .unwrap()
+        ),
+        format!(
+            "{:?} {{{:?}: ..}}",
+            entity,
+            core::any::type_name::<SparseSet<U32>>(),
+        )
+    );
+}
+
+#[test]
+fn borrowed_storage() {
+    let mut registry = ComponentRegistry::new();
+    registry.register::<U32>("U32").with_debug();
+
+    let mut world = World::new();
+
+    let entity = world.add_entity((U32(0),));
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+    assert_eq!(
+        format!("{:?}", world.debug_entity(entity, &registry).unwrap()),
+        format!("{:?} {{\"U32\": U32(0)}}", entity)
+    );
+    assert_eq!(world.components_of(entity).unwrap().len(), 1);
+    drop(u32s);
+
+    let _u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    assert!(matches!(
+        world.components_of(entity),
+        Err(error::GetStorage::StorageBorrow { .. })
+    ));
+    assert!(world.debug_entity(entity, &registry).is_err());
+}
@@ -0,0 +1,194 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct I16(i16);
+impl Component for I16 {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct MyUnique;
+impl Unique for MyUnique {}
+
+fn usize_id() -> StorageId {
+    StorageId::of::<SparseSet<USIZE>>()
+}
+
+fn u32_id() -> StorageId {
+    StorageId::of::<SparseSet<U32>>()
+}
+
+fn i16_id() -> StorageId {
+    StorageId::of::<SparseSet<I16>>()
+}
+
+#[test]
+fn filters() {
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    let entity0 = all_storages.add_entity((USIZE(0), U32(0)));
+    let entity1 = all_storages.add_entity((USIZE(1),));
+    let entity2 = all_storages.add_entity((USIZE(2), U32(2), I16(2)));
+    let entity3 = all_storages.add_entity((U32(3),));
+
+    let query = all_storages.dynamic_query(&[usize_id()], &[], &[]).unwrap();
+    assert_eq!(query.ids(), vec![entity0, entity1, entity2]);
+    drop(query);
+
+    let query = all_storages
+        .dynamic_query(&[usize_id(), u32_id()], &[], &[i16_id()])
+        .unwrap();
+    assert_eq!(query.ids(), vec![entity0]);
+    drop(query);
+
+    let query = all_storages.dynamic_query(&[], &[], &[usize_id()]).unwrap();
+    assert_eq!(query.ids(), vec![entity3]);
+    drop(query);
+
+    let mut components = Vec::new();
+    all_storages
+        .dynamic_query(&[u32_id()], &[usize_id()], &[])
+        .unwrap()
+        .for_each(|entity, required, optional| unsafe {
+            components.push((
+                entity,
+                &*required[0].cast::<U32>(),
+                optional[0].map(|usize| &*usize.cast::<USIZE>()),
+            ));
+        });
+    assert_eq!(
+        components,
+        vec![
+            (entity0, &U32(0), Some(&USIZE(0))),
+            (entity2, &U32(2), Some(&USIZE(2))),
+            (entity3, &U32(3), None),
+        ]
+    );
+}
+
+#[test]
+fn missing_storage() {
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    let entity = all_storages.add_entity((USIZE(0),));
+
+    let query = all_storages
+        .dynamic_query(&[usize_id(), u32_id()], &[], &[])
+        .unwrap();
+    assert!(query.ids().is_empty());
+    drop(query);
+
+    let query = all_storages
+        .dynamic_query(&[usize_id()], &[i16_id()], &[u32_id()])
+        .unwrap();
+    let mut count = 0;
+    query.for_each(|_, _, optional| {
+        assert_eq!(optional, &[None]);
+        count += 1;
+    });
+    assert_eq!(count, 1);
+    assert_eq!(query.ids(), vec![entity]);
+}
+
+#[test]
+fn not_sparse_set() {
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    all_storages.add_unique(MyUnique);
+
+    assert!(matches!(
+        all_storages.dynamic_query(&[StorageId::of::<UniqueStorage<MyUnique>>()], &[], &[]),
+        Err(error::DynamicQuery::NotSparseSet(_))
+    ));
+}
+
+#[test]
+fn mutable() {
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    all_storages.track_modification::<USIZE>();
+    all_storages.track_modification::<U32>();
+
+    let entity0 = all_storages.add_entity((USIZE(0), U32(0)));
+    let entity1 = all_storages.add_entity((USIZE(1),));
+    let entity2 = all_storages.add_entity((USIZE(2), I16(2)));
+    let entity3 = all_storages.add_entity((USIZE(3), U32(3)));
+
+    let mut query = all_storages
+        .dynamic_query_mut(&[usize_id()], &[u32_id()], &[i16_id()])
+        .unwrap();
+    assert_eq!(query.ids(), vec![entity0, entity1, entity3]);
+    query.for_each(|_, required, optional| unsafe {
+        if (*required[0].as_ptr().cast::<USIZE>()).0 == 3 {
+            return;
+        }
+
+        (*required[0].as_mut_ptr().cast::<USIZE>()).0 += 10;
+        if let Some(u32) = &mut optional[0] {
+            (*u32.as_mut_ptr().cast::<U32>()).0 += 10;
+        }
+    });
+    drop(query);
+
+    let (usizes, u32s) = all_storages
+        .borrow::<(
+            View<USIZE, track::Modification>,
+            View<U32, track::Modification>,
+        )>()
+        .unwrap();
+    assert!(usizes.is_modified(entity0));
+    assert!(usizes.is_modified(entity1));
+    assert!(!usizes.is_modified(entity2));
+    assert!(!usizes.is_modified(entity3));
+    assert!(u32s.is_modified(entity0));
+    assert!(!u32s.is_modified(entity3));
+    assert_eq!(usizes[entity1], USIZE(11));
+    assert_eq!(usizes[entity2], USIZE(2));
+    assert_eq!(usizes[entity3], USIZE(3));
+    assert_eq!(u32s[entity0], U32(10));
+}
+
+#[test]
+fn borrow_rules() {
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    all_storages.add_entity((USIZE(0), U32(0)));
+
+    let query = all_storages.dynamic_query(&[usize_id()], &[], &[]).unwrap();
+    assert!(all_storages.borrow::<View<USIZE>>().is_ok());
+    assert!(matches!(
+        all_storages.dynamic_query_mut(&[usize_id()], &[], &[]),
+        Err(error::DynamicQuery::StorageBorrow(
+            error::GetStorage::StorageBorrow {
+                borrow: error::Borrow::Shared(_),
+                ..
+            }
+        ))
+    ));
+    drop(query);
+
+    let query = all_storages
+        .dynamic_query_mut(&[usize_id()], &[], &[u32_id()])
+        .unwrap();
+    assert!(all_storages.borrow::<View<USIZE>>().is_err());
+    assert!(all_storages.borrow::<View<U32>>().is_ok());
+    assert!(all_storages
+        .dynamic_query_mut(&[u32_id()], &[], &[])
+        .is_err());
+    drop(query);
+
+    assert!(all_storages
+        .dynamic_query_mut(&[usize_id(), usize_id()], &[], &[])
+        .is_err());
+}
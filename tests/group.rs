@@ -0,0 +1,225 @@
+use shipyard::iter::Iter;
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct I16(i16);
+impl Component for I16 {}
+
+fn sorted_pairs(world: &World) -> Vec<(usize, u32)> {
+    let (usizes, u32s) = world.borrow::<(View<USIZE>, View<U32>)>().unwrap();
+
+    let mut pairs = (&usizes, &u32s)
+        .iter()
+        .map(|(x, y)| (x.0, y.0))
+        .collect::<Vec<_>>();
+    pairs.sort_unstable();
+
+    pairs
+}
+
+fn is_tight(world: &World) -> bool {
+    let (usizes, u32s) = world.borrow::<(View<USIZE>, View<U32>)>().unwrap();
+
+    matches!((&usizes, &u32s).iter(), Iter::Tight(_))
+}
+
+#[test]
+fn add_group() {
+    let mut world = World::new();
+
+    world.add_entity((USIZE(0),));
+    world.add_entity((USIZE(1), U32(1)));
+    world.add_entity((U32(2),));
+
+    world.add_group::<(USIZE, U32)>().unwrap();
+
+    world.add_entity((USIZE(3), U32(3)));
+    let entity = world.add_entity((USIZE(4),));
+    world.add_component(entity, (U32(4),));
+
+    assert!(is_tight(&world));
+    assert_eq!(sorted_pairs(&world), vec![(1, 1), (3, 3), (4, 4)]);
+
+    let (usizes, u32s) = world.borrow::<(View<USIZE>, View<U32>)>().unwrap();
+    assert_eq!((&usizes, &u32s).iter().with_id().count(), 3);
+    for (id, (x, y)) in (&usizes, &u32s).iter().with_id() {
+        assert_eq!(usizes[id].0, x.0);
+        assert_eq!(u32s[id].0, y.0);
+    }
+}
+
+#[test]
+fn add_group_errors() {
+    let mut world = World::new();
+
+    assert_eq!(
+        world.add_group::<(USIZE, USIZE)>(),
+        Err(error::AddGroup::DuplicateStorage(core::any::type_name::<
+            SparseSet<USIZE>,
+        >()))
+    );
+
+    world.add_group::<(USIZE, U32)>().unwrap();
+
+    assert_eq!(
+        world.add_group::<(I16, U32)>(),
+        Err(error::AddGroup::AlreadyGrouped(core::any::type_name::<
+            SparseSet<U32>,
+        >()))
+    );
+}
+
+#[test]
+fn remove_delete() {
+    let mut world = World::new();
+    world.add_group::<(USIZE, U32)>().unwrap();
+
+    let entities = (0..6)
+        .map(|i| world.add_entity((USIZE(i), U32(i as u32))))
+        .collect::<Vec<_>>();
+
+    assert_eq!(world.remove::<(U32,)>(entities[1]), (Some(U32(1)),));
+    world.delete_component::<(USIZE,)>(entities[3]);
+    world.delete_entity(entities[0]);
+    world.strip(entities[5]);
+
+    assert!(is_tight(&world));
+    assert_eq!(sorted_pairs(&world), vec![(2, 2), (4, 4)]);
+
+    world.add_component(entities[1], (U32(1),));
+    world.retain::<SparseSet<USIZE>>(entities[2]);
+
+    assert!(is_tight(&world));
+    assert_eq!(sorted_pairs(&world), vec![(1, 1), (4, 4)]);
+}
+
+#[test]
+fn views_fall_back() {
+    let mut world = World::new();
+    world.add_group::<(USIZE, U32)>().unwrap();
+
+    let entity = world.add_entity((USIZE(0), U32(0)));
+
+    world.run(
+        |mut entities: EntitiesViewMut, mut usizes: ViewMut<USIZE>, mut u32s: ViewMut<U32>| {
+            entities.add_entity((&mut usizes, &mut u32s), (USIZE(1), U32(1)));
+        },
+    );
+
+    assert!(!is_tight(&world));
+    assert_eq!(sorted_pairs(&world), vec![(0, 0), (1, 1)]);
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        u32s.remove(entity);
+    });
+
+    assert_eq!(sorted_pairs(&world), vec![(1, 1)]);
+
+    world.add_entity((USIZE(2), U32(2)));
+
+    assert!(is_tight(&world));
+    assert_eq!(sorted_pairs(&world), vec![(1, 1), (2, 2)]);
+}
+
+#[test]
+fn views_remove_packed() {
+    let mut world = World::new();
+    world.add_group::<(USIZE, U32)>().unwrap();
+
+    let entities = (0..8)
+        .map(|i| world.add_entity((USIZE(i), U32(i as u32))))
+        .collect::<Vec<_>>();
+
+    // each storage loses different packed entities
+    world.run(|mut usizes: ViewMut<USIZE>, mut u32s: ViewMut<U32>| {
+        usizes.remove(entities[1]);
+        usizes.remove(entities[7]);
+        u32s.remove(entities[5]);
+        u32s.delete(entities[2]);
+    });
+
+    assert!(!is_tight(&world));
+    assert_eq!(sorted_pairs(&world), vec![(0, 0), (3, 3), (4, 4), (6, 6)]);
+
+    world.run(|mut usizes: ViewMut<USIZE>, mut u32s: ViewMut<U32>| {
+        usizes.remove(entities[0]);
+        u32s.remove(entities[6]);
+        usizes.add_component_unchecked(entities[1], USIZE(1));
+    });
+
+    world.add_entity((USIZE(8), U32(8)));
+
+    assert!(is_tight(&world));
+    assert_eq!(sorted_pairs(&world), vec![(1, 1), (3, 3), (4, 4), (8, 8)]);
+
+    world.add_component(entities[2], (U32(2),));
+    world.add_component(entities[5], (U32(5),));
+
+    assert!(is_tight(&world));
+    assert_eq!(
+        sorted_pairs(&world),
+        vec![(1, 1), (2, 2), (3, 3), (4, 4), (5, 5), (8, 8)]
+    );
+}
+
+#[test]
+fn extra_storage() {
+    let mut world = World::new();
+    world.add_group::<(USIZE, U32)>().unwrap();
+
+    world.add_entity((USIZE(0), U32(0), I16(0)));
+    world.add_entity((USIZE(1), U32(1)));
+    world.add_entity((USIZE(2), I16(2)));
+    world.add_entity((USIZE(3), U32(3), I16(3)));
+
+    world.run(|usizes: View<USIZE>, u32s: View<U32>, i16s: View<I16>| {
+        let mut triples = (&usizes, &u32s, &i16s)
+            .iter()
+            .map(|(x, y, z)| (x.0, y.0, z.0))
+            .collect::<Vec<_>>();
+        triples.sort_unstable();
+
+        assert_eq!(triples, vec![(0, 0, 0), (3, 3, 3)]);
+    });
+}
+
+#[test]
+fn bulk_add_entity() {
+    let mut world = World::new();
+    world.add_group::<(USIZE, U32)>().unwrap();
+
+    world.bulk_add_entity((0..3).map(|i| (USIZE(i),)));
+    world.bulk_add_entity((3..6).map(|i| (USIZE(i), U32(i as u32))));
+
+    assert!(is_tight(&world));
+    assert_eq!(sorted_pairs(&world), vec![(3, 3), (4, 4), (5, 5)]);
+}
+
+#[test]
+fn tracking() {
+    let mut world = World::new();
+    world.track_modification::<U32>();
+    world.add_group::<(USIZE, U32)>().unwrap();
+
+    let entity0 = world.add_entity((U32(0),));
+    let entity1 = world.add_entity((U32(1),));
+
+    world.run(|mut u32s: ViewMut<U32, track::Modification>| {
+        u32s[entity0].0 = 10;
+    });
+
+    world.add_component(entity1, (USIZE(1),));
+
+    world.run(|u32s: View<U32, track::Modification>| {
+        assert!(u32s.is_modified(entity0));
+        assert!(!u32s.is_modified(entity1));
+    });
+}
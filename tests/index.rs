@@ -0,0 +1,239 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct Team(u32);
+impl Component for Team {}
+impl Indexed for Team {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct NetworkId(u64);
+impl Component for NetworkId {}
+impl Indexed for NetworkId {
+    type Key = u64;
+
+    fn key(&self) -> u64 {
+        self.0
+    }
+}
+
+fn sorted(entities: &[EntityId]) -> Vec<EntityId> {
+    let mut entities = entities.to_vec();
+    entities.sort_unstable();
+    entities
+}
+
+#[test]
+fn multi() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((Team(0),));
+    world.add_index::<Team>();
+    let entity1 = world.add_entity((Team(0),));
+    let entity2 = world.add_entity(());
+    world.add_component(entity2, (Team(1),));
+
+    world.run(|teams: View<Team>| {
+        assert_eq!(sorted(teams.find(&0)), vec![entity0, entity1]);
+        assert_eq!(teams.find(&1), &[entity2]);
+        assert!(teams.find(&2).is_empty());
+    });
+
+    world.add_component(entity1, (Team(1),));
+    world.remove::<(Team,)>(entity0);
+
+    world.run(|teams: View<Team>| {
+        assert!(teams.find(&0).is_empty());
+        assert_eq!(sorted(teams.find(&1)), vec![entity1, entity2]);
+    });
+
+    world.delete_entity(entity2);
+    world.bulk_add_entity((0..3).map(|_| (Team(2),)));
+
+    world.run(|teams: View<Team>| {
+        assert_eq!(teams.find(&1), &[entity1]);
+        assert_eq!(teams.find(&2).len(), 3);
+    });
+
+    world.run(|mut teams: ViewMut<Team>| teams.clear());
+
+    world.run(|teams: View<Team>| {
+        assert!(teams.find(&1).is_empty());
+        assert!(teams.find(&2).is_empty());
+    });
+}
+
+#[test]
+fn unique() {
+    let mut world = World::new();
+    world.add_unique_index::<NetworkId>().unwrap();
+
+    let entity0 = world.add_entity((NetworkId(0),));
+    let entity1 = world.add_entity(());
+
+    world.run(|mut network_ids: ViewMut<NetworkId>| {
+        assert_eq!(
+            network_ids.try_add_component_unchecked(entity1, NetworkId(0)),
+            Err(error::DuplicateKey {
+                entity: entity1,
+                other: entity0,
+                name: core::any::type_name::<NetworkId>(),
+            })
+        );
+        assert!(!network_ids.contains(entity1));
+        assert_eq!(network_ids.find(&0), &[entity0]);
+    });
+
+    world.delete_entity(entity0);
+
+    world.run(|mut network_ids: ViewMut<NetworkId>| {
+        assert!(network_ids.find(&0).is_empty());
+
+        network_ids
+            .try_add_component_unchecked(entity1, NetworkId(0))
+            .unwrap();
+        assert_eq!(network_ids.find(&0), &[entity1]);
+
+        // replacing the component of the entity holding the key is allowed
+        network_ids
+            .try_add_component_unchecked(entity1, NetworkId(0))
+            .unwrap();
+        assert_eq!(network_ids.find(&0), &[entity1]);
+    });
+
+    world.delete_entity(entity1);
+
+    world.run(|network_ids: View<NetworkId>| {
+        assert!(network_ids.find(&0).is_empty());
+    });
+}
+
+#[test]
+fn unique_duplicate() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((NetworkId(0),));
+    let entity1 = world.add_entity((NetworkId(0),));
+
+    assert_eq!(
+        world.add_unique_index::<NetworkId>(),
+        Err(error::DuplicateKey {
+            entity: entity1,
+            other: entity0,
+            name: core::any::type_name::<NetworkId>(),
+        })
+    );
+
+    world.run(|mut network_ids: ViewMut<NetworkId>| {
+        assert!(network_ids.find(&0).is_empty());
+
+        network_ids[entity1].0 = 1;
+    });
+
+    world.add_unique_index::<NetworkId>().unwrap();
+
+    world.run(|mut network_ids: ViewMut<NetworkId>| {
+        network_ids[entity0].0 = 1;
+
+        assert_eq!(
+            network_ids.reindex(),
+            Err(error::DuplicateKey {
+                entity: entity0,
+                other: entity1,
+                name: core::any::type_name::<NetworkId>(),
+            })
+        );
+        assert!(network_ids.find(&0).is_empty());
+        assert_eq!(network_ids.find(&1), &[entity1]);
+
+        network_ids[entity1].0 = 2;
+        network_ids[entity0].0 = 1;
+        network_ids.reindex().unwrap();
+
+        assert_eq!(network_ids.find(&1), &[entity0]);
+        assert_eq!(network_ids.find(&2), &[entity1]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "a unique index can only associate a key with a single entity")]
+fn unique_duplicate_add() {
+    let mut world = World::new();
+    world.add_unique_index::<NetworkId>().unwrap();
+
+    world.add_entity((NetworkId(0),));
+    world.add_entity((NetworkId(0),));
+}
+
+#[test]
+#[should_panic(expected = "a unique index can only associate a key with a single entity")]
+fn unique_duplicate_borrow_end() {
+    let mut world = World::new();
+    world.add_unique_index::<NetworkId>().unwrap();
+
+    world.add_entity((NetworkId(0),));
+    let entity1 = world.add_entity((NetworkId(1),));
+
+    world.run(|mut network_ids: ViewMut<NetworkId>| {
+        network_ids[entity1].0 = 0;
+    });
+}
+
+#[test]
+fn view_mut() {
+    let mut world = World::new();
+    world.add_index::<Team>();
+
+    let entity0 = world.add_entity((Team(0),));
+    let entity1 = world.add_entity((Team(0),));
+
+    world.run(|mut teams: ViewMut<Team>| {
+        assert!(!teams.is_tracking_modification());
+
+        teams[entity0].0 = 1;
+
+        assert_eq!(sorted(teams.find(&0)), vec![entity0, entity1]);
+
+        teams.reindex().unwrap();
+
+        assert_eq!(teams.find(&0), &[entity1]);
+        assert_eq!(teams.find(&1), &[entity0]);
+
+        for mut team in (&mut teams).iter() {
+            team.0 = 2;
+        }
+    });
+
+    world.run(|teams: View<Team>| {
+        assert!(teams.find(&0).is_empty());
+        assert!(teams.find(&1).is_empty());
+        assert_eq!(sorted(teams.find(&2)), vec![entity0, entity1]);
+    });
+
+    world.run(|mut entities: EntitiesViewMut, mut teams: ViewMut<Team>| {
+        let entity2 = entities.add_entity(&mut teams, Team(3));
+        teams.delete(entity0);
+
+        assert_eq!(teams.find(&2), &[entity1]);
+        assert_eq!(teams.find(&3), &[entity2]);
+    });
+
+    world.get::<&mut Team>(entity1).unwrap().0 = 4;
+    world.run(|teams: View<Team>| {
+        assert!(teams.find(&2).is_empty());
+        assert_eq!(teams.find(&4), &[entity1]);
+    });
+
+    for mut team in &mut world.iter::<&mut Team>() {
+        team.0 = 5;
+    }
+    world.run(|teams: View<Team>| {
+        assert!(teams.find(&4).is_empty());
+        assert_eq!(teams.find(&5).len(), 2);
+    });
+}
@@ -0,0 +1,140 @@
+use core::any::type_name;
+use core::mem::size_of;
+use shipyard::*;
+
+struct U32(u32);
+impl Component for U32 {}
+
+struct USIZE(usize);
+impl Component for USIZE {}
+
+struct Counter(u32);
+impl Unique for Counter {}
+
+fn sys1(_: View<U32>) {}
+
+#[test]
+fn sections() {
+    let mut world = World::new();
+    world.track_insertion::<U32>();
+    world.add_unique(Counter(0));
+    for i in 0..10 {
+        world.add_entity((U32(i), USIZE(i as usize)));
+    }
+
+    let report = world.memory_report();
+
+    let names = report
+        .storages
+        .iter()
+        .map(|storage| &*storage.storage_name)
+        .collect::<Vec<_>>();
+    assert!(names.windows(2).all(|names| names[0] <= names[1]));
+    assert_eq!(report.borrowed_storages, 0);
+
+    let u32s = report
+        .storages
+        .iter()
+        .find(|storage| storage.storage_name == type_name::<SparseSet<U32>>())
+        .unwrap();
+    assert_eq!(u32s.kind, StorageKind::SparseSet);
+    assert_eq!(u32s.component_count, 10);
+
+    let section = |name: &str| {
+        u32s.sections
+            .iter()
+            .find(|section| section.name == name)
+            .unwrap()
+    };
+    assert_eq!(section("sparse").len, 1);
+    assert_eq!(section("dense").len, 10);
+    assert_eq!(section("data").used_memory_bytes, 10 * size_of::<U32>());
+    assert_eq!(section("insertion_data").len, 10);
+    assert_eq!(section("modification_data").len, 0);
+
+    let sections_allocated = u32s
+        .sections
+        .iter()
+        .map(|section| section.allocated_memory_bytes)
+        .sum::<usize>();
+    assert_eq!(
+        u32s.allocated_memory_bytes,
+        sections_allocated + size_of::<SparseSet<U32>>()
+    );
+    assert_eq!(
+        u32s.breakdown.component_memory_bytes
+            + u32s.breakdown.sparse_memory_bytes
+            + u32s.breakdown.tracking_memory_bytes,
+        sections_allocated
+    );
+    assert_eq!(
+        u32s.breakdown.tracking_memory_bytes,
+        section("insertion_data").allocated_memory_bytes
+    );
+
+    let entities = report
+        .storages
+        .iter()
+        .find(|storage| storage.kind == StorageKind::Entities)
+        .unwrap();
+    assert_eq!(entities.component_count, 10);
+    assert_eq!(entities.sections.len(), 1);
+
+    let counter = report
+        .storages
+        .iter()
+        .find(|storage| storage.kind == StorageKind::Unique)
+        .unwrap();
+    assert_eq!(counter.component_count, 1);
+    assert!(counter.sections.is_empty());
+}
+
+#[test]
+fn totals() {
+    let mut world = World::new();
+    world.add_entity((U32(0),));
+
+    Workload::new("Update")
+        .with_system(sys1)
+        .add_to_world(&world)
+        .unwrap();
+
+    let report = world.memory_report();
+    let scheduler = report.scheduler.as_ref().unwrap();
+    assert_eq!(scheduler.system_count, 1);
+    assert_eq!(scheduler.workload_count, 1);
+
+    let storages_used = report
+        .storages
+        .iter()
+        .map(|storage| storage.used_memory_bytes)
+        .sum::<usize>();
+    assert_eq!(
+        report.used_memory_bytes,
+        storages_used + scheduler.used_memory_bytes
+    );
+    assert!(report.allocated_memory_bytes >= report.used_memory_bytes);
+
+    let all_storages_report = world
+        .borrow::<AllStoragesViewMut>()
+        .unwrap()
+        .memory_report();
+    assert!(all_storages_report.scheduler.is_none());
+    assert_eq!(all_storages_report.used_memory_bytes, storages_used);
+}
+
+#[test]
+fn borrowed_storage() {
+    let mut world = World::new();
+    world.add_entity((U32(0),));
+
+    let all_storages = world.borrow::<AllStoragesView>().unwrap();
+    let _u32s = all_storages.borrow::<ViewMut<U32>>().unwrap();
+
+    let report = all_storages.memory_report();
+    assert_eq!(report.borrowed_storages, 1);
+    assert!(report
+        .storages
+        .iter()
+        .all(|storage| storage.storage_name != type_name::<SparseSet<U32>>()));
+}
@@ -0,0 +1,107 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct Parent(EntityId);
+impl Component for Parent {}
+impl MapEntities for Parent {
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        self.0 = entity_map.map(self.0);
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct Target(EntityId);
+impl Component for Target {}
+impl MapEntities for Target {
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        self.0 = entity_map.map(self.0);
+    }
+}
+
+fn registry() -> ComponentRegistry {
+    let mut registry = ComponentRegistry::new();
+    registry.register::<Parent>("Parent").with_map_entities();
+    registry.register::<Target>("Target");
+    registry
+}
+
+#[test]
+fn merge_from() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    let entity0 = world1.add_entity((USIZE(0), Parent(EntityId::dead())));
+
+    let parent = world2.add_entity((USIZE(1),));
+    let child = world2.add_entity((USIZE(2), Parent(parent)));
+    let dead = world2.add_entity(());
+    world2.delete_entity(dead);
+    let orphan = world2.add_entity((Parent(dead),));
+
+    let entity_map = world1.merge_from(&mut world2, &registry());
+
+    assert_eq!(entity_map.len(), 3);
+    assert_eq!(entity_map.get(dead), None);
+    assert!(!world2.is_entity_alive(parent));
+    assert!(!world2.is_entity_alive(child));
+    assert!(world2.borrow::<View<USIZE>>().unwrap().is_empty());
+
+    let new_parent = entity_map.map(parent);
+    let new_child = entity_map.map(child);
+    let new_orphan = entity_map.map(orphan);
+
+    world1.run(|usizes: View<USIZE>, parents: View<Parent>| {
+        assert_eq!(usizes[entity0], USIZE(0));
+        assert_eq!(parents[entity0], Parent(EntityId::dead()));
+        assert_eq!(usizes[new_parent], USIZE(1));
+        assert_eq!(usizes[new_child], USIZE(2));
+        assert_eq!(parents[new_child], Parent(new_parent));
+        assert_eq!(parents[new_orphan], Parent(EntityId::dead()));
+    });
+}
+
+#[test]
+fn unregistered() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+
+    world1.add_entity(());
+    let target = world2.add_entity(());
+    let entity = world2.add_entity((Target(target),));
+
+    let entity_map = world1.merge_from(&mut world2, &registry());
+
+    assert_eq!(
+        world1
+            .get::<&Target>(entity_map.map(entity))
+            .as_deref()
+            .map(|target| target.0),
+        Ok(target)
+    );
+}
+
+#[test]
+fn group() {
+    let mut world1 = World::new();
+    let mut world2 = World::new();
+    world1.add_group::<(USIZE, Parent)>().unwrap();
+
+    let parent = world2.add_entity((USIZE(0),));
+    let child = world2.add_entity((USIZE(1), Parent(parent)));
+
+    let entity_map = world1.merge_from(&mut world2, &registry());
+
+    let (usizes, parents) = world1.borrow::<(View<USIZE>, View<Parent>)>().unwrap();
+    assert!(matches!((&usizes, &parents).iter(), iter::Iter::Tight(_)));
+    assert_eq!(
+        (&usizes, &parents).iter().with_id().collect::<Vec<_>>(),
+        vec![(
+            entity_map.map(child),
+            (&USIZE(1), &Parent(entity_map.map(parent)))
+        )]
+    );
+}
@@ -0,0 +1,121 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+struct Health(u32);
+impl Component for Health {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct Name(&'static str);
+impl Component for Name {}
+
+#[test]
+fn registry() {
+    let mut registry = ComponentRegistry::new();
+    registry.register::<Health>("Health");
+    registry.register::<Name>("Name");
+
+    assert_eq!(registry.len(), 2);
+    assert_eq!(
+        registry
+            .iter()
+            .map(ComponentRegistration::name)
+            .collect::<Vec<_>>(),
+        vec!["Health", "Name"]
+    );
+    assert_eq!(
+        registry
+            .get_by_storage_id(StorageId::of::<SparseSet<Name>>())
+            .map(ComponentRegistration::name),
+        Some("Name")
+    );
+    assert!(registry.get("Position").is_none());
+
+    registry.register::<Name>("Health");
+
+    assert_eq!(registry.len(), 2);
+    assert_eq!(
+        registry.get("Health").unwrap().storage_id(),
+        StorageId::of::<SparseSet<Name>>()
+    );
+    assert!(registry
+        .get_by_storage_id(StorageId::of::<SparseSet<Health>>())
+        .is_none());
+}
+
+#[test]
+fn access() {
+    let mut registry = ComponentRegistry::new();
+    registry.register::<Health>("Health");
+
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    let health = registry.get("Health").unwrap();
+    assert!(health.list(&all_storages).unwrap().is_empty());
+
+    let entity0 = all_storages.add_entity((Health(0),));
+    let entity1 = all_storages.add_entity((Health(1), Name("1")));
+
+    assert_eq!(health.list(&all_storages).unwrap(), vec![entity0, entity1]);
+    assert_eq!(
+        health
+            .get(&all_storages, entity1)
+            .unwrap()
+            .downcast_ref::<Health>(),
+        Some(&Health(1))
+    );
+
+    let removed = health.remove(&mut all_storages, entity0).unwrap();
+    assert_eq!(removed.downcast_ref::<Health>(), Some(&Health(0)));
+    assert!(health.remove(&mut all_storages, entity0).is_none());
+    assert!(matches!(
+        health.get(&all_storages, entity0),
+        Err(error::GetComponent::MissingComponent(_))
+    ));
+    assert_eq!(health.list(&all_storages).unwrap(), vec![entity1]);
+}
+
+#[cfg(feature = "serde1")]
+#[test]
+fn serde() {
+    let mut registry = ComponentRegistry::new();
+    registry.register_serde::<Health>("Health");
+    registry.register::<Name>("Name");
+
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    let entity = all_storages.add_entity((Health(10), Name("entity")));
+
+    let health = registry.get("Health").unwrap();
+    assert_eq!(
+        health.get_value(&all_storages, entity).unwrap(),
+        serde_json::json!(10)
+    );
+
+    health
+        .insert_value(&mut all_storages, entity, serde_json::json!(20))
+        .unwrap();
+    assert_eq!(
+        all_storages.get::<&Health>(entity).as_deref(),
+        Ok(&&Health(20))
+    );
+
+    assert!(matches!(
+        health.insert_value(&mut all_storages, entity, serde_json::json!("20")),
+        Err(error::Reflect::Serde(_))
+    ));
+
+    let name = registry.get("Name").unwrap();
+    assert!(matches!(
+        name.get_value(&all_storages, entity),
+        Err(error::Reflect::NotSerde(_))
+    ));
+
+    all_storages.delete_entity(entity);
+    assert!(matches!(
+        health.insert_value(&mut all_storages, entity, serde_json::json!(20)),
+        Err(error::Reflect::EntityIsNotAlive)
+    ));
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use shipyard::info::PublisherMessage;
+use shipyard::*;
+use std::io::{BufRead, BufReader};
+
+#[derive(Serialize, Deserialize)]
+struct Health(u32);
+impl Component for Health {}
+
+struct Player;
+impl Component for Player {}
+
+struct NotRegistered;
+impl Component for NotRegistered {}
+
+fn registry() -> ComponentRegistry {
+    let mut registry = ComponentRegistry::new();
+    registry.register_serde::<Health>("Health");
+    registry.register::<Player>("Player");
+    registry
+}
+
+#[test]
+fn inspect() {
+    let mut world = World::new();
+    let entity0 = world.add_entity((Health(0), NotRegistered));
+    let entity1 = world.add_entity(());
+    let entity2 = world.add_entity((Health(2), Player));
+    world.delete_entity(entity1);
+
+    let inspection = world.inspect(&registry()).unwrap();
+
+    let ids = inspection
+        .entities
+        .iter()
+        .map(|entity| entity.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![entity0, entity2]);
+    assert_eq!(
+        inspection.entities[0].components.keys().collect::<Vec<_>>(),
+        vec!["Health"]
+    );
+    assert_eq!(
+        inspection.entities[1].components["Health"],
+        Some(serde_json::json!(2))
+    );
+    assert_eq!(inspection.with_component("Player").count(), 1);
+
+    let names = inspection
+        .storages
+        .iter()
+        .map(|usage| &*usage.storage_name)
+        .collect::<Vec<_>>();
+    assert!(names.contains(&std::any::type_name::<SparseSet<NotRegistered>>()));
+    assert!(names.windows(2).all(|names| names[0] <= names[1]));
+
+    let json = serde_json::to_string(&inspection).unwrap();
+    let deserialized: Inspection = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, inspection);
+}
+
+#[test]
+fn publish() {
+    let path = std::env::temp_dir().join(format!("shipyard_inspect_{}.jsonl", std::process::id()));
+
+    let mut world = World::new();
+    world.add_entity((Health(0),));
+    world.publish_inspection(&registry()).unwrap();
+    let inspection = world.inspect(&registry()).unwrap();
+
+    world.set_publisher(Publisher::file(&path).unwrap());
+    world.publish_inspection(&registry()).unwrap();
+    drop(world.take_publisher());
+
+    let lines = BufReader::new(std::fs::File::open(&path).unwrap())
+        .lines()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(lines.len(), 1);
+    assert_eq!(
+        serde_json::from_str::<PublisherMessage>(&lines[0]).unwrap(),
+        PublisherMessage::Inspection(inspection)
+    );
+}
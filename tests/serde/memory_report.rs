@@ -0,0 +1,23 @@
+use shipyard::*;
+
+struct U32(u32);
+impl Component for U32 {}
+
+#[test]
+fn roundtrip() {
+    let mut world = World::new();
+    world.add_entity((U32(0),));
+
+    let report = world.memory_report();
+
+    let json = serde_json::to_string(&report).unwrap();
+    let deserialized: MemoryReport = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.storages.len(), report.storages.len());
+    assert_eq!(deserialized.used_memory_bytes, report.used_memory_bytes);
+    assert_eq!(deserialized.scheduler, report.scheduler);
+    for (storage, other) in deserialized.storages.iter().zip(&report.storages) {
+        assert_eq!(storage.kind, other.kind);
+        assert_eq!(storage.sections, other.sections);
+    }
+}
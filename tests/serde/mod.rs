@@ -1 +1,7 @@
 mod entity_id;
+mod inspect;
+mod memory_report;
+mod publisher;
+mod replication;
+mod scene;
+mod workload_info;
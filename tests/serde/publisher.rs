@@ -0,0 +1,168 @@
+use shipyard::info::PublisherMessage;
+use shipyard::*;
+use std::io::{BufRead, BufReader};
+use std::net::TcpStream;
+
+struct U32(u32);
+impl Component for U32 {}
+
+fn read(_: View<U32>) {}
+fn write(_: ViewMut<U32>) {}
+
+fn messages<R: BufRead>(reader: R, count: usize) -> Vec<PublisherMessage> {
+    reader
+        .lines()
+        .take(count)
+        .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+        .collect()
+}
+
+#[test]
+fn loopback() {
+    let world = World::new();
+    let publisher = Publisher::listen("127.0.0.1:0").unwrap();
+    let addr = publisher.local_addr().unwrap();
+    world.set_publisher(publisher);
+
+    Workload::new("Read")
+        .with_system(read)
+        .add_to_world(&world)
+        .unwrap();
+
+    // clients connecting late receive the workloads already published
+    let client = TcpStream::connect(addr).unwrap();
+
+    Workload::new("Write")
+        .with_system(read)
+        .with_system(write)
+        .add_to_world(&world)
+        .unwrap();
+    world.run_workload("Write").unwrap();
+    world.set_default_workload("Read").unwrap();
+    world.run_default().unwrap();
+
+    let messages = messages(BufReader::new(client), 4);
+
+    match &messages[0] {
+        PublisherMessage::Workload(workload) => assert_eq!(workload.name, "\"Read\""),
+        message => panic!("{:?}", message),
+    }
+    match &messages[1] {
+        PublisherMessage::Workload(workload) => assert_eq!(workload.batches.len(), 2),
+        message => panic!("{:?}", message),
+    }
+    match &messages[2] {
+        PublisherMessage::Timings(timings) => {
+            assert_eq!(timings.workload, "\"Write\"");
+            assert_eq!(timings.frame, 0);
+            assert_eq!(timings.systems.len(), 2);
+            assert!(timings.systems[0].name.contains("read"));
+            assert!(timings.systems[1].name.contains("write"));
+        }
+        message => panic!("{:?}", message),
+    }
+    match &messages[3] {
+        PublisherMessage::Timings(timings) => {
+            assert_eq!(timings.workload, "\"Read\"");
+            assert_eq!(timings.frame, 1);
+            assert_eq!(timings.systems.len(), 1);
+        }
+        message => panic!("{:?}", message),
+    }
+}
+
+#[test]
+fn skipped_workloads() {
+    let path = std::env::temp_dir().join(format!(
+        "shipyard_publisher_skipped_{}.jsonl",
+        std::process::id()
+    ));
+
+    let world = World::new();
+    world.set_publisher(Publisher::file(&path).unwrap());
+
+    Workload::new("Init")
+        .with_system(read)
+        .run_once()
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("Never")
+        .with_system(read)
+        .run_if(|| false)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Init").unwrap();
+    world.run_workload("Init").unwrap();
+    world.run_workload("Never").unwrap();
+    world.reset_run_once();
+    world.run_workload("Init").unwrap();
+    drop(world.take_publisher());
+
+    let file = std::fs::File::open(&path).unwrap();
+    let messages = messages(BufReader::new(file), usize::MAX);
+    std::fs::remove_file(&path).unwrap();
+
+    let frames = messages
+        .iter()
+        .filter_map(|message| match message {
+            PublisherMessage::Timings(timings) => Some((timings.workload.as_str(), timings.frame)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(frames, vec![("\"Init\"", 0), ("\"Init\"", 1)]);
+}
+
+#[test]
+fn stalled_client() {
+    let world = World::new();
+    let publisher = Publisher::listen("127.0.0.1:0").unwrap();
+    let addr = publisher.local_addr().unwrap();
+    world.set_publisher(publisher);
+
+    Workload::new("Read")
+        .with_system(read)
+        .add_to_world(&world)
+        .unwrap();
+
+    // never reads, the socket buffers eventually fill up
+    let _client = TcpStream::connect(addr).unwrap();
+
+    let dropped = (0..1_000).any(|_| {
+        for _ in 0..1_000 {
+            world.run_workload("Read").unwrap();
+        }
+
+        let publisher = world.take_publisher().unwrap();
+        let dropped = format!("{:?}", publisher).contains("sink_count: 0");
+        world.set_publisher(publisher);
+
+        dropped
+    });
+    assert!(dropped);
+}
+
+#[test]
+fn file() {
+    let path =
+        std::env::temp_dir().join(format!("shipyard_publisher_{}.jsonl", std::process::id()));
+
+    let world = World::new();
+    world.set_publisher(Publisher::file(&path).unwrap());
+
+    Workload::new("Read")
+        .with_system(read)
+        .add_to_world(&world)
+        .unwrap();
+    world.run_workload("Read").unwrap();
+    drop(world.take_publisher());
+    world.run_workload("Read").unwrap();
+
+    let file = std::fs::File::open(&path).unwrap();
+    let messages = messages(BufReader::new(file), usize::MAX);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(messages.len(), 2);
+    assert!(matches!(messages[0], PublisherMessage::Workload(_)));
+    assert!(matches!(messages[1], PublisherMessage::Timings(_)));
+}
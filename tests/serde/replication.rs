@@ -0,0 +1,190 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use shipyard::*;
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+struct Health(u32);
+impl Component for Health {}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+struct Parent(EntityId);
+impl Component for Parent {}
+impl MapEntities for Parent {
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        self.0 = entity_map.map(self.0);
+    }
+}
+
+struct Bincode;
+
+impl ReplicationFormat for Bincode {
+    type Error = bincode::Error;
+
+    fn serialize<T: Serialize>(component: &T) -> Result<Vec<u8>, bincode::Error> {
+        bincode::serialize(component)
+    }
+    fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
+}
+
+struct Loopback<F: ReplicationFormat> {
+    replication: Replication<F>,
+    /// Sends a `ChangeSet` over the network.
+    transport: fn(&ChangeSet) -> ChangeSet,
+    server: World,
+    client: World,
+    since: TrackingTimestamp,
+    entity_map: EntityMap,
+}
+
+impl<F: ReplicationFormat> Loopback<F> {
+    fn new(transport: fn(&ChangeSet) -> ChangeSet) -> Loopback<F> {
+        let mut replication = Replication::<F>::with_format();
+        replication.register::<Health>("Health");
+        replication.register_map_entities::<Parent>("Parent");
+
+        let mut server = World::new();
+        server.track_all::<(Health, Parent)>();
+
+        let mut client = World::new();
+        // offsets client ids from server ids
+        client.add_entity(());
+
+        Loopback {
+            replication,
+            transport,
+            since: server.get_tracking_timestamp(),
+            server,
+            client,
+            entity_map: EntityMap::new(),
+        }
+    }
+
+    fn sync(&mut self) -> ChangeSet {
+        let since = self.server.get_tracking_timestamp();
+        let change_set = self
+            .server
+            .change_set(&self.replication, self.since)
+            .unwrap();
+        self.since = since;
+
+        let change_set = (self.transport)(&change_set);
+        self.client
+            .apply_change_set(&change_set, &self.replication, &mut self.entity_map)
+            .unwrap();
+
+        self.assert_in_sync();
+
+        change_set
+    }
+
+    fn assert_in_sync(&self) {
+        let server_healths = self.server.borrow::<View<Health>>().unwrap();
+        let server_parents = self.server.borrow::<View<Parent>>().unwrap();
+        let client_healths = self.client.borrow::<View<Health>>().unwrap();
+        let client_parents = self.client.borrow::<View<Parent>>().unwrap();
+
+        assert_eq!(server_healths.len(), client_healths.len());
+        for (entity, health) in server_healths.iter().with_id() {
+            assert_eq!(&client_healths[self.entity_map.map(entity)], health);
+        }
+
+        let client_entities = self.client.borrow::<EntitiesView>().unwrap();
+        assert_eq!(server_parents.len(), client_parents.len());
+        for (entity, parent) in server_parents.iter().with_id() {
+            let client_parent = &client_parents[self.entity_map.map(entity)];
+
+            match self.entity_map.get(parent.0) {
+                Some(parent) => assert_eq!(client_parent, &Parent(parent)),
+                // the parent was deleted on both sides
+                None => assert!(!client_entities.is_alive(client_parent.0)),
+            }
+        }
+    }
+}
+
+fn json(change_set: &ChangeSet) -> ChangeSet {
+    serde_json::from_str(&serde_json::to_string(change_set).unwrap()).unwrap()
+}
+
+fn bincode(change_set: &ChangeSet) -> ChangeSet {
+    bincode::deserialize(&bincode::serialize(change_set).unwrap()).unwrap()
+}
+
+#[test]
+fn loopback() {
+    replicate(Loopback::<Json>::new(json));
+}
+
+#[test]
+fn loopback_bincode() {
+    replicate(Loopback::<Bincode>::new(bincode));
+}
+
+fn replicate<F: ReplicationFormat>(mut loopback: Loopback<F>) {
+    let parent = loopback.server.add_entity((Health(10),));
+    let child = loopback.server.add_entity((Health(5), Parent(parent)));
+    let change_set = loopback.sync();
+    assert_eq!(change_set.new_entities, vec![parent, child]);
+    assert_ne!(loopback.entity_map.map(parent), parent);
+
+    assert!(loopback.sync().is_empty());
+
+    loopback.server.run(|mut healths: ViewMut<Health>| {
+        healths[child].0 -= 1;
+    });
+    loopback.server.add_component(parent, (Parent(child),));
+    let change_set = loopback.sync();
+    assert!(change_set.new_entities.is_empty());
+    assert_eq!(change_set.components["Health"].updated.len(), 1);
+
+    loopback.server.remove::<(Health,)>(parent);
+    let other = loopback.server.add_entity((Health(1),));
+    loopback.server.delete_entity(child);
+    let change_set = loopback.sync();
+    assert_eq!(change_set.new_entities, vec![other]);
+    assert_eq!(change_set.deleted_entities, vec![child]);
+    assert_eq!(change_set.components["Health"].removed, vec![parent]);
+    assert!(loopback.entity_map.get(child).is_none());
+    assert_eq!(
+        loopback
+            .client
+            .borrow::<EntitiesView>()
+            .unwrap()
+            .iter()
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn errors() {
+    let mut replication = Replication::new();
+    replication.register::<Health>("Health");
+
+    let mut world = World::new();
+    let since = world.get_tracking_timestamp();
+    assert!(matches!(
+        world.change_set(&replication, since),
+        Err(error::Replication::Untracked(_))
+    ));
+
+    let change_set: ChangeSet = serde_json::from_str(
+        r#"{"new_entities":[],"deleted_entities":[],"components":{"Position":{"updated":[],"removed":[]}}}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        world.apply_change_set(&change_set, &replication, &mut EntityMap::new()),
+        Err(error::Replication::UnknownComponent(name)) if name == "Position"
+    ));
+
+    let change_set: ChangeSet = serde_json::from_str(
+        r#"{"new_entities":[],"deleted_entities":[],"components":{"Health":{"updated":[[{"index":0,"gen":0},[0]]],"removed":[]}}}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        world.apply_change_set(&change_set, &replication, &mut EntityMap::new()),
+        Err(error::Replication::Format(_))
+    ));
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use shipyard::*;
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+struct Health(u32);
+impl Component for Health {}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+struct Parent(EntityId);
+impl Component for Parent {}
+impl MapEntities for Parent {
+    fn map_entities(&mut self, entity_map: &EntityMap) {
+        self.0 = entity_map.map(self.0);
+    }
+}
+
+struct NotSerde;
+impl Component for NotSerde {}
+
+fn registry() -> ComponentRegistry {
+    let mut registry = ComponentRegistry::new();
+    registry.register_serde::<Health>("Health");
+    registry.register_serde_map_entities::<Parent>("Parent");
+    registry.register::<NotSerde>("NotSerde");
+    registry
+}
+
+#[test]
+fn roundtrip() {
+    let registry = registry();
+    let mut world1 = World::new();
+
+    let outside = world1.add_entity((Health(0),));
+    let parent = world1.add_entity((Health(1), NotSerde));
+    let child = world1.add_entity((Health(2), Parent(parent)));
+    let orphan = world1.add_entity((Parent(outside),));
+    let dead = world1.add_entity(());
+    world1.delete_entity(dead);
+
+    let scene = world1
+        .export_scene(&[child, parent, dead, orphan, child], &registry)
+        .unwrap();
+
+    let local_child = EntityId::new_from_index_and_gen(0, 0);
+    let local_parent = EntityId::new_from_index_and_gen(1, 0);
+    let local_orphan = EntityId::new_from_index_and_gen(2, 0);
+    assert_eq!(
+        scene
+            .entities
+            .iter()
+            .map(|scene_entity| scene_entity.id)
+            .collect::<Vec<_>>(),
+        vec![local_child, local_parent, local_orphan]
+    );
+    assert_eq!(
+        scene.entities[0].components["Parent"],
+        serde_json::to_value(local_parent).unwrap()
+    );
+    assert_eq!(
+        scene.entities[1].components.keys().collect::<Vec<_>>(),
+        vec!["Health"]
+    );
+    assert_eq!(
+        scene.entities[2].components["Parent"],
+        serde_json::to_value(EntityId::dead()).unwrap()
+    );
+
+    let scene: Scene = serde_json::from_str(&serde_json::to_string(&scene).unwrap()).unwrap();
+
+    let mut world2 = World::new();
+    world2.add_entity(());
+    let entity_map = world2.spawn_scene(&scene, &registry).unwrap();
+
+    let new_child = entity_map.map(local_child);
+    let new_parent = entity_map.map(local_parent);
+    let new_orphan = entity_map.map(local_orphan);
+    world2.run(|healths: View<Health>, parents: View<Parent>| {
+        assert_eq!(healths[new_child], Health(2));
+        assert_eq!(parents[new_child], Parent(new_parent));
+        assert_eq!(healths[new_parent], Health(1));
+        assert_eq!(parents[new_orphan], Parent(EntityId::dead()));
+        assert_eq!(healths.len(), 2);
+    });
+}
+
+#[test]
+fn spawn_errors() {
+    let registry = registry();
+    let mut world = World::new();
+    world.track_deletion::<Health>();
+
+    let scene: Scene = serde_json::from_str(
+        r#"{"entities":[{"id":{"index":0,"gen":0},"components":{"Position":0}}]}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        world.spawn_scene(&scene, &registry),
+        Err(error::Scene::UnknownComponent(name)) if name == "Position"
+    ));
+
+    let scene: Scene = serde_json::from_str(
+        r#"{"entities":[{"id":{"index":0,"gen":0},"components":{}},{"id":{"index":0,"gen":0},"components":{}}]}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        world.spawn_scene(&scene, &registry),
+        Err(error::Scene::DuplicateEntity(_))
+    ));
+
+    let scene: Scene = serde_json::from_str(
+        r#"{"entities":[{"id":{"index":0,"gen":0},"components":{"Health":0}},{"id":{"index":1,"gen":0},"components":{"Health":"0"}}]}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        world.spawn_scene(&scene, &registry),
+        Err(error::Scene::Reflect(error::Reflect::Serde(_)))
+    ));
+
+    assert!(world.borrow::<View<Health>>().unwrap().is_empty());
+    assert_eq!(world.borrow::<EntitiesView>().unwrap().iter().count(), 0);
+    assert_eq!(
+        world
+            .borrow::<View<Health, track::Deletion>>()
+            .unwrap()
+            .deleted()
+            .count(),
+        0
+    );
+    // no entity was generated then deleted
+    assert_eq!(world.add_entity(()), EntityId::new_from_index_and_gen(0, 0));
+}
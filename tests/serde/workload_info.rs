@@ -0,0 +1,43 @@
+use shipyard::info::{ConflictExport, WorkloadInfoExport};
+use shipyard::*;
+
+struct U32;
+impl Component for U32 {}
+
+fn read(_: View<U32>) {}
+fn write(_: ViewMut<U32>) {}
+fn read_again(_: View<U32>) {}
+
+#[test]
+fn export() {
+    let world = World::new();
+
+    let info = Workload::new("Workload")
+        .with_system(read)
+        .with_system(write)
+        .with_system(read_again)
+        .add_to_world(&world)
+        .unwrap();
+
+    let export = WorkloadInfoExport::from(&info);
+    let json = serde_json::to_string(&export).unwrap();
+    let export: WorkloadInfoExport = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(export.name, format!("{:?}", "Workload"));
+    assert_eq!(export.batches.len(), 3);
+
+    let write_info = &export.batches[1].systems[0];
+    assert!(write_info.name.contains("::write"));
+    match &write_info.conflict {
+        Some(ConflictExport::Borrow {
+            type_info: Some(type_info),
+            other_system,
+            other_type_info,
+        }) => {
+            assert_eq!(type_info.mutability, Mutability::Exclusive);
+            assert!(other_system.contains("::read"));
+            assert_eq!(other_type_info.mutability, Mutability::Shared);
+        }
+        conflict => panic!("unexpected conflict {:?}", conflict),
+    }
+}
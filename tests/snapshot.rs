@@ -0,0 +1,139 @@
+use shipyard::*;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct NotRegistered;
+impl Component for NotRegistered {}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Frame(u32);
+impl Unique for Frame {}
+
+#[test]
+fn rollback() {
+    let mut world = World::new();
+    let mut snapshots = Snapshots::new(4);
+    snapshots.register::<U32>();
+    snapshots.register_unique::<Frame>();
+
+    let entity0 = world.add_entity((U32(0),));
+    let entity1 = world.add_entity((U32(1), NotRegistered));
+    world.save_snapshot(&mut snapshots);
+
+    world.delete_entity(entity1);
+    world.add_component(entity0, (U32(10),));
+    let entity2 = world.add_entity((U32(2), NotRegistered));
+    world.add_unique(Frame(1));
+    world.save_snapshot(&mut snapshots);
+    assert_eq!(snapshots.len(), 2);
+
+    world.rollback(&mut snapshots, 1).unwrap();
+    assert_eq!(snapshots.len(), 1);
+
+    world.run(
+        |entities: EntitiesView, u32s: View<U32>, not_registered: View<NotRegistered>| {
+            assert!(entities.is_alive(entity0));
+            assert!(entities.is_alive(entity1));
+            assert!(!entities.is_alive(entity2));
+            assert_eq!(u32s[entity0], U32(0));
+            assert_eq!(u32s[entity1], U32(1));
+            assert_eq!(u32s.len(), 2);
+            assert!(!not_registered.contains(entity2));
+        },
+    );
+    assert!(world.borrow::<UniqueView<Frame>>().is_err());
+
+    // generations and removed entities are restored, replaying gives the same ids
+    world.delete_entity(entity1);
+    assert_eq!(world.add_entity(()), entity2);
+
+    assert_eq!(
+        world.rollback(&mut snapshots, 1),
+        Err(error::Rollback::MissingSnapshot(1))
+    );
+}
+
+#[test]
+fn ring_buffer() {
+    let mut world = World::new();
+    let mut snapshots = Snapshots::new(2);
+    snapshots.register_unique::<Frame>();
+
+    world.add_unique(Frame(0));
+    for i in 1..=3 {
+        world.run(|mut frame: UniqueViewMut<Frame>| frame.0 = i);
+        world.save_snapshot(&mut snapshots);
+    }
+    assert_eq!(snapshots.len(), 2);
+
+    world.rollback(&mut snapshots, 1).unwrap();
+    assert_eq!(*world.borrow::<UniqueView<Frame>>().unwrap(), Frame(2));
+
+    world.remove_unique::<Frame>().unwrap();
+    world.rollback(&mut snapshots, 0).unwrap();
+    assert_eq!(*world.borrow::<UniqueView<Frame>>().unwrap(), Frame(2));
+}
+
+#[test]
+fn tracking() {
+    let mut world = World::new();
+    world.track_all::<U32>();
+    let mut snapshots = Snapshots::new(1);
+    snapshots.register::<U32>();
+
+    let entity0 = world.add_entity((U32(0),));
+    world.run(|u32s: ViewMut<U32, track::All>| u32s.clear_all_inserted());
+    let entity1 = world.add_entity((U32(1),));
+    world.save_snapshot(&mut snapshots);
+
+    world.run(|mut u32s: ViewMut<U32, track::All>| u32s[entity0].0 += 1);
+    world.run(|u32s: ViewMut<U32, track::All>| u32s.clear_all_inserted());
+    world.delete_entity(entity1);
+
+    world.rollback(&mut snapshots, 0).unwrap();
+
+    world.run(|u32s: View<U32, track::All>| {
+        assert_eq!(
+            u32s.inserted().iter().with_id().collect::<Vec<_>>(),
+            vec![(entity1, &U32(1))]
+        );
+        assert_eq!(u32s.modified().iter().count(), 0);
+        assert_eq!(u32s.deleted().count(), 0);
+    });
+}
+
+#[test]
+fn group() {
+    let mut world = World::new();
+    world.add_group::<(U32, USIZE)>().unwrap();
+    let mut snapshots = Snapshots::new(1);
+    snapshots.register::<U32>();
+
+    let entity0 = world.add_entity((U32(0), USIZE(0)));
+    let entity1 = world.add_entity((U32(1),));
+    world.save_snapshot(&mut snapshots);
+
+    world.add_component(entity1, (USIZE(1),));
+    world.remove::<(U32,)>(entity0);
+
+    world.rollback(&mut snapshots, 0).unwrap();
+
+    let (u32s, usizes) = world.borrow::<(View<U32>, View<USIZE>)>().unwrap();
+    assert!(matches!((&u32s, &usizes).iter(), iter::Iter::Tight(_)));
+    let mut packed = (&u32s, &usizes).iter().with_id().collect::<Vec<_>>();
+    packed.sort_by_key(|(entity, _)| *entity);
+    assert_eq!(
+        packed,
+        vec![
+            (entity0, (&U32(0), &USIZE(0))),
+            (entity1, (&U32(1), &USIZE(1)))
+        ]
+    );
+}
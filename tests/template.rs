@@ -0,0 +1,126 @@
+use shipyard::*;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct NotClone;
+impl Component for NotClone {}
+
+fn registry() -> ComponentRegistry {
+    let mut registry = ComponentRegistry::new();
+    registry.register::<U32>("U32").with_clone();
+    registry.register::<USIZE>("USIZE").with_clone();
+    registry.register::<NotClone>("NotClone");
+    registry
+}
+
+#[test]
+fn clone_entity() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((U32(0), USIZE(1), NotClone));
+    let clone = world.clone_entity(entity, &registry());
+
+    assert_ne!(entity, clone);
+    world.run(
+        |u32s: View<U32>, usizes: View<USIZE>, not_clones: View<NotClone>| {
+            assert_eq!(u32s[clone], U32(0));
+            assert_eq!(usizes[clone], USIZE(1));
+            assert!(!not_clones.contains(clone));
+            assert_eq!(u32s[entity], U32(0));
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Entity has to be alive to be cloned.")]
+fn clone_dead_entity() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world.delete_entity(entity);
+
+    world.clone_entity(entity, &registry());
+}
+
+#[test]
+fn clone_entity_group() {
+    let mut world = World::new();
+    world.add_group::<(U32, USIZE)>().unwrap();
+
+    let entity = world.add_entity((U32(0), USIZE(1)));
+    let clone = world.clone_entity(entity, &registry());
+
+    let (u32s, usizes) = world.borrow::<(View<U32>, View<USIZE>)>().unwrap();
+    assert!(matches!((&u32s, &usizes).iter(), iter::Iter::Tight(_)));
+    assert_eq!(
+        (&u32s, &usizes).iter().with_id().collect::<Vec<_>>(),
+        vec![
+            (entity, (&U32(0), &USIZE(1))),
+            (clone, (&U32(0), &USIZE(1)))
+        ]
+    );
+}
+
+#[test]
+fn template() {
+    let mut template = Template::new().with(U32(0)).with(USIZE(0));
+    template.insert(U32(1));
+
+    assert_eq!(template.len(), 2);
+
+    let mut world = World::new();
+
+    let entity = world.spawn_template(&template);
+
+    template.remove::<USIZE>();
+    let entity_without_usize = world.spawn_template(&template);
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert_eq!(u32s[entity], U32(1));
+        assert_eq!(usizes[entity], USIZE(0));
+        assert_eq!(u32s[entity_without_usize], U32(1));
+        assert!(!usizes.contains(entity_without_usize));
+    });
+}
+
+#[test]
+fn bulk_spawn_template() {
+    let mut world = World::new();
+    world.add_group::<(U32, USIZE)>().unwrap();
+
+    let template = Template::new().with(U32(0));
+
+    let entities = world
+        .bulk_spawn_template(&template, (0..3).map(|_| ()))
+        .collect::<Vec<_>>();
+    assert_eq!(entities.len(), 3);
+
+    let overridden = world
+        .bulk_spawn_template(&template, (0..3).map(|i| (U32(i + 1), USIZE(i as usize))))
+        .collect::<Vec<_>>();
+
+    let (u32s, usizes) = world.borrow::<(View<U32>, View<USIZE>)>().unwrap();
+    assert_eq!(
+        entities
+            .iter()
+            .map(|&entity| &u32s[entity])
+            .collect::<Vec<_>>(),
+        vec![&U32(0), &U32(0), &U32(0)]
+    );
+    assert!(matches!((&u32s, &usizes).iter(), iter::Iter::Tight(_)));
+    assert_eq!(
+        (&u32s, &usizes).iter().with_id().collect::<Vec<_>>(),
+        vec![
+            (overridden[0], (&U32(1), &USIZE(0))),
+            (overridden[1], (&U32(2), &USIZE(1))),
+            (overridden[2], (&U32(3), &USIZE(2)))
+        ]
+    );
+}
@@ -0,0 +1,346 @@
+use shipyard::*;
+
+struct Counter(Vec<&'static str>);
+impl Component for Counter {}
+impl Unique for Counter {}
+
+struct Position;
+impl Component for Position {}
+
+fn world() -> World {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Counter(Vec::new()));
+
+    Workload::new("Update")
+        .with_system(a)
+        .with_system(b)
+        .with_system(c)
+        .add_to_world(&world)
+        .unwrap();
+
+    world
+}
+
+fn calls(world: &World) -> Vec<&'static str> {
+    world.borrow::<UniqueView<Counter>>().unwrap().0.clone()
+}
+
+fn a(mut counter: UniqueViewMut<Counter>) {
+    counter.0.push("a");
+}
+fn b(mut counter: UniqueViewMut<Counter>) {
+    counter.0.push("b");
+}
+fn c(mut counter: UniqueViewMut<Counter>) {
+    counter.0.push("c");
+}
+fn d(mut counter: UniqueViewMut<Counter>) {
+    counter.0.push("d");
+}
+
+#[test]
+fn remove() {
+    let world = world();
+
+    let info = world.remove_system("Update", b).unwrap();
+    assert_eq!(info.batch_info.len(), 2);
+
+    world.run_workload("Update").unwrap();
+    assert_eq!(calls(&world), vec!["a", "c"]);
+
+    assert!(matches!(
+        world.remove_system("Update", b),
+        Err(error::EditWorkload::MissingSystem(_))
+    ));
+    assert!(matches!(
+        world.remove_system("Draw", a),
+        Err(error::EditWorkload::MissingWorkload)
+    ));
+}
+
+#[test]
+fn remove_all() {
+    let world = world();
+
+    world.remove_system("Update", a).unwrap();
+    world.remove_system("Update", b).unwrap();
+    let info = world.remove_system("Update", c).unwrap();
+    assert!(info.batch_info.is_empty());
+
+    world.run_workload("Update").unwrap();
+    assert!(calls(&world).is_empty());
+
+    world.insert_system("Update", d).unwrap();
+    world.run_workload("Update").unwrap();
+    assert_eq!(calls(&world), vec!["d"]);
+}
+
+#[test]
+fn remove_keeps_batches() {
+    struct Velocity;
+    impl Component for Velocity {}
+
+    fn movement(_: ViewMut<Position>) {}
+    fn physics(_: ViewMut<Velocity>) {}
+    fn render(_: View<Position>) {}
+    fn ui(_: View<Position>, _: View<Velocity>) {}
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let info = Workload::new("Update")
+        .with_system(movement)
+        .with_system(physics)
+        .with_system(render)
+        .with_system(ui)
+        .add_to_world(&world)
+        .unwrap();
+    assert_eq!(info.batch_info.len(), 2);
+    assert_eq!(info.batch_info[1].systems.1.len(), 2);
+
+    let info = world.remove_system("Update", movement).unwrap();
+    assert_eq!(info.batch_info.len(), 2);
+    assert_eq!(info.batch_info[0].systems.1.len(), 1);
+    assert_eq!(info.batch_info[1].systems.1.len(), 2);
+    assert!(format!("{:?}", info.batch_info[0].systems.1[0].name).contains("physics"));
+
+    let info = world.remove_system("Update", physics).unwrap();
+    assert_eq!(info.batch_info.len(), 1);
+    assert_eq!(info.batch_info[0].systems.1.len(), 2);
+
+    world.run_workload("Update").unwrap();
+}
+
+#[test]
+fn remove_keeps_requirements() {
+    struct Velocity;
+    impl Component for Velocity {}
+
+    fn read(_: View<Position>) {}
+    fn read_after(_: View<Position>) {}
+    fn write(_: ViewMut<Velocity>) {}
+    fn read_velocity(_: View<Velocity>) {}
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let info = Workload::new("Update")
+        .with_system(read)
+        .with_system(read_after.after_all(read))
+        .with_system(write)
+        .with_system(read_velocity)
+        .add_to_world(&world)
+        .unwrap();
+    assert_eq!(info.batch_info.len(), 2);
+
+    let info = world.remove_system("Update", write).unwrap();
+    assert_eq!(info.batch_info.len(), 2);
+    assert_eq!(info.batch_info[1].systems.1.len(), 2);
+    assert!(info.batch_info[1]
+        .systems
+        .1
+        .iter()
+        .any(|system| format!("{:?}", system.name).contains("read_after")));
+
+    world.remove_system("Update", read_velocity).unwrap();
+    world.remove_system("Update", read).unwrap();
+    world.run_workload("Update").unwrap();
+}
+
+#[test]
+fn free_systems() {
+    fn system_count(world: &World) -> usize {
+        world.memory_report().scheduler.unwrap().system_count
+    }
+
+    let world = world();
+
+    Workload::new("Draw")
+        .with_system(c)
+        .add_to_world(&world)
+        .unwrap();
+    assert_eq!(system_count(&world), 3);
+
+    world.remove_system("Update", b).unwrap();
+    assert_eq!(system_count(&world), 2);
+
+    // still used by "Draw"
+    world.remove_system("Update", c).unwrap();
+    assert_eq!(system_count(&world), 2);
+
+    world.replace_system("Update", a, d).unwrap();
+    assert_eq!(system_count(&world), 2);
+
+    world.insert_system("Update", b).unwrap();
+    assert_eq!(system_count(&world), 3);
+
+    world.run_workload("Update").unwrap();
+    world.run_workload("Draw").unwrap();
+    assert_eq!(calls(&world), vec!["d", "b", "c"]);
+}
+
+#[test]
+fn reused_system_last_run() {
+    fn inserted(positions: View<Position, track::Insertion>, mut counter: UniqueViewMut<Counter>) {
+        if positions.inserted().iter().next().is_some() {
+            counter.0.push("inserted");
+        }
+    }
+    fn inserted_too(
+        positions: View<Position, track::Insertion>,
+        mut counter: UniqueViewMut<Counter>,
+    ) {
+        if positions.inserted().iter().next().is_some() {
+            counter.0.push("inserted_too");
+        }
+    }
+
+    let mut world = world();
+    world.remove_system("Update", b).unwrap();
+    world.remove_system("Update", c).unwrap();
+    world.insert_system("Update", inserted).unwrap();
+    world.add_entity(Position);
+
+    world.run_workload("Update").unwrap();
+    world.run_workload("Update").unwrap();
+    assert_eq!(calls(&world), vec!["a", "inserted", "a"]);
+
+    // `inserted_too` reuses `inserted`'s slot but not when it last ran
+    world.remove_system("Update", inserted).unwrap();
+    world.insert_system("Update", inserted_too).unwrap();
+
+    world.run_workload("Update").unwrap();
+    assert_eq!(
+        calls(&world),
+        vec!["a", "inserted", "a", "a", "inserted_too"]
+    );
+}
+
+#[test]
+fn replace() {
+    let world = world();
+
+    let info = world.replace_system("Update", b, d).unwrap();
+    assert_eq!(info.batch_info.len(), 3);
+
+    world.run_workload("Update").unwrap();
+    assert_eq!(calls(&world), vec!["a", "d", "c"]);
+}
+
+#[test]
+fn replace_keeps_batches() {
+    fn movement(_: ViewMut<Position>) {}
+    fn render(_: View<Position>) {}
+    fn idle() {}
+    fn exclusive(_: AllStoragesViewMut) {}
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    Workload::new("Update")
+        .with_system(movement)
+        .with_system(render)
+        .add_to_world(&world)
+        .unwrap();
+
+    // `render` doesn't conflict with `idle` anymore but stays in the second batch
+    let info = world.replace_system("Update", movement, idle).unwrap();
+    assert_eq!(info.batch_info.len(), 2);
+    assert!(format!("{:?}", info.batch_info[0].systems.1[0].name).contains("idle"));
+    assert!(format!("{:?}", info.batch_info[1].systems.1[0].name).contains("render"));
+
+    let info = world.replace_system("Update", idle, exclusive).unwrap();
+    assert_eq!(info.batch_info.len(), 2);
+    assert!(info.batch_info[0].systems.0.is_some());
+    assert!(info.batch_info[0].systems.1.is_empty());
+
+    let info = world.replace_system("Update", exclusive, movement).unwrap();
+    assert!(info.batch_info[0].systems.0.is_none());
+    assert_eq!(info.batch_info[0].systems.1.len(), 1);
+
+    world.run_workload("Update").unwrap();
+}
+
+#[test]
+fn replace_keeps_run_if() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Counter(Vec::new()));
+
+    Workload::new("Update")
+        .with_system(a)
+        .with_system(b.run_if(|| false))
+        .add_to_world(&world)
+        .unwrap();
+
+    world.replace_system("Update", b, d).unwrap();
+    world.run_workload("Update").unwrap();
+    assert_eq!(calls(&world), vec!["a"]);
+
+    world
+        .replace_system("Update", a, c.run_if(|| false))
+        .unwrap();
+    world.run_workload("Update").unwrap();
+    assert_eq!(calls(&world), vec!["a"]);
+}
+
+#[test]
+fn replace_conflict() {
+    fn read(_: View<Position>) {}
+    fn read_too(_: View<Position>) {}
+    fn write(_: ViewMut<Position>) {}
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let info = Workload::new("Update")
+        .with_system(read)
+        .with_system(read_too)
+        .add_to_world(&world)
+        .unwrap();
+    assert_eq!(info.batch_info.len(), 1);
+
+    assert!(matches!(
+        world.replace_system("Update", read_too, write),
+        Err(error::EditWorkload::Conflict(_, _))
+    ));
+
+    world.remove_system("Update", read).unwrap();
+    world.replace_system("Update", read_too, write).unwrap();
+    world.run_workload("Update").unwrap();
+}
+
+#[test]
+fn insert() {
+    let world = world();
+
+    let info = world.insert_system("Update", d).unwrap();
+    assert_eq!(info.batch_info.len(), 4);
+
+    world.run_workload("Update").unwrap();
+    assert_eq!(calls(&world), vec!["a", "b", "c", "d"]);
+}
+
+#[test]
+fn insert_parallel() {
+    fn read(_: View<Position>) {}
+    fn read_too(_: View<Position>) {}
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    Workload::new("Update")
+        .with_system(read)
+        .add_to_world(&world)
+        .unwrap();
+
+    let info = world.insert_system("Update", read_too).unwrap();
+    assert_eq!(info.batch_info.len(), 1);
+    assert_eq!(info.batch_info[0].systems.1.len(), 2);
+
+    world.run_workload("Update").unwrap();
+}
+
+#[test]
+fn insert_requirements() {
+    let world = world();
+
+    assert!(matches!(
+        world.insert_system("Update", d.after_all(a)),
+        Err(error::EditWorkload::Requirements(_))
+    ));
+}
@@ -1,5 +1,11 @@
+mod edit;
 #[cfg(feature = "thread_local")]
 mod non_send_sync;
+mod pipe;
+mod run_once;
+#[cfg(feature = "tracing")]
+mod spans;
+mod worlds;
 
 struct U32(u32);
 impl Component for U32 {}
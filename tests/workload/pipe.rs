@@ -0,0 +1,137 @@
+use shipyard::*;
+
+struct Position(u32);
+impl Component for Position {}
+
+struct Log(Vec<String>);
+impl Component for Log {}
+impl Unique for Log {}
+
+fn world() -> World {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Log(Vec::new()));
+    world.add_entity(Position(1));
+    world.add_entity(Position(2));
+    world
+}
+
+fn type_name_of<T>(_: T) -> &'static str {
+    core::any::type_name::<T>()
+}
+
+fn log(world: &World) -> Vec<String> {
+    world.borrow::<UniqueView<Log>>().unwrap().0.clone()
+}
+
+fn sum(positions: View<Position>) -> u32 {
+    positions.iter().map(|position| position.0).sum()
+}
+fn double(value: u32) -> u32 {
+    value * 2
+}
+fn write_log(value: u32, mut log: UniqueViewMut<Log>) {
+    log.0.push(value.to_string());
+}
+
+#[test]
+fn pipe() {
+    let world = world();
+
+    let info = Workload::new("")
+        .with_system(sum.pipe(write_log))
+        .add_to_world(&world)
+        .unwrap();
+
+    let system = info.batch_info[0].systems.0.as_ref().unwrap();
+    assert_eq!(
+        format!("{:?}", system.name),
+        format!("\"{} | {}\"", type_name_of(sum), type_name_of(write_log))
+    );
+    assert_eq!(system.borrow.len(), 2);
+
+    world.run_default().unwrap();
+    assert_eq!(log(&world), vec!["3"]);
+}
+
+#[test]
+fn chain() {
+    let world = world();
+
+    Workload::new("")
+        .with_system(sum.pipe(double).pipe(write_log))
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default().unwrap();
+    assert_eq!(log(&world), vec!["6"]);
+}
+
+#[test]
+fn errors() {
+    #[derive(Debug)]
+    struct TooFar;
+
+    fn check_positions(positions: View<Position>) -> Result<(), TooFar> {
+        if positions.iter().any(|position| position.0 > 1) {
+            Err(TooFar)
+        } else {
+            Ok(())
+        }
+    }
+    fn log_error(result: Result<(), TooFar>, mut log: UniqueViewMut<Log>) {
+        if let Err(err) = result {
+            log.0.push(format!("{:?}", err));
+        }
+    }
+
+    let world = world();
+
+    Workload::new("")
+        .with_system(check_positions.pipe(log_error))
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default().unwrap();
+    assert_eq!(log(&world), vec!["TooFar"]);
+}
+
+#[test]
+fn borrow_union() {
+    fn read(_: View<Position>) {}
+    fn move_positions(mut positions: ViewMut<Position>) -> u32 {
+        for mut position in (&mut positions).iter() {
+            position.0 += 1;
+        }
+
+        positions.len() as u32
+    }
+
+    let world = world();
+
+    let info = Workload::new("")
+        .with_system(read)
+        .with_system(move_positions.pipe(write_log))
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(info.batch_info.len(), 2);
+    assert!(info.batch_info[1].systems.1[0].conflict.is_some());
+
+    world.run_default().unwrap();
+    assert_eq!(log(&world), vec!["2"]);
+}
+
+#[test]
+fn modificator() {
+    let world = world();
+
+    Workload::new("")
+        .with_system(sum.pipe(write_log).run_if(|| false))
+        .with_system(sum.pipe(double).pipe(write_log).run_once())
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default().unwrap();
+    world.run_default().unwrap();
+    assert_eq!(log(&world), vec!["6"]);
+}
@@ -0,0 +1,203 @@
+use shipyard::info::RunFrequency;
+use shipyard::*;
+
+struct Counter(Vec<&'static str>);
+impl Component for Counter {}
+impl Unique for Counter {}
+
+struct Missing;
+impl Component for Missing {}
+impl Unique for Missing {}
+
+fn world() -> World {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Counter(Vec::new()));
+    world
+}
+
+fn calls(world: &World) -> Vec<&'static str> {
+    world.borrow::<UniqueView<Counter>>().unwrap().0.clone()
+}
+
+fn load(mut counter: UniqueViewMut<Counter>) {
+    counter.0.push("load");
+}
+fn spawn(mut counter: UniqueViewMut<Counter>) {
+    counter.0.push("spawn");
+}
+fn update(mut counter: UniqueViewMut<Counter>) {
+    counter.0.push("update");
+}
+
+#[test]
+fn run_once() {
+    let world = world();
+
+    let info = Workload::new("Load")
+        .with_system(load)
+        .run_once()
+        .add_to_world(&world)
+        .unwrap();
+    assert_eq!(info.frequency, RunFrequency::Once { has_run: false });
+
+    world.run_workload("Load").unwrap();
+    world.run_workload("Load").unwrap();
+    assert_eq!(calls(&world), vec!["load"]);
+    assert_eq!(
+        world.workload_frequency("Load"),
+        Some(RunFrequency::Once { has_run: true })
+    );
+
+    world.reset_run_once();
+    assert_eq!(
+        world.workload_frequency("Load"),
+        Some(RunFrequency::Once { has_run: false })
+    );
+
+    world.run_workload("Load").unwrap();
+    assert_eq!(calls(&world), vec!["load", "load"]);
+}
+
+#[test]
+fn startup() {
+    let world = world();
+
+    let info = Workload::new("Load")
+        .with_system(load)
+        .startup()
+        .add_to_world(&world)
+        .unwrap();
+    assert_eq!(info.frequency, RunFrequency::Startup { has_run: false });
+    Workload::new("Update")
+        .with_system(update)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("Spawn")
+        .with_system(spawn)
+        .startup()
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(
+        world.workload_frequency("Update"),
+        Some(RunFrequency::Always)
+    );
+
+    world.run_default().unwrap();
+    world.run_default().unwrap();
+    assert_eq!(calls(&world), vec!["load", "spawn", "update", "update"]);
+    assert_eq!(
+        world.workload_frequency("Spawn"),
+        Some(RunFrequency::Startup { has_run: true })
+    );
+
+    world.reset_run_once();
+    world.run_default().unwrap();
+    assert_eq!(
+        calls(&world),
+        vec!["load", "spawn", "update", "update", "load", "spawn", "update"]
+    );
+}
+
+#[test]
+fn startup_only() {
+    let world = world();
+
+    Workload::new("Load")
+        .with_system(load)
+        .startup()
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default().unwrap();
+    world.run_default().unwrap();
+    assert_eq!(calls(&world), vec!["load"]);
+
+    Workload::new("Update")
+        .with_system(update)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default().unwrap();
+    assert_eq!(calls(&world), vec!["load", "update"]);
+}
+
+#[test]
+fn run_once_system() {
+    let world = world();
+
+    Workload::new("Update")
+        .with_system(load.run_once())
+        .with_system(update)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default().unwrap();
+    world.run_default().unwrap();
+    assert_eq!(calls(&world), vec!["load", "update", "update"]);
+
+    world.reset_run_once();
+    world.run_default().unwrap();
+    assert_eq!(
+        calls(&world),
+        vec!["load", "update", "update", "load", "update"]
+    );
+}
+
+#[test]
+fn nested_run_once() {
+    let world = world();
+
+    (Workload::new("Load").with_system(load).run_once(), update)
+        .into_workload()
+        .rename("Update")
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default().unwrap();
+    world.run_default().unwrap();
+    assert_eq!(calls(&world), vec!["load", "update", "update"]);
+}
+
+#[test]
+fn error_runs_again() {
+    let world = world();
+
+    Workload::new("Load")
+        .with_system(|_: UniqueView<Missing>| {})
+        .run_once()
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(world.run_workload("Load").is_err());
+    assert_eq!(
+        world.workload_frequency("Load"),
+        Some(RunFrequency::Once { has_run: false })
+    );
+
+    world.add_unique(Missing);
+    world.run_workload("Load").unwrap();
+    assert_eq!(
+        world.workload_frequency("Load"),
+        Some(RunFrequency::Once { has_run: true })
+    );
+}
+
+#[test]
+fn scheduled_workload() {
+    let world = world();
+
+    let (workload, _) = Workload::new("Load")
+        .with_system(load)
+        .run_once()
+        .build()
+        .unwrap();
+
+    workload.run_with_world(&world).unwrap();
+    workload.run_with_world(&world).unwrap();
+    assert_eq!(calls(&world), vec!["load"]);
+
+    workload.reset_run_once();
+    workload.run_with_world(&world).unwrap();
+    assert_eq!(calls(&world), vec!["load", "load"]);
+}
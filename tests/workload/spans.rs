@@ -0,0 +1,132 @@
+use shipyard::*;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Dispatch, Event, Metadata, Subscriber};
+
+struct Position;
+impl Component for Position {}
+
+struct Velocity;
+impl Component for Velocity {}
+
+/// Captures the fields of every `system` span.
+#[derive(Clone, Default)]
+struct SystemSpans {
+    spans: Arc<Mutex<Vec<Vec<(&'static str, String)>>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+struct Fields<'a>(&'a mut Vec<(&'static str, String)>);
+
+impl Visit for Fields<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name(), value.to_string()));
+    }
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push((field.name(), format!("{:?}", value)));
+    }
+}
+
+impl Subscriber for SystemSpans {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        if span.metadata().name() == "system" {
+            let mut fields = Vec::new();
+            span.record(&mut Fields(&mut fields));
+            self.spans.lock().unwrap().push(fields);
+        }
+
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+    fn event(&self, _: &Event<'_>) {}
+    fn enter(&self, _: &Id) {}
+    fn exit(&self, _: &Id) {}
+}
+
+impl SystemSpans {
+    /// Returns the fields of the span of the system whose name contains `system`.
+    fn fields(&self, system: &str) -> Vec<(&'static str, String)> {
+        self.spans
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|fields| {
+                fields
+                    .iter()
+                    .any(|(name, value)| *name == "name" && value.contains(system))
+            })
+            .unwrap()
+            .iter()
+            .filter(|(name, _)| *name != "name")
+            .cloned()
+            .collect()
+    }
+}
+
+// systems run on the `World`'s thread pool, its threads have to report to `dispatch` too
+#[cfg(feature = "parallel")]
+fn world(dispatch: &Dispatch) -> World {
+    let dispatch = dispatch.clone();
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .start_handler(move |_| core::mem::forget(tracing::dispatcher::set_default(&dispatch)))
+        .build()
+        .unwrap();
+
+    World::new_with_custom_lock_and_local_thread_pool::<parking_lot::RawRwLock>(thread_pool)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn world(_: &Dispatch) -> World {
+    World::new_with_custom_lock::<parking_lot::RawRwLock>()
+}
+
+#[test]
+fn system_spans() {
+    fn read(_: View<Position>) {}
+    fn write(_: ViewMut<Position>, _: View<Velocity>) {}
+    fn skipped(_: View<Velocity>) {}
+
+    let subscriber = SystemSpans::default();
+    let dispatch = Dispatch::new(subscriber.clone());
+    let world = world(&dispatch);
+
+    Workload::new("Spans")
+        .with_system(read)
+        .with_system(write)
+        .with_system(skipped.run_if(|| false))
+        .add_to_world(&world)
+        .unwrap();
+
+    tracing::dispatcher::with_default(&dispatch, || world.run_workload("Spans").unwrap());
+
+    let expected = |batch: &str, borrow: String, skipped: &str| {
+        vec![
+            ("workload", "\"Spans\"".to_string()),
+            ("batch", batch.to_string()),
+            ("borrow", borrow),
+            ("skipped", skipped.to_string()),
+        ]
+    };
+    let position = std::any::type_name::<SparseSet<Position>>();
+    let velocity = std::any::type_name::<SparseSet<Velocity>>();
+
+    assert_eq!(
+        subscriber.fields("read"),
+        expected("0", format!("&{}", position), "false")
+    );
+    assert_eq!(
+        subscriber.fields("write"),
+        expected("1", format!("&mut {}, &{}", position, velocity), "false")
+    );
+    assert_eq!(
+        subscriber.fields("skipped"),
+        expected("0", format!("&{}", velocity), "true")
+    );
+}
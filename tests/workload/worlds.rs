@@ -0,0 +1,105 @@
+use shipyard::*;
+
+struct Position;
+impl Component for Position {}
+
+struct Count(usize);
+impl Component for Count {}
+impl Unique for Count {}
+
+fn world() -> World {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Count(0));
+    world.add_entity(Position);
+    world
+}
+
+fn count(world: &World) -> usize {
+    world.borrow::<UniqueView<Count>>().unwrap().0
+}
+
+fn increment(mut count: UniqueViewMut<Count>) {
+    count.0 += 1;
+}
+
+fn count_inserted(positions: View<Position, track::Insertion>, mut count: UniqueViewMut<Count>) {
+    count.0 = positions.inserted().iter().count();
+}
+
+#[test]
+fn run_workload_in_worlds() {
+    let worlds: Vec<World> = (0..8)
+        .map(|_| {
+            let world = world();
+            Workload::new("Tick")
+                .with_system(increment)
+                .add_to_world(&world)
+                .unwrap();
+            world
+        })
+        .collect();
+
+    World::run_workload_in_worlds(&worlds, "Tick").unwrap();
+    World::run_workload_in_worlds(&worlds, "Tick").unwrap();
+
+    for world in &worlds {
+        assert_eq!(count(world), 2);
+    }
+}
+
+#[test]
+fn errors() {
+    let worlds: Vec<World> = (0..4)
+        .map(|i| {
+            let world = world();
+            if i % 2 == 0 {
+                Workload::new("Tick")
+                    .with_system(increment)
+                    .add_to_world(&world)
+                    .unwrap();
+            }
+            world
+        })
+        .collect();
+
+    let errors = World::run_workload_in_worlds(&worlds, "Tick")
+        .unwrap_err()
+        .errors;
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].0, 1);
+    assert!(matches!(errors[0].1, error::RunWorkload::MissingWorkload));
+    assert_eq!(errors[1].0, 3);
+
+    assert_eq!(count(&worlds[0]), 1);
+    assert_eq!(count(&worlds[1]), 0);
+}
+
+#[test]
+fn scheduled_workload() {
+    fn is_send_sync<T: Send + Sync>(_: &T) {}
+
+    let (workload, _) = Workload::new("Tick")
+        .with_system(count_inserted)
+        .build()
+        .unwrap();
+    is_send_sync(&workload);
+
+    let mut worlds = [world(), world()];
+    for world in &mut worlds {
+        workload.apply_tracking(world).unwrap();
+        world.add_entity(Position);
+    }
+
+    workload.run_with_worlds(&worlds).unwrap();
+    assert_eq!(count(&worlds[0]), 1);
+    assert_eq!(count(&worlds[1]), 1);
+
+    worlds[0].add_entity(Position);
+    workload.run_with_worlds(&worlds).unwrap();
+    assert_eq!(count(&worlds[0]), 1);
+    assert_eq!(count(&worlds[1]), 0);
+
+    workload.run_with_world(&worlds[1]).unwrap();
+    assert_eq!(count(&worlds[1]), 0);
+}
@@ -1,24 +1,43 @@
+use crate::inspector::Inspector;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::live::{LiveConnection, Timings};
 use crate::syntax_highlight::code_view_ui;
+use crate::timeline::{timeline_ui, type_usage};
+#[cfg(not(target_arch = "wasm32"))]
+use shipyard::info::PublisherMessage;
 use shipyard::{
-    info::{TypeInfo, WorkloadsTypeUsage},
-    Mutability,
+    info::{TypeInfo, WorkloadInfoExport, WorkloadsTypeUsage},
+    Inspection, Mutability,
 };
 use std::{borrow::Cow, collections::HashMap, io::Read};
 
 pub struct MyApp {
     workloads: Option<WorkloadsTypeUsage>,
+    workloads_info: Vec<WorkloadInfoExport>,
     selected_workload: Option<String>,
     info_display: InfoDisplay,
     info: Info,
     info_selection: InfoSelection,
     selected_system: Option<String>,
     selected_component: Option<String>,
+    selected_batch_system: Option<(usize, String)>,
+    inspector: Inspector,
+    show_inspector: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    live: Option<LiveConnection>,
+    #[cfg(not(target_arch = "wasm32"))]
+    live_source: String,
+    #[cfg(not(target_arch = "wasm32"))]
+    live_error: Option<String>,
+    #[cfg(not(target_arch = "wasm32"))]
+    timings: Timings,
 }
 
 impl Default for MyApp {
     fn default() -> MyApp {
         MyApp {
             workloads: None,
+            workloads_info: Vec::new(),
             selected_workload: None,
             info_display: InfoDisplay {
                 systems: Vec::new(),
@@ -34,6 +53,17 @@ impl Default for MyApp {
             },
             selected_system: None,
             selected_component: None,
+            selected_batch_system: None,
+            inspector: Inspector::default(),
+            show_inspector: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            live: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            live_source: "127.0.0.1:7878".to_string(),
+            #[cfg(not(target_arch = "wasm32"))]
+            live_error: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            timings: Timings::default(),
         }
     }
 }
@@ -59,17 +89,42 @@ r#"std::fs::write(
     "drop_me.json",
     serde_json::to_string(&world.workloads_type_usage()).unwrap(),
 )
-.unwrap();"#;
+.unwrap();
+
+// Or to see batches and conflicts
+let info = Workload::new("Update")
+    .with_system(system)
+    .add_to_world(&world)
+    .unwrap();
+std::fs::write(
+    "drop_me.json",
+    serde_json::to_string(&[WorkloadInfoExport::from(&info)]).unwrap(),
+)
+.unwrap();
+
+// Or to inspect entities and components
+std::fs::write(
+    "drop_me.json",
+    serde_json::to_string(&world.inspect(&registry).unwrap()).unwrap(),
+)
+.unwrap();
+
+// Or to connect live
+world.set_publisher(shipyard::Publisher::listen("127.0.0.1:7878").unwrap());
+world.publish_inspection(&registry).unwrap();"#;
 
 impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.receive_live_messages();
+
         egui::Area::new("Light switch")
             .fixed_pos((0.0, 0.0))
             .show(ctx, |ui| {
                 egui::widgets::global_dark_light_mode_switch(ui);
             });
 
-        if self.workloads.is_none() {
+        if self.workloads.is_none() && !self.inspector.is_loaded() {
             egui::Area::new("Drop")
                 .fixed_pos(ctx.available_rect().center())
                 .anchor(egui::Align2::CENTER_CENTER, (0.0, 0.0))
@@ -86,6 +141,13 @@ impl eframe::App for MyApp {
                             ui.heading(
                                 egui::RichText::new("shipyard::info::WorkloadsTypeUsage").code(),
                             );
+                            ui.heading(", ");
+                            ui.heading(
+                                egui::RichText::new("Vec<shipyard::info::WorkloadInfoExport>")
+                                    .code(),
+                            );
+                            ui.heading(" or ");
+                            ui.heading(egui::RichText::new("shipyard::Inspection").code());
                             ui.heading(".");
                         });
 
@@ -102,6 +164,9 @@ impl eframe::App for MyApp {
                             })
                         }
 
+                        #[cfg(not(target_arch = "wasm32"))]
+                        self.live_connection_ui(ui);
+
                         ui.add_space(15.0);
 
                         code_view_ui(ui, CODE);
@@ -123,12 +188,33 @@ impl eframe::App for MyApp {
                     .clicked()
                 {
                     self.workloads = None;
+                    self.inspector.clear();
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        self.live = None;
+                    }
                 }
             });
 
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(live) = &self.live {
+                ui.vertical_centered(|ui| ui.label(format!("Live: {}", live.source)));
+            }
+
             ui.add_space(10.0);
 
-            if let Some(workloads) = &self.workloads {
+            if self.workloads.is_some() && self.inspector.is_loaded() {
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.show_inspector, false, "Workloads");
+                    ui.selectable_value(&mut self.show_inspector, true, "World");
+                });
+
+                ui.add_space(10.0);
+            }
+
+            if self.inspector.is_loaded() && (self.show_inspector || self.workloads.is_none()) {
+                self.inspector.ui(ui);
+            } else if let Some(workloads) = &self.workloads {
                 ui.horizontal(|ui| {
                     ui.label("Selected workload:");
 
@@ -150,6 +236,7 @@ impl eframe::App for MyApp {
                                     .clicked()
                                 {
                                     self.selected_workload = Some(workload_name.clone());
+                                    self.selected_batch_system = None;
 
                                     let workload = workloads.0.get(workload_name).unwrap();
                                     let mut system_to_components = HashMap::new();
@@ -236,6 +323,24 @@ impl eframe::App for MyApp {
                     }
                 });
 
+                if let Some(workload) = self.selected_workload.as_ref().and_then(|name| {
+                    self.workloads_info
+                        .iter()
+                        .find(|workload| workload.name == *name)
+                }) {
+                    ui.add_space(20.0);
+
+                    ui.vertical_centered(|ui| ui.heading("Batches"));
+                    timeline_ui(ui, workload, &mut self.selected_batch_system);
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(workload_name) = &self.selected_workload {
+                    ui.add_space(20.0);
+
+                    self.timings.ui(ui, workload_name);
+                }
+
                 if self.selected_workload.is_some() {
                     ui.add_space(20.0);
 
@@ -363,13 +468,88 @@ impl eframe::App for MyApp {
                     serde_json::from_slice::<WorkloadsTypeUsage>(&bytes)
                 {
                     self.workloads = Some(workload_type_usage);
+                    self.workloads_info.clear();
+                    self.selected_workload = None;
+                } else if let Ok(workloads_info) =
+                    serde_json::from_slice::<Vec<WorkloadInfoExport>>(&bytes)
+                {
+                    self.workloads = Some(type_usage(&workloads_info));
+                    self.workloads_info = workloads_info;
                     self.selected_workload = None;
+                } else if let Ok(inspection) = serde_json::from_slice::<Inspection>(&bytes) {
+                    self.inspector.set(inspection);
+                    self.show_inspector = true;
                 }
             }
         })
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl MyApp {
+    fn live_connection_ui(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(15.0);
+
+        ui.horizontal(|ui| {
+            ui.label("Or connect to a running application:");
+            ui.text_edit_singleline(&mut self.live_source);
+
+            let connection = if ui.button("Connect").clicked() {
+                Some(LiveConnection::tcp(&self.live_source, ui.ctx()))
+            } else if ui.button("Watch file").clicked() {
+                Some(LiveConnection::file(
+                    self.live_source.clone().into(),
+                    ui.ctx(),
+                ))
+            } else {
+                None
+            };
+
+            match connection {
+                Some(Ok(live)) => {
+                    self.live = Some(live);
+                    self.live_error = None;
+                    self.workloads = Some(WorkloadsTypeUsage(Default::default()));
+                    self.workloads_info.clear();
+                    self.inspector.clear();
+                    self.timings.clear();
+                    self.selected_workload = None;
+                }
+                Some(Err(err)) => self.live_error = Some(err.to_string()),
+                None => {}
+            }
+        });
+
+        if let Some(err) = &self.live_error {
+            ui.colored_label(ui.visuals().error_fg_color, err);
+        }
+    }
+
+    fn receive_live_messages(&mut self) {
+        let Some(live) = &self.live else {
+            return;
+        };
+
+        let mut workloads_changed = false;
+        for message in live.messages() {
+            match message {
+                PublisherMessage::Workload(workload) => {
+                    self.workloads_info
+                        .retain(|other| other.name != workload.name);
+                    self.workloads_info.push(workload);
+                    workloads_changed = true;
+                }
+                PublisherMessage::Timings(timings) => self.timings.push(timings),
+                PublisherMessage::Inspection(inspection) => self.inspector.set(inspection),
+            }
+        }
+
+        if workloads_changed {
+            self.workloads = Some(type_usage(&self.workloads_info));
+        }
+    }
+}
+
 fn shared_color(ui: &mut egui::Ui) -> egui::Color32 {
     if ui.visuals().dark_mode {
         egui::Color32::DARK_BLUE
@@ -0,0 +1,52 @@
+//! Prints a text report of a workload Json file, or the differences between two of them.
+//!
+//! ```text
+//! report <workloads.json>
+//! report diff <old.json> <new.json>
+//! ```
+//!
+//! The Json files are either a `shipyard::info::WorkloadsTypeUsage` or a `Vec<shipyard::info::WorkloadInfoExport>`.\
+//! `diff` exits with code 1 when the files differ.
+
+use std::process::ExitCode;
+use visualizer_lib::Report;
+
+const USAGE: &str = "usage: report <workloads.json>\n       report diff <old.json> <new.json>";
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["diff", old, new] => load(old).and_then(|old| {
+            let diff = old.diff(&load(new)?);
+
+            for line in &diff {
+                println!("{}", line);
+            }
+
+            Ok(if diff.is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::from(1)
+            })
+        }),
+        [path] if path != "diff" => load(path).map(|report| {
+            print!("{}", report);
+
+            ExitCode::SUCCESS
+        }),
+        _ => Err(USAGE.to_string()),
+    };
+
+    result.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+
+        ExitCode::from(2)
+    })
+}
+
+fn load(path: &str) -> Result<Report, String> {
+    let bytes = std::fs::read(path).map_err(|err| format!("{}: {}", path, err))?;
+
+    Report::from_json(&bytes).map_err(|err| format!("{}: {}", path, err))
+}
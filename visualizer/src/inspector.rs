@@ -0,0 +1,133 @@
+use shipyard::{EntityId, Inspection};
+use std::collections::BTreeSet;
+
+/// Browses the entities, components and memory usage of a `shipyard::Inspection`.
+#[derive(Default)]
+pub(crate) struct Inspector {
+    inspection: Option<Inspection>,
+    component_names: BTreeSet<String>,
+    filter: Option<String>,
+    selected_entity: Option<EntityId>,
+}
+
+impl Inspector {
+    /// Displays `inspection`, the filter and selected entity are kept.
+    pub(crate) fn set(&mut self, inspection: Inspection) {
+        self.component_names = inspection
+            .entities
+            .iter()
+            .flat_map(|entity| entity.components.keys().cloned())
+            .collect();
+        self.inspection = Some(inspection);
+    }
+    pub(crate) fn clear(&mut self) {
+        *self = Inspector::default();
+    }
+    pub(crate) fn is_loaded(&self) -> bool {
+        self.inspection.is_some()
+    }
+    pub(crate) fn ui(&mut self, ui: &mut egui::Ui) {
+        let Some(inspection) = &self.inspection else {
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            ui.label("With component:");
+
+            egui::ComboBox::from_id_source("Component filter")
+                .width(300.0)
+                .selected_text(self.filter.as_deref().unwrap_or("Any"))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.filter, None, "Any");
+
+                    for name in &self.component_names {
+                        ui.selectable_value(&mut self.filter, Some(name.clone()), name);
+                    }
+                });
+        });
+
+        ui.add_space(10.0);
+
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                ui.heading("Entities");
+
+                egui::ScrollArea::vertical()
+                    .id_source("Entities")
+                    .max_height(400.0)
+                    .show(ui, |ui| {
+                        for entity in inspection.entities.iter().filter(|entity| {
+                            self.filter
+                                .iter()
+                                .all(|name| entity.components.contains_key(name))
+                        }) {
+                            if ui
+                                .selectable_label(
+                                    self.selected_entity == Some(entity.id),
+                                    format!("{:?}", entity.id),
+                                )
+                                .clicked()
+                            {
+                                self.selected_entity = Some(entity.id);
+                            }
+                        }
+                    });
+            });
+
+            columns[1].vertical_centered_justified(|ui| {
+                ui.heading("Components");
+
+                let entity = self
+                    .selected_entity
+                    .and_then(|id| inspection.entities.iter().find(|entity| entity.id == id));
+
+                if let Some(entity) = entity {
+                    for (name, value) in &entity.components {
+                        ui.label(egui::RichText::new(name).strong());
+
+                        match value {
+                            Some(value) => {
+                                ui.code(
+                                    serde_json::to_string_pretty(value)
+                                        .unwrap_or_else(|err| err.to_string()),
+                                );
+                            }
+                            None => {
+                                ui.label("Not serializable");
+                            }
+                        }
+                    }
+                }
+            });
+        });
+
+        ui.add_space(20.0);
+
+        ui.heading("Memory usage");
+
+        egui::Grid::new("Memory usage")
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("Storage");
+                ui.strong("Components");
+                ui.strong("Used");
+                ui.strong("Allocated");
+                ui.end_row();
+
+                for usage in &inspection.storages {
+                    ui.label(
+                        usage
+                            .storage_name
+                            .trim_start_matches("shipyard::")
+                            .trim_start_matches("sparse_set::")
+                            .trim_start_matches("unique::")
+                            .trim_start_matches("entities::"),
+                    );
+                    ui.label(usage.component_count.to_string());
+                    ui.label(format!("{} B", usage.used_memory_bytes));
+                    ui.label(format!("{} B", usage.allocated_memory_bytes));
+                    ui.end_row();
+                }
+            });
+    }
+}
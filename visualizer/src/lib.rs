@@ -1,4 +1,10 @@
 mod app;
+mod inspector;
+#[cfg(not(target_arch = "wasm32"))]
+mod live;
+mod report;
 mod syntax_highlight;
+mod timeline;
 
 pub use app::MyApp;
+pub use report::Report;
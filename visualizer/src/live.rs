@@ -0,0 +1,169 @@
+use shipyard::info::{PublisherMessage, WorkloadTimings};
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::Duration;
+
+/// Number of frames kept per workload.
+const HISTORY: usize = 120;
+
+/// Messages received from a `shipyard::Publisher`, read on a separate thread.
+pub(crate) struct LiveConnection {
+    pub(crate) source: String,
+    receiver: Receiver<PublisherMessage>,
+}
+
+impl LiveConnection {
+    /// Connects to a publisher listening on `addr`.
+    pub(crate) fn tcp(addr: &str, ctx: &egui::Context) -> std::io::Result<LiveConnection> {
+        let stream = TcpStream::connect(addr)?;
+        let (sender, receiver) = channel();
+        let ctx = ctx.clone();
+
+        std::thread::spawn(move || {
+            for line in BufReader::new(stream).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+
+                if !send_line(&line, &sender, &ctx) {
+                    break;
+                }
+            }
+        });
+
+        Ok(LiveConnection {
+            source: addr.to_string(),
+            receiver,
+        })
+    }
+    /// Follows the file a publisher writes to, starting from its beginning.
+    pub(crate) fn file(path: PathBuf, ctx: &egui::Context) -> std::io::Result<LiveConnection> {
+        let file = std::fs::File::open(&path)?;
+        let (sender, receiver) = channel();
+        let ctx = ctx.clone();
+        let source = path.display().to_string();
+
+        std::thread::spawn(move || {
+            let mut reader = BufReader::new(file);
+            let mut line = String::new();
+            let mut position = 0;
+
+            loop {
+                match reader.read_line(&mut line) {
+                    Ok(_) if line.ends_with('\n') => {
+                        position += line.len() as u64;
+
+                        if !send_line(&line, &sender, &ctx) {
+                            break;
+                        }
+
+                        line.clear();
+                    }
+                    Ok(_) => {
+                        std::thread::sleep(Duration::from_millis(100));
+
+                        // the publisher truncates the file when it's created again
+                        let len = std::fs::metadata(&path).map_or(0, |metadata| metadata.len());
+                        if len < position {
+                            position = 0;
+                            line.clear();
+
+                            if reader.seek(SeekFrom::Start(0)).is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+
+        Ok(LiveConnection { source, receiver })
+    }
+    /// Returns the messages received since the last call.
+    pub(crate) fn messages(&self) -> impl Iterator<Item = PublisherMessage> + '_ {
+        self.receiver.try_iter()
+    }
+}
+
+/// Returns `false` when the app stopped listening.
+fn send_line(line: &str, sender: &Sender<PublisherMessage>, ctx: &egui::Context) -> bool {
+    if let Ok(message) = serde_json::from_str(line) {
+        if sender.send(message).is_err() {
+            return false;
+        }
+
+        ctx.request_repaint();
+    }
+
+    true
+}
+
+/// Last frames of each workload.
+#[derive(Default)]
+pub(crate) struct Timings(HashMap<String, VecDeque<WorkloadTimings>>);
+
+impl Timings {
+    pub(crate) fn push(&mut self, timings: WorkloadTimings) {
+        let frames = self.0.entry(timings.workload.clone()).or_default();
+
+        if frames.len() == HISTORY {
+            frames.pop_front();
+        }
+
+        frames.push_back(timings);
+    }
+    pub(crate) fn clear(&mut self) {
+        self.0.clear();
+    }
+    /// Displays the duration of each system during the last frame and on average.
+    pub(crate) fn ui(&self, ui: &mut egui::Ui, workload_name: &str) {
+        let Some(frames) = self.0.get(workload_name) else {
+            return;
+        };
+        let Some(last) = frames.back() else {
+            return;
+        };
+
+        ui.label(format!(
+            "Frame {}: {:.3}ms, average over {} frames: {:.3}ms",
+            last.frame,
+            millis(last.duration),
+            frames.len(),
+            millis(frames.iter().map(|frame| frame.duration).sum::<Duration>())
+                / frames.len() as f64
+        ));
+
+        egui::Grid::new("Timings").striped(true).show(ui, |ui| {
+            for system in &last.systems {
+                let (total, count) = frames
+                    .iter()
+                    .flat_map(|frame| &frame.systems)
+                    .filter(|other| other.name == system.name)
+                    .fold((Duration::ZERO, 0), |(total, count), other| {
+                        (total + other.duration, count + 1)
+                    });
+
+                ui.label(&system.name);
+                ui.add(
+                    egui::ProgressBar::new(
+                        (system.duration.as_secs_f64()
+                            / last.duration.as_secs_f64().max(f64::EPSILON))
+                            as f32,
+                    )
+                    .desired_width(200.0)
+                    .text(format!("{:.3}ms", millis(system.duration))),
+                );
+                ui.label(format!("average {:.3}ms", millis(total) / count as f64));
+                ui.end_row();
+            }
+        });
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
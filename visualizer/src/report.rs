@@ -0,0 +1,357 @@
+use crate::timeline::{conflict_description, short_name};
+use shipyard::{
+    info::{TypeInfo, WorkloadInfoExport, WorkloadsTypeUsage},
+    Mutability,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display, Formatter};
+
+/// Text report of a `WorkloadsTypeUsage` or `Vec<WorkloadInfoExport>` Json file.
+///
+/// Lists the systems of each workload, which storages they borrow and
+/// the storages written by a system while borrowed by others.\
+/// Batches and the conflicts that split them are only known for `WorkloadInfoExport`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    workloads: BTreeMap<String, WorkloadReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WorkloadReport {
+    batch_count: Option<usize>,
+    systems: Vec<SystemReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SystemReport {
+    name: String,
+    batch: Option<usize>,
+    /// Storage name to the strongest borrow of the system
+    borrow: BTreeMap<String, Mutability>,
+    conflict: Option<String>,
+}
+
+impl Report {
+    /// Parses a `Vec<WorkloadInfoExport>` or a `WorkloadsTypeUsage`.
+    pub fn from_json(bytes: &[u8]) -> Result<Report, serde_json::Error> {
+        match serde_json::from_slice::<Vec<WorkloadInfoExport>>(bytes) {
+            Ok(workloads) => Ok(Report::from_workloads_info(&workloads)),
+            Err(_) => serde_json::from_slice::<WorkloadsTypeUsage>(bytes)
+                .map(|type_usage| Report::from_type_usage(&type_usage)),
+        }
+    }
+    /// Builds a report with batch information.
+    pub fn from_workloads_info(workloads: &[WorkloadInfoExport]) -> Report {
+        Report {
+            workloads: workloads
+                .iter()
+                .map(|workload| {
+                    let systems = workload
+                        .batches
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(index, batch)| {
+                            batch
+                                .single_system
+                                .iter()
+                                .chain(&batch.systems)
+                                .map(move |system| SystemReport {
+                                    name: system.name.clone(),
+                                    batch: Some(index),
+                                    borrow: borrow_map(&system.borrow),
+                                    conflict: system.conflict.as_ref().map(conflict_description),
+                                })
+                        })
+                        .collect();
+
+                    (
+                        workload.name.clone(),
+                        WorkloadReport {
+                            batch_count: Some(workload.batches.len()),
+                            systems,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+    /// Builds a report without batch information.
+    pub fn from_type_usage(type_usage: &WorkloadsTypeUsage) -> Report {
+        Report {
+            workloads: type_usage
+                .0
+                .iter()
+                .map(|(name, systems)| {
+                    (
+                        name.clone(),
+                        WorkloadReport {
+                            batch_count: None,
+                            systems: systems
+                                .iter()
+                                .map(|(name, borrow)| SystemReport {
+                                    name: name.clone(),
+                                    batch: None,
+                                    borrow: borrow_map(borrow),
+                                    conflict: None,
+                                })
+                                .collect(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+    /// Returns a line for each difference between `self` and `new`.\
+    /// Storages are compared by name, their ids are not stable between builds.
+    pub fn diff(&self, new: &Report) -> Vec<String> {
+        let mut diff = Vec::new();
+
+        for name in self.workloads.keys() {
+            if !new.workloads.contains_key(name) {
+                diff.push(format!("- workload {}", name));
+            }
+        }
+
+        for (name, new_workload) in &new.workloads {
+            let Some(old_workload) = self.workloads.get(name) else {
+                diff.push(format!("+ workload {}", name));
+                continue;
+            };
+
+            if let (Some(old), Some(new)) = (old_workload.batch_count, new_workload.batch_count) {
+                if old != new {
+                    diff.push(format!("  workload {}: {} -> {} batches", name, old, new));
+                }
+            }
+
+            for old_system in &old_workload.systems {
+                if new_workload.system(&old_system.name).is_none() {
+                    diff.push(format!("- workload {}: system {}", name, old_system.name));
+                }
+            }
+
+            for new_system in &new_workload.systems {
+                let Some(old_system) = old_workload.system(&new_system.name) else {
+                    diff.push(format!("+ workload {}: system {}", name, new_system.name));
+                    continue;
+                };
+
+                if let (Some(old), Some(new)) = (old_system.batch, new_system.batch) {
+                    if old != new {
+                        diff.push(format!(
+                            "  workload {}: system {}: batch {} -> {}",
+                            name, new_system.name, old, new
+                        ));
+                    }
+                }
+
+                let storages = old_system
+                    .borrow
+                    .keys()
+                    .chain(new_system.borrow.keys())
+                    .collect::<BTreeSet<_>>();
+
+                for storage in storages {
+                    let old = old_system.borrow.get(storage).copied();
+                    let new = new_system.borrow.get(storage).copied();
+
+                    if old != new {
+                        diff.push(format!(
+                            "  workload {}: system {}: {} {} -> {}",
+                            name,
+                            new_system.name,
+                            storage,
+                            access(old),
+                            access(new)
+                        ));
+                    }
+                }
+            }
+        }
+
+        diff
+    }
+}
+
+impl WorkloadReport {
+    fn system(&self, name: &str) -> Option<&SystemReport> {
+        self.systems.iter().find(|system| system.name == name)
+    }
+    /// Storages written by at least one system and borrowed by at least two.
+    fn write_conflicts(&self) -> BTreeMap<&str, Vec<(&str, Mutability)>> {
+        let mut storages: BTreeMap<&str, Vec<(&str, Mutability)>> = BTreeMap::new();
+
+        for system in &self.systems {
+            for (storage, mutability) in &system.borrow {
+                storages
+                    .entry(storage)
+                    .or_default()
+                    .push((&system.name, *mutability));
+            }
+        }
+
+        storages.retain(|_, systems| {
+            systems.len() > 1
+                && systems
+                    .iter()
+                    .any(|(_, mutability)| *mutability == Mutability::Exclusive)
+        });
+
+        storages
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (name, workload) in &self.workloads {
+            write!(f, "Workload {} ({} systems", name, workload.systems.len())?;
+            if let Some(batch_count) = workload.batch_count {
+                write!(f, ", {} batches", batch_count)?;
+            }
+            writeln!(f, ")")?;
+
+            writeln!(f, "  Systems:")?;
+            for (index, system) in workload.systems.iter().enumerate() {
+                match system.batch {
+                    Some(batch) => {
+                        writeln!(f, "    {:>3}  batch {:<3} {}", index, batch, system.name)?
+                    }
+                    None => writeln!(f, "    {:>3}  {}", index, system.name)?,
+                }
+            }
+
+            let storages = workload
+                .systems
+                .iter()
+                .flat_map(|system| system.borrow.keys())
+                .collect::<BTreeSet<_>>();
+
+            if !storages.is_empty() {
+                let width = storages
+                    .iter()
+                    .map(|storage| storage.len())
+                    .max()
+                    .unwrap_or(0);
+
+                writeln!(f, "  Access (R: shared, W: exclusive):")?;
+                write!(f, "    {:width$}", "", width = width)?;
+                for index in 0..workload.systems.len() {
+                    write!(f, " {:>3}", index)?;
+                }
+                writeln!(f)?;
+
+                for storage in storages {
+                    write!(f, "    {:width$}", storage, width = width)?;
+                    for system in &workload.systems {
+                        write!(f, " {:>3}", access(system.borrow.get(storage).copied()))?;
+                    }
+                    writeln!(f)?;
+                }
+            }
+
+            let write_conflicts = workload.write_conflicts();
+            if !write_conflicts.is_empty() {
+                writeln!(f, "  Write conflicts:")?;
+                for (storage, systems) in write_conflicts {
+                    let systems = systems
+                        .iter()
+                        .map(|(system, mutability)| {
+                            format!("{} ({})", system, access(Some(*mutability)))
+                        })
+                        .collect::<Vec<_>>();
+
+                    writeln!(f, "    {}: {}", storage, systems.join(", "))?;
+                }
+            }
+
+            if workload
+                .systems
+                .iter()
+                .any(|system| system.conflict.is_some())
+            {
+                writeln!(f, "  Batch conflicts:")?;
+                for system in &workload.systems {
+                    if let Some(conflict) = &system.conflict {
+                        writeln!(f, "    {}: {}", system.name, conflict)?;
+                    }
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn borrow_map(borrow: &[TypeInfo]) -> BTreeMap<String, Mutability> {
+    let mut map = BTreeMap::new();
+
+    for type_info in borrow {
+        let mutability = map
+            .entry(short_name(type_info).to_string())
+            .or_insert(type_info.mutability);
+
+        *mutability = (*mutability).max(type_info.mutability);
+    }
+
+    map
+}
+
+fn access(mutability: Option<Mutability>) -> &'static str {
+    match mutability {
+        Some(Mutability::Shared) => "R",
+        Some(Mutability::Exclusive) => "W",
+        None => "-",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shipyard::{Component, View, ViewMut, Workload, World};
+
+    #[derive(Component)]
+    struct Position;
+
+    fn read(_: View<Position>) {}
+    fn read_again(_: View<Position>) {}
+    fn write(_: ViewMut<Position>) {}
+
+    fn report(workload: Workload) -> Report {
+        let world = World::new();
+        let info = workload.add_to_world(&world).unwrap();
+
+        Report::from_json(&serde_json::to_vec(&[WorkloadInfoExport::from(&info)]).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn text() {
+        let report = report(Workload::new("Update").with_system(read).with_system(write));
+        let text = report.to_string();
+
+        assert!(text.contains("Workload \"Update\" (2 systems, 2 batches)"));
+        assert!(text.contains("Write conflicts:"));
+        assert!(text.contains("Batch conflicts:"));
+    }
+
+    #[test]
+    fn diff() {
+        let old = report(
+            Workload::new("Update")
+                .with_system(read)
+                .with_system(read_again),
+        );
+        let new = report(Workload::new("Update").with_system(read).with_system(write));
+
+        assert!(old.diff(&old).is_empty());
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.len(), 3);
+        assert_eq!(diff[0], "  workload \"Update\": 1 -> 2 batches");
+        assert!(
+            diff[1].starts_with("- workload \"Update\": system") && diff[1].contains("read_again")
+        );
+        assert!(diff[2].starts_with("+ workload \"Update\": system") && diff[2].contains("write"));
+    }
+}
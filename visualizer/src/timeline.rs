@@ -0,0 +1,165 @@
+use shipyard::{
+    info::{ConflictExport, SystemInfoExport, TypeInfo, WorkloadInfoExport, WorkloadsTypeUsage},
+    Mutability,
+};
+
+/// Builds the type usage of exported workloads, systems are listed in execution order.
+pub(crate) fn type_usage(workloads: &[WorkloadInfoExport]) -> WorkloadsTypeUsage {
+    WorkloadsTypeUsage(
+        workloads
+            .iter()
+            .map(|workload| {
+                (
+                    workload.name.clone(),
+                    workload
+                        .batches
+                        .iter()
+                        .flat_map(|batch| batch.single_system.iter().chain(&batch.systems))
+                        .map(|system| (system.name.clone(), system.borrow.clone()))
+                        .collect(),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Displays the batches of `workload` from left to right.\
+/// Clicking a system displays what it borrows and the conflict that prevented it from running in the previous batch.
+pub(crate) fn timeline_ui(
+    ui: &mut egui::Ui,
+    workload: &WorkloadInfoExport,
+    selected_system: &mut Option<(usize, String)>,
+) {
+    egui::ScrollArea::horizontal().show(ui, |ui| {
+        ui.horizontal_top(|ui| {
+            for (batch_index, batch) in workload.batches.iter().enumerate() {
+                ui.group(|ui| {
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(format!("Batch {}", batch_index)).strong());
+
+                        if let Some(system) = &batch.single_system {
+                            system_button(ui, batch_index, system, "single", selected_system);
+                        }
+
+                        for system in &batch.systems {
+                            system_button(ui, batch_index, system, "parallel", selected_system);
+                        }
+                    });
+                });
+            }
+        });
+    });
+
+    let selected = selected_system.as_ref().and_then(|(batch_index, name)| {
+        let batch = workload.batches.get(*batch_index)?;
+
+        batch
+            .single_system
+            .iter()
+            .chain(&batch.systems)
+            .find(|system| system.name == *name)
+            .map(|system| (*batch_index, system))
+    });
+
+    if let Some((batch_index, system)) = selected {
+        ui.add_space(10.0);
+
+        ui.label(egui::RichText::new(&system.name).strong());
+
+        for type_info in &system.borrow {
+            ui.label(format!(
+                "{} {}",
+                mutability_str(type_info.mutability),
+                short_name(type_info)
+            ));
+        }
+
+        ui.add_space(5.0);
+
+        match &system.conflict {
+            Some(conflict) => {
+                ui.label(conflict_description(conflict));
+            }
+            None if batch_index == 0 => {
+                ui.label("Runs in the first batch.");
+            }
+            None => {
+                ui.label("No borrow conflict with the previous batch.");
+            }
+        }
+    }
+}
+
+fn system_button(
+    ui: &mut egui::Ui,
+    batch_index: usize,
+    system: &SystemInfoExport,
+    kind: &str,
+    selected_system: &mut Option<(usize, String)>,
+) {
+    let is_selected = matches!(
+        selected_system,
+        Some((index, name)) if *index == batch_index && *name == system.name
+    );
+
+    if ui
+        .selectable_label(is_selected, &system.name)
+        .on_hover_text(kind)
+        .clicked()
+    {
+        *selected_system = Some((batch_index, system.name.clone()));
+    }
+}
+
+pub(crate) fn conflict_description(conflict: &ConflictExport) -> String {
+    match conflict {
+        ConflictExport::Borrow {
+            type_info: Some(type_info),
+            other_system,
+            other_type_info,
+        } => format!(
+            "Borrows {} {} while {} borrows {} {}.",
+            mutability_str(type_info.mutability),
+            short_name(type_info),
+            other_system,
+            mutability_str(other_type_info.mutability),
+            short_name(other_type_info)
+        ),
+        ConflictExport::Borrow {
+            type_info: None,
+            other_system,
+            other_type_info,
+        } => format!(
+            "Conflicts with {} borrowing {} {}.",
+            other_system,
+            mutability_str(other_type_info.mutability),
+            short_name(other_type_info)
+        ),
+        ConflictExport::NotSendSync(type_info) => format!(
+            "Borrows {} which isn't Send and/or Sync, it can't run in parallel.",
+            short_name(type_info)
+        ),
+        ConflictExport::OtherNotSendSync { system, type_info } => format!(
+            "{} borrows {} which isn't Send and/or Sync, it can't run in parallel.",
+            system,
+            short_name(type_info)
+        ),
+    }
+}
+
+fn mutability_str(mutability: Mutability) -> &'static str {
+    match mutability {
+        Mutability::Shared => "shared",
+        Mutability::Exclusive => "exclusive",
+    }
+}
+
+pub(crate) fn short_name(type_info: &TypeInfo) -> &str {
+    type_info
+        .name
+        .trim_start_matches("shipyard::")
+        .trim_start_matches("sparse_set::")
+        .trim_start_matches("unique::")
+        .trim_start_matches("all_storages::")
+        .trim_start_matches("entities::")
+}